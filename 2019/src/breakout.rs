@@ -0,0 +1,234 @@
+//! The arcade cabinet from day 13, playable in the terminal.
+//!
+//! The game program draws tiles as `x, y, tile` triples, reports the score as `-1, 0, score`, and
+//! reads the joystick position (-1 for left, 0 for neutral, 1 for right) once per frame. Run
+//! `cargo run --bin day_13` to play it with the arrow keys, or `-- --auto` to watch it play itself.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    io::{self, Write},
+    time::Duration,
+};
+
+use crossterm::{
+    cursor,
+    event::{self, Event as TermEvent, KeyCode, KeyModifiers},
+    execute, queue, style,
+    terminal::{self, ClearType},
+};
+
+use crate::util::intcode::{Error, Event, FlatMem, Memory, State};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tile {
+    Empty,
+    Wall,
+    Block,
+    HorizontalPaddle,
+    Ball,
+}
+
+impl From<i128> for Tile {
+    fn from(i: i128) -> Self {
+        match i {
+            0 => Tile::Empty,
+            1 => Tile::Wall,
+            2 => Tile::Block,
+            3 => Tile::HorizontalPaddle,
+            4 => Tile::Ball,
+            x => panic!("Unrecognised tile ID {x:?}"),
+        }
+    }
+}
+
+impl From<Tile> for char {
+    fn from(tile: Tile) -> Self {
+        match tile {
+            Tile::Empty => '.',
+            Tile::Wall => '#',
+            Tile::Block => '%',
+            Tile::HorizontalPaddle => '-',
+            Tile::Ball => 'O',
+        }
+    }
+}
+
+pub struct Breakout {
+    state: State<FlatMem>,
+    inputs: VecDeque<i128>,
+    /// The outputs of the triple being drawn.
+    pending: Vec<i128>,
+    pub screen: HashMap<(i128, i128), Tile>,
+    pub score: i128,
+    pub ball: Option<(i128, i128)>,
+    pub paddle: Option<(i128, i128)>,
+    pub halted: bool,
+}
+
+impl Breakout {
+    /// Loads the game, inserting quarters to play for free if `free_play` is set.
+    pub fn new(mut program: Vec<i128>, free_play: bool) -> Self {
+        if free_play {
+            program[0] = 2;
+        }
+        Self {
+            state: State::with_memory(FlatMem::new(program)),
+            inputs: VecDeque::new(),
+            pending: Vec::with_capacity(3),
+            screen: HashMap::new(),
+            score: 0,
+            ball: None,
+            paddle: None,
+            halted: false,
+        }
+    }
+
+    /// Runs until the next frame is drawn, which is when the game reads the joystick or halts.
+    /// `joystick` is the position to hold the joystick in for the frame, if the game is waiting
+    /// for one.
+    pub fn advance(&mut self, joystick: Option<i128>) -> Result<(), Error> {
+        self.inputs.extend(joystick);
+        loop {
+            match self.state.resume(&mut self.inputs)? {
+                Event::Output(value) => {
+                    self.pending.push(value);
+                    if let [x, y, value] = self.pending[..] {
+                        self.draw(x, y, value);
+                        self.pending.clear();
+                    }
+                }
+                Event::NeedsInput => return Ok(()),
+                Event::Halted => {
+                    self.halted = true;
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    fn draw(&mut self, x: i128, y: i128, value: i128) {
+        if (x, y) == (-1, 0) {
+            self.score = value;
+            return;
+        }
+        let tile = Tile::from(value);
+        match tile {
+            Tile::Ball => self.ball = Some((x, y)),
+            Tile::HorizontalPaddle => self.paddle = Some((x, y)),
+            _ => {}
+        }
+        self.screen.insert((x, y), tile);
+    }
+
+    pub fn blocks(&self) -> usize {
+        self.screen
+            .values()
+            .filter(|&&tile| tile == Tile::Block)
+            .count()
+    }
+
+    /// The joystick position that moves the paddle under the ball.
+    pub fn autopilot(&self) -> i128 {
+        match (self.ball, self.paddle) {
+            (Some((ball, _)), Some((paddle, _))) => (ball - paddle).signum(),
+            _ => 0,
+        }
+    }
+
+    /// The score line followed by the screen, one line per row.
+    pub fn render(&self) -> String {
+        let width = self.screen.keys().map(|&(x, _)| x + 1).max().unwrap_or(0);
+        let height = self.screen.keys().map(|&(_, y)| y + 1).max().unwrap_or(0);
+        let mut frame = format!("Score: {}\n", self.score);
+        for y in 0..height {
+            for x in 0..width {
+                let tile = self.screen.get(&(x, y)).copied().unwrap_or(Tile::Empty);
+                frame.push(tile.into());
+            }
+            frame.push('\n');
+        }
+        frame
+    }
+
+    /// Plays the game to the end without a terminal, choosing each joystick position with
+    /// `joystick` and passing each frame to `on_frame` as it's drawn. Returns the final score.
+    pub fn play_headless(
+        &mut self,
+        mut joystick: impl FnMut(&Self) -> i128,
+        mut on_frame: impl FnMut(String),
+    ) -> Result<i128, Error> {
+        self.advance(None)?;
+        on_frame(self.render());
+        while !self.halted {
+            self.advance(Some(joystick(self)))?;
+            on_frame(self.render());
+        }
+        Ok(self.score)
+    }
+
+    /// Plays the game in the terminal, with the arrow keys (or `a` and `d`) moving the paddle.
+    /// `p` toggles the autopilot, which starts on if `autopilot` is set, and `q` quits. Returns the
+    /// final score.
+    pub fn play_interactive(&mut self, mut autopilot: bool) -> io::Result<i128> {
+        let _terminal = RawTerminal::enter()?;
+        let mut stdout = io::stdout();
+        self.advance(None).map_err(io::Error::other)?;
+        loop {
+            queue!(stdout, cursor::MoveTo(0, 0))?;
+            for line in self.render().lines() {
+                queue!(stdout, style::Print(line), cursor::MoveToNextLine(1))?;
+            }
+            if self.halted {
+                queue!(stdout, style::Print("Game over; press any key"))?;
+                stdout.flush()?;
+                event::read()?;
+                return Ok(self.score);
+            }
+            stdout.flush()?;
+
+            let delay = Duration::from_millis(if autopilot { 20 } else { 150 });
+            let mut joystick = 0;
+            if event::poll(delay)? {
+                if let TermEvent::Key(key) = event::read()? {
+                    match key.code {
+                        KeyCode::Left | KeyCode::Char('a') => joystick = -1,
+                        KeyCode::Right | KeyCode::Char('d') => joystick = 1,
+                        KeyCode::Char('p') => autopilot = !autopilot,
+                        KeyCode::Char('q') | KeyCode::Esc => return Ok(self.score),
+                        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                            return Ok(self.score)
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            if autopilot {
+                joystick = self.autopilot();
+            }
+            self.advance(Some(joystick)).map_err(io::Error::other)?;
+        }
+    }
+}
+
+/// Puts the terminal in raw mode on an alternate screen until dropped.
+struct RawTerminal;
+
+impl RawTerminal {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(
+            io::stdout(),
+            terminal::EnterAlternateScreen,
+            terminal::Clear(ClearType::All),
+            cursor::Hide
+        )?;
+        Ok(Self)
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
@@ -0,0 +1,31 @@
+use std::fmt::Display;
+
+use crate::util::Solution;
+
+pub(crate) struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Self::Input {
+        input.lines().map(|l| l.parse().unwrap()).collect()
+    }
+
+    fn part_1(inp: &Self::Input) -> impl Display {
+        inp.iter().map(|x| x / 3 - 2).sum::<i64>()
+    }
+
+    fn part_2(inp: &Self::Input) -> impl Display {
+        inp.iter()
+            .map(|&x| {
+                let mut fuel = 0;
+                let mut weight_added = x / 3 - 2;
+                while weight_added > 0 {
+                    fuel += weight_added;
+                    weight_added = weight_added / 3 - 2;
+                }
+                fuel
+            })
+            .sum::<i64>()
+    }
+}
@@ -0,0 +1,148 @@
+use std::{f64::consts::PI, fmt::Display};
+
+use aoc_common::{Grid, Pos};
+
+use crate::util::Solution;
+use itertools::Itertools;
+
+fn is_line_of_sight(grid: &Grid<bool>, pos_a: Pos, pos_b: Pos) -> bool {
+    let pos_b_from_a = (
+        (pos_b.0 as isize) - (pos_a.0 as isize),
+        (pos_b.1 as isize) - (pos_a.1 as isize),
+    );
+    for ((asteroid_x, asteroid_y), &asteroid_cell) in grid.iter() {
+        if asteroid_cell && pos_a != (asteroid_x, asteroid_y) && pos_b != (asteroid_x, asteroid_y) {
+            let asteroid_pos_from_a = (
+                (asteroid_x as isize) - (pos_a.0 as isize),
+                (asteroid_y as isize) - (pos_a.1 as isize),
+            );
+            if asteroid_pos_from_a.0 * pos_b_from_a.1 == asteroid_pos_from_a.1 * pos_b_from_a.0
+                && ((asteroid_pos_from_a.0 >= 0 && pos_b_from_a.0 >= 0)
+                    || (asteroid_pos_from_a.0 <= 0 && pos_b_from_a.0 <= 0))
+                && ((asteroid_pos_from_a.1 >= 0 && pos_b_from_a.1 >= 0)
+                    || (asteroid_pos_from_a.1 <= 0 && pos_b_from_a.1 <= 0))
+                && (asteroid_pos_from_a.0.abs() < pos_b_from_a.0.abs()
+                    || asteroid_pos_from_a.1.abs() < pos_b_from_a.1.abs())
+            {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+fn count_asteroids_visible_from(grid: &Grid<bool>, pos: Pos) -> usize {
+    grid.iter()
+        .filter(|&(asteroid, &asteroid_cell)| {
+            asteroid_cell && pos != asteroid && is_line_of_sight(grid, pos, asteroid)
+        })
+        .count()
+}
+
+fn arctan(opposite: f64, adjacent: f64) -> f64 {
+    let mut basic = opposite.atan2(adjacent);
+    if basic < 0f64 {
+        basic += 2f64 * PI;
+    }
+    basic
+}
+
+/// Finds the asteroid that can see the most other asteroids, returning its coordinates and how
+/// many it can see.
+fn find_station(grid: &Grid<bool>) -> (Pos, usize) {
+    let mut station_coordinates = (0, 0);
+    let mut asteroids_in_view = 0;
+    for (station, &station_cell) in grid.iter() {
+        if station_cell {
+            let new_in_view = count_asteroids_visible_from(grid, station);
+            if new_in_view >= asteroids_in_view {
+                asteroids_in_view = new_in_view;
+                station_coordinates = station;
+            }
+        }
+    }
+
+    (station_coordinates, asteroids_in_view)
+}
+
+pub(crate) struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<bool>;
+
+    fn parse(input: &str) -> Self::Input {
+        Grid::parse(input.trim(), |c| Some(c == '#')).unwrap()
+    }
+
+    fn part_1(grid: &Self::Input) -> impl Display {
+        find_station(grid).1
+    }
+
+    fn part_2(grid: &Self::Input) -> impl Display {
+        let (station_coordinates, _) = find_station(grid);
+        let asteroids: Vec<Pos> = grid
+            .iter()
+            .filter(|&(_, &station_cell)| station_cell)
+            .map(|(pos, _)| pos)
+            .collect();
+        let ordered_asteroids = asteroids.iter().copied().sorted_by(|&(x1, y1), &(x2, y2)| {
+            arctan(
+                (x1 as isize - station_coordinates.0 as isize) as f64,
+                -(y1 as isize - station_coordinates.1 as isize) as f64,
+            )
+            .total_cmp(&arctan(
+                (x2 as isize - station_coordinates.0 as isize) as f64,
+                -(y2 as isize - station_coordinates.1 as isize) as f64,
+            ))
+        });
+        let mut asteroid_groups = Vec::new();
+        let mut asteroid_group = Vec::new();
+        let mut ast_x = 0;
+        let mut ast_y = 0;
+        for (x, y) in ordered_asteroids {
+            if (x, y) == station_coordinates {
+                continue;
+            }
+            if asteroid_group.is_empty() {
+                asteroid_group.push((x, y));
+                ast_x = x as isize - station_coordinates.0 as isize;
+                ast_y = y as isize - station_coordinates.1 as isize;
+            } else {
+                let st_x = x as isize - station_coordinates.0 as isize;
+                let st_y = y as isize - station_coordinates.1 as isize;
+                if ast_x * st_y == ast_y * st_x
+                    && ((ast_x >= 0 && st_x >= 0) || (ast_x <= 0 && st_x <= 0))
+                    && ((ast_y >= 0 && st_y >= 0) || (ast_y <= 0 && st_y <= 0))
+                {
+                    asteroid_group.push((x, y));
+                } else {
+                    asteroid_groups.push(asteroid_group);
+                    asteroid_group = vec![(x, y)];
+                    ast_x = st_x;
+                    ast_y = st_y;
+                }
+            }
+        }
+        asteroid_groups.push(asteroid_group);
+
+        for group in &mut asteroid_groups {
+            group.sort_by_key(|&(x, y)| {
+                -((x as isize - station_coordinates.0 as isize).abs().pow(2)
+                    + (y as isize - station_coordinates.1 as isize).abs().pow(2))
+            });
+        }
+
+        let mut asteroid_count = 0;
+        let (x, y) = 'outer: loop {
+            for group in &mut asteroid_groups {
+                if let Some(a) = group.pop() {
+                    asteroid_count += 1;
+                    if asteroid_count == 200 {
+                        break 'outer a;
+                    }
+                }
+            }
+        };
+        x * 100 + y
+    }
+}
@@ -0,0 +1,132 @@
+use std::{cell::RefCell, collections::HashMap, fmt::Display, rc::Rc};
+
+use aoc_common::{Direction, Point2};
+
+use crate::util::{
+    intcode::{FlatMem, Memory, State},
+    Solution,
+};
+
+#[derive(Clone, Copy, Debug)]
+enum Paint {
+    White,
+    Black,
+}
+
+enum Turn {
+    Left,
+    Right,
+}
+
+#[derive(Clone)]
+struct Hull {
+    panels: HashMap<Point2, Paint>,
+    robot_pos: Point2,
+    robot_direction: Direction,
+}
+
+impl Hull {
+    fn new() -> Self {
+        Self {
+            panels: HashMap::new(),
+            robot_pos: Point2::ORIGIN,
+            robot_direction: Direction::Up,
+        }
+    }
+
+    fn inspect(&self) -> Paint {
+        self.panels
+            .get(&self.robot_pos)
+            .copied()
+            .unwrap_or(Paint::Black)
+    }
+
+    fn paint(&mut self, paint: Paint) {
+        self.panels.insert(self.robot_pos, paint);
+    }
+    fn mv(&mut self, turn: Turn) {
+        self.robot_direction = match turn {
+            Turn::Left => self.robot_direction.turn_left(),
+            Turn::Right => self.robot_direction.turn_right(),
+        };
+        self.robot_pos += self.robot_direction;
+    }
+}
+
+fn paint_hull(mem: Vec<i128>, hull: Hull) -> Hull {
+    let hull = Rc::new(RefCell::new(hull));
+    let inp_hull = Rc::clone(&hull);
+    let out_hull = Rc::clone(&hull);
+    let mut should_paint = true;
+    let mut state = State::with_memory(FlatMem::new(mem));
+    state
+        .run_to_completion(
+            move || match inp_hull.borrow().inspect() {
+                Paint::Black => 0,
+                Paint::White => 1,
+            },
+            move |x| {
+                if should_paint {
+                    out_hull.borrow_mut().paint(match x {
+                        0 => Paint::Black,
+                        1 => Paint::White,
+                        x => panic!("Unrecognised paint colour id {x}"),
+                    });
+                } else {
+                    out_hull.borrow_mut().mv(match x {
+                        0 => Turn::Left,
+                        1 => Turn::Right,
+                        x => panic!("Unrecognised paint colour id {x}"),
+                    });
+                }
+                should_paint = !should_paint;
+            },
+        )
+        .unwrap();
+
+    let hull = hull.borrow().clone();
+    hull
+}
+
+pub(crate) struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<i128>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .trim_end()
+            .split(',')
+            .map(|s| s.parse().unwrap())
+            .collect()
+    }
+
+    fn part_1(input: &Self::Input) -> impl Display {
+        paint_hull(input.clone(), Hull::new()).panels.len()
+    }
+
+    fn part_2(input: &Self::Input) -> impl Display {
+        let mut hull = Hull::new();
+        hull.panels.insert(Point2::ORIGIN, Paint::White);
+        let hull = paint_hull(input.clone(), hull);
+        let max_x = hull.panels.keys().map(|p| p.x).max().unwrap();
+        let max_y = hull.panels.keys().map(|p| p.y).max().unwrap();
+
+        let mut part_2 = String::new();
+        for y in 0..=max_y {
+            for x in 0..=max_x {
+                match hull
+                    .panels
+                    .get(&Point2::new(x, y))
+                    .copied()
+                    .unwrap_or(Paint::Black)
+                {
+                    Paint::White => part_2.push('#'),
+                    Paint::Black => part_2.push('.'),
+                }
+            }
+            part_2.push('\n');
+        }
+        part_2
+    }
+}
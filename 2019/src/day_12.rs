@@ -1,32 +1,17 @@
+use std::fmt::Display;
+
+use crate::util::{Params, Solution};
+use aoc_common::lcm_all;
 use regex::Regex;
-use util::PerfTimer;
 
 type Point = (isize, isize, isize);
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-struct Moon {
+pub(crate) struct Moon {
     position: Point,
     velocity: Point,
 }
 
-fn input() -> Vec<Moon> {
-    let raw = util::get_day_input(12);
-    let re = Regex::new(r"<x=(-?\d+), y=(-?\d+), z=(-?\d+)>").unwrap();
-    let mut moons = Vec::new();
-    for line in raw.trim().lines() {
-        let caps = re.captures(line).unwrap();
-        moons.push(Moon {
-            position: (
-                caps.get(1).unwrap().as_str().parse().unwrap(),
-                caps.get(2).unwrap().as_str().parse().unwrap(),
-                caps.get(3).unwrap().as_str().parse().unwrap(),
-            ),
-            velocity: (0, 0, 0),
-        });
-    }
-    moons
-}
-
 fn do_step(moons: &mut [Moon]) {
     for i in 0..moons.len() {
         let mut moon = moons[i];
@@ -57,32 +42,35 @@ fn do_step(moons: &mut [Moon]) {
     }
 }
 
-fn hcf(m: u128, n: u128) -> u128 {
-    assert!(m > 0);
-    assert!(n > 0);
-    let mut hcf = None;
-    for i in (1..=(m.min(n))).rev() {
-        if m % i == 0 && n % i == 0 {
-            hcf = Some(i);
-            break;
-        }
-    }
-    hcf.unwrap()
-}
+pub(crate) struct Day12;
 
-fn lcm(m: u128, n: u128) -> u128 {
-    assert!(m > 0);
-    assert!(n > 0);
-    (m * n) / hcf(m, n)
-}
+impl Solution for Day12 {
+    type Input = (Vec<Moon>, usize);
 
-fn main() {
-    let input = input();
+    fn parse(input: &str) -> Self::Input {
+        Self::parse_with(input, &Params::default())
+    }
 
-    {
-        let _timer = PerfTimer::new("Part 1");
+    fn parse_with(input: &str, params: &Params) -> Self::Input {
+        let re = Regex::new(r"<x=(-?\d+), y=(-?\d+), z=(-?\d+)>").unwrap();
+        let mut moons = Vec::new();
+        for line in input.trim().lines() {
+            let caps = re.captures(line).unwrap();
+            moons.push(Moon {
+                position: (
+                    caps.get(1).unwrap().as_str().parse().unwrap(),
+                    caps.get(2).unwrap().as_str().parse().unwrap(),
+                    caps.get(3).unwrap().as_str().parse().unwrap(),
+                ),
+                velocity: (0, 0, 0),
+            });
+        }
+        (moons, params.get("steps", 1000))
+    }
+
+    fn part_1((input, steps): &Self::Input) -> impl Display {
         let mut moons = input.clone();
-        for _step in 0..1000 {
+        for _step in 0..*steps {
             do_step(&mut moons);
         }
         let part_1: isize = moons
@@ -96,14 +84,14 @@ fn main() {
                 },
             )
             .sum();
-        println!("Part 1: {part_1}");
+
+        part_1
     }
 
-    {
-        let _timer = PerfTimer::new("Part 2");
+    fn part_2((input, _): &Self::Input) -> impl Display {
         let mut moons = input.clone();
         let mut steps = (None, None, None);
-        let mut step = 0;
+        let mut step: u64 = 0;
         while steps.0.is_none() || steps.1.is_none() || steps.2.is_none() {
             do_step(&mut moons);
             step += 1;
@@ -129,7 +117,7 @@ fn main() {
                 steps.2 = Some(step);
             }
         }
-        let part_2 = lcm(lcm(steps.0.unwrap(), steps.1.unwrap()), steps.2.unwrap());
-        println!("Part 2: {part_2}");
+
+        lcm_all([steps.0.unwrap(), steps.1.unwrap(), steps.2.unwrap()]).unwrap()
     }
 }
@@ -0,0 +1,27 @@
+use std::fmt::Display;
+
+use crate::{
+    breakout::Breakout,
+    util::{intcode, Solution},
+};
+
+pub(crate) struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<i128>;
+
+    fn parse(input: &str) -> Self::Input {
+        intcode::parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> impl Display {
+        let mut game = Breakout::new(input.clone(), false);
+        game.advance(None).unwrap();
+        game.blocks()
+    }
+
+    fn part_2(input: &Self::Input) -> impl Display {
+        let mut game = Breakout::new(input.clone(), true);
+        game.play_headless(Breakout::autopilot, |_| ()).unwrap()
+    }
+}
@@ -1,16 +1,11 @@
-use std::collections::{HashSet, VecDeque};
+use std::{
+    collections::{HashSet, VecDeque},
+    fmt::Display,
+};
 
-use util::{intcode::State, PerfTimer};
+use aoc_common::{Direction, Point2};
 
-type Point = (i64, i64);
-
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-enum Direction {
-    North,
-    South,
-    West,
-    East,
-}
+use crate::util::{intcode::State, Solution};
 
 enum StatusCode {
     /// Droid hit a wall (didn't move)
@@ -32,30 +27,20 @@ impl From<i128> for StatusCode {
     }
 }
 
-impl Direction {
-    fn command(&self) -> i128 {
-        match self {
-            Direction::North => 1,
-            Direction::South => 2,
-            Direction::West => 3,
-            Direction::East => 4,
-        }
-    }
-
-    fn mv(&self, (row, col): Point) -> Point {
-        match self {
-            Direction::North => (row - 1, col),
-            Direction::South => (row + 1, col),
-            Direction::West => (row, col - 1),
-            Direction::East => (row, col + 1),
-        }
+/// The movement command that moves the droid in `direction`.
+fn command(direction: Direction) -> i128 {
+    match direction {
+        Direction::Up => 1,
+        Direction::Down => 2,
+        Direction::Left => 3,
+        Direction::Right => 4,
     }
 }
 
 #[derive(Clone, Debug)]
 struct RobotState {
     path: Vec<Direction>,
-    position: Point,
+    position: Point2,
     state: State,
 }
 
@@ -67,7 +52,7 @@ enum SearchFor {
 
 fn find_path(initial_state: RobotState, search_for: SearchFor) -> RobotState {
     use Direction::*;
-    let mut discovered: HashSet<Point> = HashSet::new();
+    let mut discovered: HashSet<Point2> = HashSet::new();
     discovered.insert(initial_state.position);
     let mut states = VecDeque::new();
     let mut last_state = initial_state.clone();
@@ -85,14 +70,17 @@ fn find_path(initial_state: RobotState, search_for: SearchFor) -> RobotState {
                 }
             },
         };
-        for direction in [North, South, West, East] {
-            let new_pos = direction.mv(state.position);
+        for direction in [Up, Down, Left, Right] {
+            let new_pos = state.position + direction;
             if !discovered.insert(new_pos) {
                 continue;
             }
             let mut new_state = state.state.clone();
-            let status_code =
-                StatusCode::from(new_state.run_one_in_one_out(direction.command()).unwrap());
+            // A branch whose program halts or faults is a dead end, the same as a wall.
+            let status_code = match new_state.run_one_in_one_out(command(direction)) {
+                Ok(Some(status)) => StatusCode::from(status),
+                Ok(None) | Err(_) => continue,
+            };
             match status_code {
                 StatusCode::HitWall => {}
                 StatusCode::Moved => {
@@ -121,37 +109,41 @@ fn find_path(initial_state: RobotState, search_for: SearchFor) -> RobotState {
     }
 }
 
-fn main() {
-    let input: Vec<i128> = util::get_day_input(15)
-        .trim_end()
-        .split(',')
-        .map(|s| s.parse().unwrap())
-        .collect();
-    {
-        let _timer = PerfTimer::new("Part 1");
+pub(crate) struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<i128>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .trim_end()
+            .split(',')
+            .map(|s| s.parse().unwrap())
+            .collect()
+    }
+
+    fn part_1(input: &Self::Input) -> impl Display {
+        // Scrap exploring, here's an idea: keep expanding the shortest path, never going to a position we've already
+        // been to, until we find oxygen. Keep track of all the states simultaneously.
         let mem = input.clone();
-        let path = find_path(
+        find_path(
             RobotState {
                 path: Vec::new(),
-                position: (0, 0),
+                position: Point2::ORIGIN,
                 state: State::new(mem),
             },
             SearchFor::Oxygen,
         )
-        .path;
-        let part_1 = path.len();
-        println!("Part 1: {part_1}");
-
-        // Scrap exploring, here's an idea: keep expanding the shortest path, never going to a position we've already
-        // been to, until we find oxygen. Keep track of all the states simultaneously.
+        .path
+        .len()
     }
-    {
-        let _timer = PerfTimer::new("Part 2");
+
+    fn part_2(input: &Self::Input) -> impl Display {
         let mem = input.clone();
         let at_oxygen = find_path(
             RobotState {
                 path: Vec::new(),
-                position: (0, 0),
+                position: Point2::ORIGIN,
                 state: State::new(mem),
             },
             SearchFor::Oxygen,
@@ -164,7 +156,6 @@ fn main() {
             SearchFor::LongestPath,
         )
         .path;
-        let part_2 = path.len();
-        println!("Part 2: {part_2}");
+        path.len()
     }
 }
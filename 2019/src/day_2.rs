@@ -0,0 +1,44 @@
+use std::fmt::Display;
+
+use crate::util::{
+    intcode::{self, FlatMem, Memory, State},
+    Solution,
+};
+
+fn run_program(mem: Vec<i128>) -> i128 {
+    let mut state = State::with_memory(FlatMem::new(mem));
+    state.run([]).unwrap();
+    state.mem[0]
+}
+
+pub(crate) struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<i128>;
+
+    fn parse(input: &str) -> Self::Input {
+        intcode::parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> impl Display {
+        let mut mem = input.clone();
+        mem[1] = 12;
+        mem[2] = 2;
+        run_program(mem)
+    }
+
+    fn part_2(input: &Self::Input) -> impl Display {
+        for noun in 0..100 {
+            for verb in 0..100 {
+                let mut mem = input.clone();
+                mem[1] = noun;
+                mem[2] = verb;
+                let result = run_program(mem);
+                if result == 19690720 {
+                    return 100 * noun + verb;
+                }
+            }
+        }
+        panic!("no noun and verb produce 19690720")
+    }
+}
@@ -1,12 +1,14 @@
 use std::{
     cmp::min,
     collections::{HashMap, HashSet},
+    fmt::Display,
 };
 
-use util::PerfTimer;
+use crate::util::Solution;
 use Instruction::*;
 
-enum Instruction {
+#[derive(Clone, Copy)]
+pub(crate) enum Instruction {
     Up(usize),
     Right(usize),
     Down(usize),
@@ -24,27 +26,15 @@ fn parse_instruction(s: &str) -> Instruction {
     }
 }
 
-fn input() -> (Vec<Instruction>, Vec<Instruction>) {
-    let raw = util::get_day_input(3);
-    let mut lines = raw.lines();
-    let raw_1 = lines.next().unwrap();
-    let raw_2 = lines.next().unwrap();
-
-    let wire_1 = raw_1.split(',').map(parse_instruction).collect();
-    let wire_2 = raw_2.split(',').map(parse_instruction).collect();
-    (wire_1, wire_2)
-}
-
-fn main() {
-    let (wire_1, wire_2) = input();
-
-    let _timer = PerfTimer::new("Both parts");
+/// Finds where the two wires cross, returning the smallest distance from the origin to a crossing
+/// and the fewest combined steps the wires take to reach one.
+fn find_crossings(wire_1: &[Instruction], wire_2: &[Instruction]) -> (usize, usize) {
     let mut wire_1_positions = HashSet::new();
     let mut wire_1_map = HashMap::new();
 
     let mut steps = 0;
-    let mut position = (0, 0);
-    for instruction in wire_1 {
+    let mut position = (0_isize, 0_isize);
+    for &instruction in wire_1 {
         match instruction {
             Up(l) => {
                 for _ in 0..l {
@@ -81,22 +71,22 @@ fn main() {
         }
     }
 
-    let mut part_1 = usize::MAX;
-    let mut part_2 = usize::MAX;
-    let mut position = (0, 0);
+    let mut closest_distance = usize::MAX;
+    let mut fewest_steps = usize::MAX;
+    let mut position = (0_isize, 0_isize);
     let mut len = 0;
 
-    for instruction in wire_2 {
+    for &instruction in wire_2 {
         match instruction {
             Up(l) => {
                 for _ in 0..l {
                     position.0 += 1;
                     len += 1;
                     if wire_1_positions.contains(&position) {
-                        let p = position.0 + position.1;
-                        part_1 = min(part_1, p);
+                        let p = position.0.unsigned_abs() + position.1.unsigned_abs();
+                        closest_distance = min(closest_distance, p);
                         let p2 = wire_1_map.get(&position).unwrap();
-                        part_2 = min(part_2, p2 + len);
+                        fewest_steps = min(fewest_steps, p2 + len);
                     }
                 }
             }
@@ -105,10 +95,10 @@ fn main() {
                     position.1 += 1;
                     len += 1;
                     if wire_1_positions.contains(&position) {
-                        let p = position.0 + position.1;
-                        part_1 = min(part_1, p);
+                        let p = position.0.unsigned_abs() + position.1.unsigned_abs();
+                        closest_distance = min(closest_distance, p);
                         let p2 = wire_1_map.get(&position).unwrap();
-                        part_2 = min(part_2, p2 + len);
+                        fewest_steps = min(fewest_steps, p2 + len);
                     }
                 }
             }
@@ -117,10 +107,10 @@ fn main() {
                     position.0 -= 1;
                     len += 1;
                     if wire_1_positions.contains(&position) {
-                        let p = position.0 + position.1;
-                        part_1 = min(part_1, p);
+                        let p = position.0.unsigned_abs() + position.1.unsigned_abs();
+                        closest_distance = min(closest_distance, p);
                         let p2 = wire_1_map.get(&position).unwrap();
-                        part_2 = min(part_2, p2 + len);
+                        fewest_steps = min(fewest_steps, p2 + len);
                     }
                 }
             }
@@ -129,15 +119,38 @@ fn main() {
                     position.1 -= 1;
                     len += 1;
                     if wire_1_positions.contains(&position) {
-                        let p = position.0 + position.1;
-                        part_1 = min(part_1, p);
+                        let p = position.0.unsigned_abs() + position.1.unsigned_abs();
+                        closest_distance = min(closest_distance, p);
                         let p2 = wire_1_map.get(&position).unwrap();
-                        part_2 = min(part_2, p2 + len);
+                        fewest_steps = min(fewest_steps, p2 + len);
                     }
                 }
             }
         }
     }
-    println!("Part 1: {part_1}");
-    println!("Part 2: {part_2}");
+    (closest_distance, fewest_steps)
+}
+
+pub(crate) struct Day3;
+
+impl Solution for Day3 {
+    type Input = (Vec<Instruction>, Vec<Instruction>);
+
+    fn parse(input: &str) -> Self::Input {
+        let mut lines = input.lines();
+        let raw_1 = lines.next().unwrap();
+        let raw_2 = lines.next().unwrap();
+
+        let wire_1 = raw_1.split(',').map(parse_instruction).collect();
+        let wire_2 = raw_2.split(',').map(parse_instruction).collect();
+        (wire_1, wire_2)
+    }
+
+    fn part_1((wire_1, wire_2): &Self::Input) -> impl Display {
+        find_crossings(wire_1, wire_2).0
+    }
+
+    fn part_2((wire_1, wire_2): &Self::Input) -> impl Display {
+        find_crossings(wire_1, wire_2).1
+    }
 }
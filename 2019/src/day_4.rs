@@ -1,14 +1,6 @@
-#![feature(array_windows)]
+use std::{cmp::Ordering, fmt::Display};
 
-use std::cmp::Ordering;
-
-use util::{get_day_input, PerfTimer};
-
-fn input() -> (u64, u64) {
-    let raw = get_day_input(4);
-    let (left, right) = raw.trim().split_once('-').unwrap();
-    (left.parse().unwrap(), right.parse().unwrap())
-}
+use crate::util::Solution;
 
 enum DoubleState {
     NotFound,
@@ -18,12 +10,11 @@ enum DoubleState {
     CompletedMoreThanPair,
 }
 
-fn main() {
-    let (lower, upper) = input();
-
-    let _timer = PerfTimer::new("Both parts");
-    let mut part_1 = 0;
-    let mut part_2 = 0;
+/// Counts the passwords in the range that never decrease, returning how many contain a repeated
+/// digit and how many contain a digit repeated exactly twice.
+fn count_passwords(lower: u64, upper: u64) -> (u64, u64) {
+    let mut with_double = 0;
+    let mut with_pair = 0;
     for i in lower..=upper {
         let s = i.to_string();
         let mut prev = None;
@@ -63,17 +54,35 @@ fn main() {
         if increasing {
             match double {
                 DoubleState::FoundPair | DoubleState::CompletedPair => {
-                    part_1 += 1;
-                    part_2 += 1;
+                    with_double += 1;
+                    with_pair += 1;
                 }
                 DoubleState::FoundMoreThanPair | DoubleState::CompletedMoreThanPair => {
-                    part_1 += 1;
+                    with_double += 1;
                 }
                 DoubleState::NotFound => {}
             }
         }
     }
 
-    println!("Part 1: {part_1}");
-    println!("Part 2: {part_2}");
+    (with_double, with_pair)
+}
+
+pub(crate) struct Day4;
+
+impl Solution for Day4 {
+    type Input = (u64, u64);
+
+    fn parse(input: &str) -> Self::Input {
+        let (left, right) = input.trim().split_once('-').unwrap();
+        (left.parse().unwrap(), right.parse().unwrap())
+    }
+
+    fn part_1(&(lower, upper): &Self::Input) -> impl Display {
+        count_passwords(lower, upper).0
+    }
+
+    fn part_2(&(lower, upper): &Self::Input) -> impl Display {
+        count_passwords(lower, upper).1
+    }
 }
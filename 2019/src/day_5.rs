@@ -0,0 +1,34 @@
+use std::fmt::Display;
+
+use crate::util::{
+    intcode::{self, FlatMem, Memory, State},
+    Solution,
+};
+
+/// Runs the diagnostic program, checking that every test passed, and returns the diagnostic code.
+fn run_program(mem: Vec<i128>, input: i128) -> i128 {
+    let outputs = State::with_memory(FlatMem::new(mem)).run([input]).unwrap();
+    let (&code, tests) = outputs.split_last().unwrap();
+    for &i in tests {
+        assert_eq!(i, 0);
+    }
+    code
+}
+
+pub(crate) struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vec<i128>;
+
+    fn parse(input: &str) -> Self::Input {
+        intcode::parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> impl Display {
+        run_program(input.clone(), 1)
+    }
+
+    fn part_2(input: &Self::Input) -> impl Display {
+        run_program(input.clone(), 5)
+    }
+}
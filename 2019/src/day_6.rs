@@ -1,22 +1,23 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt::Display};
 
+use crate::util::Solution;
 use itertools::Itertools;
-use util::{get_day_input, PerfTimer};
 
-fn input() -> Vec<(String, String)> {
-    let raw = get_day_input(6);
-    let mut orbits = Vec::new();
-    for line in raw.lines() {
-        let (orbited, orbiter) = line.split_once(')').unwrap();
-        orbits.push((String::from(orbited), String::from(orbiter)));
+pub(crate) struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<(String, String)>;
+
+    fn parse(input: &str) -> Self::Input {
+        let mut orbits = Vec::new();
+        for line in input.lines() {
+            let (orbited, orbiter) = line.split_once(')').unwrap();
+            orbits.push((String::from(orbited), String::from(orbiter)));
+        }
+        orbits
     }
-    orbits
-}
 
-fn main() {
-    let orbits = input();
-    {
-        let _timer = PerfTimer::new("Part 1");
+    fn part_1(orbits: &Self::Input) -> impl Display {
         let mut orbit_count = 0;
         let orbits = orbits.iter().cloned().into_group_map();
         let mut set = vec![("COM", 1)];
@@ -34,11 +35,10 @@ fn main() {
             set = new_set;
             new_set = Vec::new();
         }
-        println!("Part 1: {orbit_count}");
+        orbit_count
     }
 
-    {
-        let _timer = PerfTimer::new("Part 2");
+    fn part_2(orbits: &Self::Input) -> impl Display {
         let orbits: HashMap<String, String> = orbits.iter().cloned().map(|(a, b)| (b, a)).collect();
 
         let mut you_path_to_com = Vec::new();
@@ -53,12 +53,13 @@ fn main() {
             san_path_to_com.push(orbits[*san_path_to_com.last().unwrap()].as_str())
         }
 
-        for (i, &o) in you_path_to_com.iter().enumerate() {
-            if let Some(j) = san_path_to_com.iter().position(|&x| x == o) {
-                let part_2 = i + j;
-                println!("Part 2: {part_2}");
-                break;
-            }
-        }
+        you_path_to_com
+            .iter()
+            .enumerate()
+            .find_map(|(i, &o)| {
+                let j = san_path_to_com.iter().position(|&x| x == o)?;
+                Some(i + j)
+            })
+            .unwrap()
     }
 }
@@ -0,0 +1,69 @@
+use std::fmt::Display;
+
+use itertools::Itertools;
+
+use crate::util::{
+    intcode::{self, FlatMem, Memory, Network, State},
+    Solution,
+};
+
+fn run_configuration(mem: &[i128], settings: &[i128]) -> i128 {
+    let mut signal = 0;
+    for &setting in settings {
+        let outputs = State::with_memory(FlatMem::new(mem.to_vec()))
+            .run([setting, signal])
+            .unwrap();
+        signal = *outputs.last().unwrap();
+    }
+    signal
+}
+
+/// Runs the amplifiers in a feedback loop until they halt, and returns the last signal sent to
+/// the thrusters.
+fn run_feedback_loop(mem: &[i128], settings: &[i128]) -> i128 {
+    let machines = vec![State::with_memory(FlatMem::new(mem.to_vec())); settings.len()];
+    let mut network = Network::new(machines, 1);
+    for (i, &setting) in settings.iter().enumerate() {
+        network.send(i, &[setting]);
+    }
+    network.send(0, &[0]);
+
+    let last = settings.len() - 1;
+    let mut thrust = 0;
+    network
+        .run(|from, signal| {
+            if from == last {
+                thrust = signal[0];
+            }
+            Some(((from + 1) % settings.len(), signal))
+        })
+        .unwrap();
+    assert!(network.all_halted());
+    thrust
+}
+
+pub(crate) struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<i128>;
+
+    fn parse(input: &str) -> Self::Input {
+        intcode::parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> impl Display {
+        (0..=4)
+            .permutations(5)
+            .map(|config| run_configuration(input, &config))
+            .max()
+            .unwrap()
+    }
+
+    fn part_2(input: &Self::Input) -> impl Display {
+        (5..=9)
+            .permutations(5)
+            .map(|config| run_feedback_loop(input, &config))
+            .max()
+            .unwrap()
+    }
+}
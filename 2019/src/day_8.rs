@@ -0,0 +1,74 @@
+use std::fmt::Display;
+
+use crate::util::{Params, Solution};
+
+const WIDTH: usize = 25;
+const HEIGHT: usize = 6;
+
+pub(crate) struct Image {
+    pixels: Vec<u8>,
+    width: usize,
+    height: usize,
+}
+
+pub(crate) struct Day8;
+
+impl Solution for Day8 {
+    type Input = Image;
+
+    fn parse(input: &str) -> Self::Input {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Self::Input {
+        let pixels = input
+            .trim()
+            .chars()
+            .map(|c| (c as u32 - '0' as u32) as u8)
+            .collect();
+        Image {
+            pixels,
+            width: params.get("width", WIDTH),
+            height: params.get("height", HEIGHT),
+        }
+    }
+
+    fn part_1(input: &Self::Input) -> impl Display {
+        let part_1_layer = input
+            .pixels
+            .chunks(input.width * input.height)
+            .min_by_key(|l| l.iter().copied().filter(|&p| p == 0).count())
+            .unwrap();
+        let ones = part_1_layer.iter().filter(|&&p| p == 1).count();
+        let twos = part_1_layer.iter().filter(|&&p| p == 2).count();
+        ones * twos
+    }
+
+    fn part_2(input: &Self::Input) -> impl Display {
+        let layers: Vec<&[u8]> = input.pixels.chunks(input.width * input.height).collect();
+        let mut image = vec![2; input.width * input.height];
+        for &layer in layers.iter().rev() {
+            for (pi, &pl) in image.iter_mut().zip(layer.iter()) {
+                match pl {
+                    0 => *pi = 0,
+                    1 => *pi = 1,
+                    2 => {}
+                    x => panic!("Unhandled pixel value {x:?}"),
+                }
+            }
+        }
+
+        let mut part_2 = String::new();
+        for row in image.chunks(input.width) {
+            for &p in row {
+                match p {
+                    0 => part_2.push(' '),
+                    1 => part_2.push('#'),
+                    x => panic!("Unexpected pixel value {x:?}"),
+                }
+            }
+            part_2.push('\n');
+        }
+        part_2
+    }
+}
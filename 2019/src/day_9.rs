@@ -0,0 +1,56 @@
+use std::fmt::Display;
+
+use crate::util::{
+    intcode::{self, Code, FlatMem, Memory, State},
+    Solution,
+};
+
+/// Runs BOOST, checking that it found no faulty opcodes, and returns the keycode or coordinates.
+fn run_program(mem: Vec<i128>, input: i128) -> i128 {
+    let outputs = State::with_memory(FlatMem::new(mem)).run([input]).unwrap();
+    let (&result, faults) = outputs.split_last().unwrap();
+    for &i in faults {
+        assert_eq!(i, 0);
+    }
+    result
+}
+
+#[allow(dead_code)]
+fn run_one_in_one_out<M: Memory>(state: &mut State<M>, input: i128) -> Option<i128> {
+    state.run_one_in_one_out(input).unwrap()
+}
+
+#[allow(dead_code)]
+fn run_one_in<M: Memory>(state: &mut State<M>, input: i128) -> bool {
+    let mut input = Some(input);
+    while input.is_some() {
+        let code = state
+            .execute_operation(
+                || input.take().unwrap(),
+                |_| panic!("Received unexpected output"),
+            )
+            .unwrap();
+        if code == Code::Stop {
+            return false;
+        }
+    }
+    true
+}
+
+pub(crate) struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<i128>;
+
+    fn parse(input: &str) -> Self::Input {
+        intcode::parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> impl Display {
+        run_program(input.clone(), 1)
+    }
+
+    fn part_2(input: &Self::Input) -> impl Display {
+        run_program(input.clone(), 2)
+    }
+}
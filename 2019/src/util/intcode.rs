@@ -0,0 +1,460 @@
+use std::{
+    collections::VecDeque,
+    hash::{Hash, Hasher},
+};
+
+pub use ascii::{Ascii, AsciiOutput};
+pub use asm::{assemble, disassemble};
+pub use debugger::{Debugger, IoEvent, Stop};
+pub use error::{Error, ErrorKind};
+pub use instruction::{Instruction, Mode, Op, Param};
+pub use mem::Mem;
+pub use memory::{FlatMem, Memory};
+pub use network::Network;
+pub use trace::{Step, Trace};
+
+mod ascii;
+mod asm;
+mod debugger;
+mod error;
+mod instruction;
+mod memory;
+mod network;
+mod trace;
+
+pub fn parse_input(raw: &str) -> Vec<i128> {
+    raw.trim_end()
+        .split(',')
+        .map(|s| s.parse().unwrap())
+        .collect()
+}
+
+mod mem {
+    use std::{
+        hash::{DefaultHasher, Hash, Hasher},
+        sync::{Arc, OnceLock},
+    };
+
+    use super::Memory;
+
+    const PAGE_SIZE: usize = 256;
+
+    static ZERO: i128 = 0;
+
+    /// A program's memory, split into pages that clones share until one of them writes to a page.
+    /// Cloning copies a pointer per page, and a read or write is a single lookup however many
+    /// times the memory has been cloned. Words that were never written are zero.
+    #[derive(Clone, Default)]
+    pub struct Mem {
+        pages: Vec<Option<Arc<Page>>>,
+        /// One past the highest word that was loaded or written to.
+        len: usize,
+    }
+
+    #[derive(Clone)]
+    struct Page {
+        words: [i128; PAGE_SIZE],
+        /// The hash of `words`, or `None` if they're all zero, so that a page of zeros compares
+        /// and hashes the same as a missing one. Cleared when the page is written to.
+        hash: OnceLock<Option<u64>>,
+    }
+
+    impl Page {
+        fn new(words: [i128; PAGE_SIZE]) -> Self {
+            Self {
+                words,
+                hash: OnceLock::new(),
+            }
+        }
+
+        fn hash(&self) -> Option<u64> {
+            *self.hash.get_or_init(|| {
+                self.words.iter().any(|&word| word != 0).then(|| {
+                    let mut hasher = DefaultHasher::new();
+                    self.words.hash(&mut hasher);
+                    hasher.finish()
+                })
+            })
+        }
+    }
+
+    impl Mem {
+        fn page(&self, n: usize) -> Option<&Arc<Page>> {
+            self.pages.get(n).and_then(Option::as_ref)
+        }
+    }
+
+    impl Memory for Mem {
+        fn new(program: Vec<i128>) -> Self {
+            let len = program.len();
+            let pages = program
+                .chunks(PAGE_SIZE)
+                .map(|chunk| {
+                    let mut words = [0; PAGE_SIZE];
+                    words[..chunk.len()].copy_from_slice(chunk);
+                    Some(Arc::new(Page::new(words)))
+                })
+                .collect();
+            Self { pages, len }
+        }
+
+        fn len(&self) -> usize {
+            self.len
+        }
+    }
+
+    impl std::ops::Index<usize> for Mem {
+        type Output = i128;
+
+        fn index(&self, index: usize) -> &Self::Output {
+            match self.page(index / PAGE_SIZE) {
+                Some(page) => &page.words[index % PAGE_SIZE],
+                None => &ZERO,
+            }
+        }
+    }
+
+    impl std::ops::IndexMut<usize> for Mem {
+        fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+            self.len = self.len.max(index + 1);
+            let n = index / PAGE_SIZE;
+            if n >= self.pages.len() {
+                self.pages.resize(n + 1, None);
+            }
+            let page = self.pages[n].get_or_insert_with(|| Arc::new(Page::new([0; PAGE_SIZE])));
+            let page = Arc::make_mut(page);
+            page.hash.take();
+            &mut page.words[index % PAGE_SIZE]
+        }
+    }
+    impl PartialEq for Mem {
+        fn eq(&self, other: &Self) -> bool {
+            (0..self.pages.len().max(other.pages.len())).all(|n| {
+                match (self.page(n), other.page(n)) {
+                    (Some(a), Some(b)) if Arc::ptr_eq(a, b) => true,
+                    (Some(a), Some(b)) => a.hash() == b.hash() && a.words == b.words,
+                    (Some(page), None) | (None, Some(page)) => page.hash().is_none(),
+                    (None, None) => true,
+                }
+            })
+        }
+    }
+
+    impl Eq for Mem {}
+
+    impl Hash for Mem {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            for (n, page) in self.pages.iter().enumerate() {
+                if let Some(hash) = page.as_deref().and_then(Page::hash) {
+                    n.hash(state);
+                    hash.hash(state);
+                }
+            }
+        }
+    }
+
+    impl std::fmt::Debug for Mem {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("Mem")
+                .field("data", &self.range(0..self.len()))
+                .finish()
+        }
+    }
+}
+
+/// A machine. With the default [`Mem`], cloning one is cheap, as the clone shares memory with it
+/// until either writes, so a search can snapshot a machine and fork it at every branch. A machine
+/// that's never cloned runs faster in a [`FlatMem`]. Machines compare and hash by their memory and
+/// registers, ignoring any trace, so revisited states can be detected.
+#[derive(Clone, Debug)]
+pub struct State<M = Mem> {
+    pub mem: M,
+    pub pos: usize,
+    pub relative_base: i128,
+    /// Records each instruction executed, if set.
+    pub trace: Option<Trace>,
+}
+
+impl<M: PartialEq> PartialEq for State<M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.pos == other.pos && self.relative_base == other.relative_base && self.mem == other.mem
+    }
+}
+
+impl<M: Eq> Eq for State<M> {}
+
+impl<M: Hash> Hash for State<M> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.pos.hash(state);
+        self.relative_base.hash(state);
+        self.mem.hash(state);
+    }
+}
+
+/// Why [`State::resume`] returned.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Event {
+    NeedsInput,
+    Output(i128),
+    Halted,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Code {
+    Success,
+    Stop,
+}
+
+/// The operation and parameter modes of an instruction, as a fault-free decoding of its opcode.
+#[derive(Clone, Copy)]
+struct Decoded {
+    op: Op,
+    opcode: i128,
+    modes: [Mode; 3],
+}
+
+impl State {
+    pub fn new(program: Vec<i128>) -> Self {
+        Self::with_memory(Mem::new(program))
+    }
+}
+
+impl<M: Memory> State<M> {
+    /// A machine about to run the program loaded into `mem`.
+    pub fn with_memory(mem: M) -> Self {
+        Self {
+            mem,
+            pos: 0,
+            relative_base: 0,
+            trace: None,
+        }
+    }
+
+    fn error(&self, opcode: i128, kind: ErrorKind) -> Error {
+        Error {
+            pos: self.pos,
+            opcode,
+            kind,
+        }
+    }
+
+    fn decode(&self) -> Result<Decoded, Error> {
+        let opcode = self.mem[self.pos];
+        let op = Op::from_opcode(opcode % 100)
+            .filter(|_| opcode >= 0)
+            .ok_or_else(|| self.error(opcode, ErrorKind::UnknownOpcode))?;
+        let mut modes = [Mode::Position; 3];
+        let mut digits = opcode / 100;
+        for (param, mode) in modes.iter_mut().enumerate().take(op.arity()) {
+            *mode = Mode::from_digit(digits % 10).ok_or_else(|| {
+                self.error(
+                    opcode,
+                    ErrorKind::InvalidMode {
+                        param,
+                        mode: digits % 10,
+                    },
+                )
+            })?;
+            digits /= 10;
+        }
+        Ok(Decoded { op, opcode, modes })
+    }
+
+    /// The address parameter `param` refers to.
+    fn addr(&self, decoded: &Decoded, param: usize) -> Result<usize, Error> {
+        let value = self.mem[self.pos + 1 + param];
+        let addr = match decoded.modes[param] {
+            Mode::Position => value,
+            Mode::Relative => self.relative_base + value,
+            Mode::Immediate => {
+                return Err(self.error(decoded.opcode, ErrorKind::ImmediateWrite { param }))
+            }
+        };
+        usize::try_from(addr)
+            .map_err(|_| self.error(decoded.opcode, ErrorKind::NegativeAddress { param, addr }))
+    }
+
+    fn read(&self, decoded: &Decoded, param: usize) -> Result<i128, Error> {
+        match decoded.modes[param] {
+            Mode::Immediate => Ok(self.mem[self.pos + 1 + param]),
+            _ => Ok(self.mem[self.addr(decoded, param)?]),
+        }
+    }
+
+    /// Reads parameter `param`, adding its value to `args` if the instruction is being traced.
+    fn arg(
+        &self,
+        decoded: &Decoded,
+        param: usize,
+        args: Option<&mut Vec<i128>>,
+    ) -> Result<i128, Error> {
+        let value = self.read(decoded, param)?;
+        if let Some(args) = args {
+            args.push(value);
+        }
+        Ok(value)
+    }
+
+    fn write(&mut self, decoded: &Decoded, param: usize, value: i128) -> Result<(), Error> {
+        let addr = self.addr(decoded, param)?;
+        self.mem[addr] = value;
+        Ok(())
+    }
+
+    fn jump(
+        &mut self,
+        decoded: &Decoded,
+        condition: bool,
+        args: Option<&mut Vec<i128>>,
+    ) -> Result<(), Error> {
+        if !condition {
+            self.pos += 3;
+            return Ok(());
+        }
+        let addr = self.arg(decoded, 1, args)?;
+        self.pos = usize::try_from(addr).map_err(|_| {
+            self.error(
+                decoded.opcode,
+                ErrorKind::NegativeAddress { param: 1, addr },
+            )
+        })?;
+        Ok(())
+    }
+
+    /// Executes the instruction at the instruction pointer. A faulting instruction leaves the
+    /// machine as it was.
+    pub fn execute_operation(
+        &mut self,
+        input: impl FnMut() -> i128,
+        output: impl FnMut(i128),
+    ) -> Result<Code, Error> {
+        let decoded = self.decode()?;
+        if self.trace.is_none() {
+            return self.execute(&decoded, input, output, None);
+        }
+
+        let op = decoded.op;
+        let params = (0..op.arity())
+            .map(|i| Param {
+                mode: decoded.modes[i],
+                value: self.mem[self.pos + 1 + i],
+            })
+            .collect();
+        let pos = self.pos;
+        // If the write target is invalid, executing faults below, so it's never used.
+        let target = op.write_param().and_then(|i| self.addr(&decoded, i).ok());
+        // Only the parameters the instruction reads as it runs are recorded, so that tracing
+        // can't fault on one it skips, such as the target of a jump that isn't taken.
+        let mut args = Vec::new();
+        let code = self.execute(&decoded, input, output, Some(&mut args))?;
+        let step = Step {
+            index: self.trace.as_ref().map_or(0, Trace::executed),
+            pos,
+            instruction: Instruction { op, params },
+            args,
+            write: target.map(|addr| (addr, self.mem[addr])),
+        };
+        if let Some(trace) = &mut self.trace {
+            trace.record(step);
+        }
+        Ok(code)
+    }
+
+    fn execute(
+        &mut self,
+        decoded: &Decoded,
+        mut input: impl FnMut() -> i128,
+        mut output: impl FnMut(i128),
+        mut args: Option<&mut Vec<i128>>,
+    ) -> Result<Code, Error> {
+        let decoded = *decoded;
+        match decoded.op {
+            Op::Add | Op::Mul | Op::LessThan | Op::Equals => {
+                let a = self.arg(&decoded, 0, args.as_deref_mut())?;
+                let b = self.arg(&decoded, 1, args)?;
+                let value = match decoded.op {
+                    Op::Add => a + b,
+                    Op::Mul => a * b,
+                    Op::LessThan => i128::from(a < b),
+                    _ => i128::from(a == b),
+                };
+                self.write(&decoded, 2, value)?;
+                self.pos += 4;
+            }
+            Op::Input => {
+                // Check the address first, so that a fault doesn't consume any input.
+                self.addr(&decoded, 0)?;
+                self.write(&decoded, 0, input())?;
+                self.pos += 2;
+            }
+            Op::Output => {
+                output(self.arg(&decoded, 0, args)?);
+                self.pos += 2;
+            }
+            Op::JumpIfTrue => {
+                let condition = self.arg(&decoded, 0, args.as_deref_mut())? != 0;
+                self.jump(&decoded, condition, args)?;
+            }
+            Op::JumpIfFalse => {
+                let condition = self.arg(&decoded, 0, args.as_deref_mut())? == 0;
+                self.jump(&decoded, condition, args)?;
+            }
+            Op::AdjustRelativeBase => {
+                self.relative_base += self.arg(&decoded, 0, args)?;
+                self.pos += 2;
+            }
+            Op::Halt => return Ok(Code::Stop),
+        }
+        Ok(Code::Success)
+    }
+
+    /// Runs until the program outputs a value, halts, or needs input when `inputs` is empty.
+    /// Input is taken from the front of `inputs`. A machine that needs input can be resumed once
+    /// some has been added.
+    pub fn resume(&mut self, inputs: &mut VecDeque<i128>) -> Result<Event, Error> {
+        loop {
+            if self.mem[self.pos] % 100 == 3 && inputs.is_empty() {
+                return Ok(Event::NeedsInput);
+            }
+            let mut output = None;
+            let code =
+                self.execute_operation(|| inputs.pop_front().unwrap(), |x| output = Some(x))?;
+            if let Some(x) = output {
+                return Ok(Event::Output(x));
+            }
+            if code == Code::Stop {
+                return Ok(Event::Halted);
+            }
+        }
+    }
+
+    pub fn run_one_in_one_out(&mut self, input: i128) -> Result<Option<i128>, Error> {
+        match self.resume(&mut VecDeque::from([input]))? {
+            Event::Output(x) => Ok(Some(x)),
+            Event::Halted => Ok(None),
+            Event::NeedsInput => panic!("Program needed a second input"),
+        }
+    }
+
+    /// Runs until the program halts, giving it `inputs` in order, and returns its outputs.
+    pub fn run(&mut self, inputs: impl IntoIterator<Item = i128>) -> Result<Vec<i128>, Error> {
+        let mut inputs = inputs.into_iter().collect();
+        let mut outputs = Vec::new();
+        loop {
+            match self.resume(&mut inputs)? {
+                Event::Output(x) => outputs.push(x),
+                Event::Halted => return Ok(outputs),
+                Event::NeedsInput => panic!("Program needed more input"),
+            }
+        }
+    }
+
+    pub fn run_to_completion(
+        &mut self,
+        mut input: impl FnMut() -> i128,
+        mut output: impl FnMut(i128),
+    ) -> Result<(), Error> {
+        while self.execute_operation(&mut input, &mut output)? == Code::Success {}
+        Ok(())
+    }
+}
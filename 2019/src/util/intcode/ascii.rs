@@ -0,0 +1,101 @@
+//! Driving programs that read and write text, one ASCII code per value.
+//!
+//! Such programs usually prompt with a line of text and read a line back, and report their
+//! answer as a single value too large to be a character. `Ascii::new(state).run_interactive()`
+//! plays one on the terminal, and [`Ascii::play`] runs one through a script of input lines.
+
+use std::{
+    collections::VecDeque,
+    io::{self, BufRead, Write},
+};
+
+use super::{Error, Event, Mem, Memory, State};
+
+/// The values a program output before it stopped.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AsciiOutput {
+    /// The ASCII characters.
+    pub text: String,
+    /// The values that aren't ASCII characters, in order.
+    pub answers: Vec<i128>,
+    /// Whether the program halted, rather than stopping for input.
+    pub halted: bool,
+}
+
+impl AsciiOutput {
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.text.lines()
+    }
+}
+
+pub struct Ascii<M = Mem> {
+    pub state: State<M>,
+    pub inputs: VecDeque<i128>,
+}
+
+impl<M: Memory> Ascii<M> {
+    pub fn new(state: State<M>) -> Self {
+        Self {
+            state,
+            inputs: VecDeque::new(),
+        }
+    }
+
+    /// Queues `text` for the program to read.
+    pub fn push_str(&mut self, text: &str) {
+        self.inputs.extend(text.bytes().map(i128::from));
+    }
+
+    /// Queues `line` for the program to read, followed by a newline.
+    pub fn push_line(&mut self, line: &str) {
+        self.push_str(line);
+        self.inputs.push_back(i128::from(b'\n'));
+    }
+
+    /// Runs until the program halts or needs input when none is queued, collecting what it
+    /// outputs on the way.
+    pub fn run(&mut self) -> Result<AsciiOutput, Error> {
+        let mut output = AsciiOutput::default();
+        loop {
+            match self.state.resume(&mut self.inputs)? {
+                Event::Output(value) => match u8::try_from(value) {
+                    Ok(byte) if byte.is_ascii() => output.text.push(char::from(byte)),
+                    _ => output.answers.push(value),
+                },
+                Event::NeedsInput => return Ok(output),
+                Event::Halted => {
+                    output.halted = true;
+                    return Ok(output);
+                }
+            }
+        }
+    }
+
+    /// Runs the program, writing its text and answers to `out` and giving it each line of
+    /// `commands` when it needs input, until it halts or the commands run out. A program fault is
+    /// returned as an error of kind [`io::ErrorKind::Other`].
+    pub fn play(&mut self, commands: impl BufRead, mut out: impl Write) -> io::Result<AsciiOutput> {
+        let mut commands = commands.lines();
+        loop {
+            let output = self.run().map_err(io::Error::other)?;
+            write!(out, "{}", output.text)?;
+            for answer in &output.answers {
+                writeln!(out, "{answer}")?;
+            }
+            out.flush()?;
+            if output.halted {
+                return Ok(output);
+            }
+            match commands.next() {
+                Some(line) => self.push_line(&line?),
+                None => return Ok(output),
+            }
+        }
+    }
+
+    /// Plays the program on stdin and stdout.
+    pub fn run_interactive(&mut self) {
+        let stdin = io::stdin();
+        self.play(stdin.lock(), io::stdout()).unwrap();
+    }
+}
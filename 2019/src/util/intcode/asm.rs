@@ -0,0 +1,280 @@
+//! A text form of Intcode programs, for reading them and for writing test programs by hand.
+//!
+//! Each line holds an instruction such as `add [4], 3, [rb-2]`, or `data` followed by raw words.
+//! Parameters are written `[n]` in position mode, `n` in immediate mode and `[rb+n]` in relative
+//! mode. A line can start with `name:` to label its address, which can then be used in place of a
+//! number (optionally as `name+n` or `name-n`), and with `n:` to check that it's at address `n`.
+//! Anything after a `;` is a comment.
+
+use std::{collections::HashMap, fmt::Write};
+
+use super::instruction::{Instruction, Mode, Op, Param};
+
+/// How many words of data to put on each `data` line.
+const DATA_PER_LINE: usize = 8;
+
+enum Line {
+    Instruction(Instruction),
+    Data(i128),
+}
+
+/// Renders `program` as a listing with each line's address, decoding every word that starts a
+/// valid instruction as one and the rest as data. Jump targets and positions inside the program
+/// are given labels. Assembling the listing gives back `program`.
+pub fn disassemble(program: &[i128]) -> String {
+    let mut lines = Vec::new();
+    let mut addr = 0;
+    while addr < program.len() {
+        match Instruction::decode(&program[addr..]) {
+            Some(instruction) => {
+                let size = instruction.size();
+                lines.push((addr, Line::Instruction(instruction)));
+                addr += size;
+            }
+            None => {
+                lines.push((addr, Line::Data(program[addr])));
+                addr += 1;
+            }
+        }
+    }
+
+    let starts: Vec<usize> = lines.iter().map(|&(addr, _)| addr).collect();
+    let mut labels = HashMap::new();
+    for (_, line) in &lines {
+        let Line::Instruction(instruction) = line else {
+            continue;
+        };
+        for (i, param) in instruction.params.iter().enumerate() {
+            let is_target = match param.mode {
+                Mode::Position => true,
+                Mode::Immediate => instruction.op.jump_param() == Some(i),
+                Mode::Relative => false,
+            };
+            let Ok(target) = usize::try_from(param.value) else {
+                continue;
+            };
+            if is_target && starts.binary_search(&target).is_ok() {
+                labels.insert(target, format!("L{target}"));
+            }
+        }
+    }
+
+    let mut listing = String::new();
+    let mut lines = lines.into_iter().peekable();
+    while let Some((addr, line)) = lines.next() {
+        if let Some(label) = labels.get(&addr) {
+            writeln!(listing, "{label}:").unwrap();
+        }
+        write!(listing, "{addr:>6}: ").unwrap();
+        match line {
+            Line::Instruction(instruction) => {
+                listing.push_str(instruction.op.mnemonic());
+                for (i, param) in instruction.params.iter().enumerate() {
+                    let separator = if i == 0 { " " } else { ", " };
+                    let label = usize::try_from(param.value)
+                        .ok()
+                        .and_then(|target| labels.get(&target));
+                    match (param.mode, label) {
+                        (Mode::Position, Some(label)) => {
+                            write!(listing, "{separator}[{label}]").unwrap()
+                        }
+                        (Mode::Immediate, Some(label))
+                            if instruction.op.jump_param() == Some(i) =>
+                        {
+                            write!(listing, "{separator}{label}").unwrap()
+                        }
+                        _ => write!(listing, "{separator}{param}").unwrap(),
+                    }
+                }
+            }
+            Line::Data(word) => {
+                write!(listing, "data {word}").unwrap();
+                for _ in 1..DATA_PER_LINE {
+                    match lines.peek() {
+                        Some((addr, Line::Data(word))) if !labels.contains_key(addr) => {
+                            write!(listing, ", {word}").unwrap();
+                            lines.next();
+                        }
+                        _ => break,
+                    }
+                }
+            }
+        }
+        listing.push('\n');
+    }
+    listing
+}
+
+/// An operand whose value may refer to a label.
+#[derive(Clone, Copy)]
+struct Operand<'a> {
+    mode: Mode,
+    label: Option<&'a str>,
+    offset: i128,
+}
+
+enum Statement<'a> {
+    Instruction(Op, Vec<Operand<'a>>),
+    Data(Vec<Operand<'a>>),
+}
+
+fn is_label(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn parse_value(text: &str) -> Result<(Option<&str>, i128), String> {
+    let text = text.trim();
+    if let Ok(n) = text.parse() {
+        return Ok((None, n));
+    }
+    let (label, offset) = match text.find(['+', '-']) {
+        Some(i) => {
+            let offset: i128 = text[i + 1..]
+                .trim()
+                .parse()
+                .map_err(|_| format!("invalid offset in {text:?}"))?;
+            let sign = if text.as_bytes()[i] == b'-' { -1 } else { 1 };
+            (text[..i].trim(), sign * offset)
+        }
+        None => (text, 0),
+    };
+    if !is_label(label) {
+        return Err(format!("expected a number or label, not {text:?}"));
+    }
+    Ok((Some(label), offset))
+}
+
+fn parse_operand(text: &str) -> Result<Operand<'_>, String> {
+    let text = text.trim();
+    let (mode, value) = match text.strip_prefix('[').and_then(|t| t.strip_suffix(']')) {
+        Some(inner) => match inner.trim().strip_prefix("rb") {
+            Some("") => (Mode::Relative, "0"),
+            Some(offset) if offset.starts_with(['+', '-']) => {
+                (Mode::Relative, offset.trim_start_matches('+'))
+            }
+            _ => (Mode::Position, inner),
+        },
+        None => (Mode::Immediate, text),
+    };
+    let (label, offset) = parse_value(value)?;
+    Ok(Operand {
+        mode,
+        label,
+        offset,
+    })
+}
+
+fn parse_statement(text: &str) -> Result<Option<Statement<'_>>, String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Ok(None);
+    }
+    let (mnemonic, operands) = text.split_once(char::is_whitespace).unwrap_or((text, ""));
+    let operands = if operands.trim().is_empty() {
+        Vec::new()
+    } else {
+        operands
+            .split(',')
+            .map(parse_operand)
+            .collect::<Result<Vec<_>, _>>()?
+    };
+
+    if mnemonic == "data" {
+        if let Some(operand) = operands.iter().find(|o| o.mode != Mode::Immediate) {
+            return Err(format!(
+                "data can only hold plain values, not {:?} ones",
+                operand.mode
+            ));
+        }
+        return Ok(Some(Statement::Data(operands)));
+    }
+    let op = Op::from_mnemonic(mnemonic).ok_or_else(|| format!("unknown mnemonic {mnemonic:?}"))?;
+    if operands.len() != op.arity() {
+        return Err(format!(
+            "{mnemonic} takes {} operands, not {}",
+            op.arity(),
+            operands.len()
+        ));
+    }
+    if let Some(i) = op.write_param() {
+        if operands[i].mode == Mode::Immediate {
+            return Err(format!("{mnemonic} can't write to an immediate operand"));
+        }
+    }
+    Ok(Some(Statement::Instruction(op, operands)))
+}
+
+/// Assembles the text form described in the [module docs](self) into a program.
+///
+/// Returns an error naming the offending line if a line isn't valid, a label is defined twice or
+/// never, or an address check fails.
+pub fn assemble(text: &str) -> Result<Vec<i128>, String> {
+    let mut labels = HashMap::new();
+    let mut statements = Vec::new();
+    let mut addr = 0;
+    for (line_number, line) in (1..).zip(text.lines()) {
+        let error = |message: String| format!("Line {line_number}: {message}");
+        let mut line = line.split(';').next().unwrap_or_default();
+        while let Some((prefix, rest)) = line.split_once(':') {
+            let prefix = prefix.trim();
+            if let Ok(expected) = prefix.parse::<usize>() {
+                if expected != addr {
+                    return Err(error(format!(
+                        "expected address {expected}, but it's {addr}"
+                    )));
+                }
+            } else if is_label(prefix) {
+                if labels.insert(prefix, addr).is_some() {
+                    return Err(error(format!("label {prefix} is defined twice")));
+                }
+            } else {
+                break;
+            }
+            line = rest;
+        }
+
+        let Some(statement) = parse_statement(line).map_err(error)? else {
+            continue;
+        };
+        addr += match &statement {
+            Statement::Instruction(op, _) => 1 + op.arity(),
+            Statement::Data(words) => words.len(),
+        };
+        statements.push((line_number, statement));
+    }
+
+    let mut program = Vec::with_capacity(addr);
+    for (line_number, statement) in statements {
+        let value = |operand: &Operand| -> Result<i128, String> {
+            let base = match operand.label {
+                Some(label) => *labels
+                    .get(label)
+                    .ok_or_else(|| format!("Line {line_number}: label {label} isn't defined"))?
+                    as i128,
+                None => 0,
+            };
+            Ok(base + operand.offset)
+        };
+        match statement {
+            Statement::Instruction(op, operands) => {
+                let params = operands
+                    .iter()
+                    .map(|operand| {
+                        Ok(Param {
+                            mode: operand.mode,
+                            value: value(operand)?,
+                        })
+                    })
+                    .collect::<Result<_, String>>()?;
+                program.extend(Instruction { op, params }.encode());
+            }
+            Statement::Data(words) => {
+                for word in &words {
+                    program.push(value(word)?);
+                }
+            }
+        }
+    }
+    Ok(program)
+}
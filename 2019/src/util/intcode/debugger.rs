@@ -0,0 +1,331 @@
+//! An interactive debugger for a [`State`], driven by commands read line by line.
+//!
+//! Any day can hand its machine over with `Debugger::new(state).run_interactive()` to step through
+//! it, set breakpoints and poke at its memory. Type `help` for the commands.
+
+use std::{
+    collections::{BTreeSet, VecDeque},
+    fmt,
+    io::{self, BufRead, Write},
+};
+
+use super::{Code, Error, Instruction, Mem, Memory, Op, State};
+
+const HELP: &str = "\
+Commands:
+    step [n]                Execute the next n instructions (default 1), showing each one
+    continue                Run until a breakpoint, the program halts, or it needs input
+    break <addr|mnemonic>   Stop before executing the instruction at addr, or any such operation
+    delete <addr|mnemonic>  Remove a breakpoint
+    breaks                  List breakpoints
+    input <values...>       Queue values for the program to read
+    mem <addr> [end]        Show memory from addr up to end (default addr + 8)
+    set <addr> <values...>  Write values to memory starting at addr
+    regs                    Show the instruction pointer and relative base
+    dis [addr] [count]      Disassemble count instructions (default 8) from addr (default the pc)
+    log on|off              Show each input and output as it happens (default on)
+    io                      Show every input and output so far
+    quit                    Stop debugging";
+
+/// A value the program read or wrote.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IoEvent {
+    Input(i128),
+    Output(i128),
+}
+
+impl fmt::Display for IoEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IoEvent::Input(value) => write!(f, "input: {value}"),
+            IoEvent::Output(value) => write!(f, "output: {value}"),
+        }
+    }
+}
+
+/// Why execution stopped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stop {
+    /// The requested number of steps ran.
+    Stepped,
+    Breakpoint,
+    /// The program needs input and none is queued.
+    NeedsInput,
+    Halted,
+    /// The instruction at the instruction pointer faulted, and wasn't executed.
+    Fault(Error),
+}
+
+/// A command that couldn't be carried out, either because it was invalid or because its output
+/// couldn't be written.
+enum CommandError {
+    Invalid(String),
+    Io(io::Error),
+}
+
+impl From<String> for CommandError {
+    fn from(message: String) -> Self {
+        Self::Invalid(message)
+    }
+}
+
+impl From<io::Error> for CommandError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+pub struct Debugger<M = Mem> {
+    pub state: State<M>,
+    pub inputs: VecDeque<i128>,
+    pub io_log: Vec<IoEvent>,
+    pub breakpoints: BTreeSet<usize>,
+    pub break_on: BTreeSet<&'static str>,
+    pub log_io: bool,
+}
+
+impl<M: Memory> Debugger<M> {
+    pub fn new(state: State<M>) -> Self {
+        Self {
+            state,
+            inputs: VecDeque::new(),
+            io_log: Vec::new(),
+            breakpoints: BTreeSet::new(),
+            break_on: BTreeSet::new(),
+            log_io: true,
+        }
+    }
+
+    /// The instruction at the instruction pointer, if it's a valid one.
+    pub fn current(&self) -> Option<Instruction> {
+        let pos = self.state.pos;
+        Instruction::decode(&self.state.mem.range(pos..pos + 4))
+    }
+
+    fn is_breakpoint(&self) -> bool {
+        let op = Op::from_opcode(self.state.mem[self.state.pos] % 100);
+        self.breakpoints.contains(&self.state.pos)
+            || op.is_some_and(|op| self.break_on.contains(op.mnemonic()))
+    }
+
+    /// Executes up to `steps` instructions, stopping early as [`Stop`] describes. A breakpoint at
+    /// the first instruction doesn't stop it, so that execution can continue from a breakpoint.
+    /// Each executed instruction and I/O event is written to `out` if `trace` is set.
+    pub fn run(&mut self, steps: usize, trace: bool, out: &mut impl Write) -> io::Result<Stop> {
+        for step in 0..steps {
+            if step > 0 && self.is_breakpoint() {
+                return Ok(Stop::Breakpoint);
+            }
+            if self.state.mem[self.state.pos] % 100 == 3 && self.inputs.is_empty() {
+                return Ok(Stop::NeedsInput);
+            }
+            if trace {
+                match self.current() {
+                    Some(instruction) => writeln!(out, "{:>6}: {instruction}", self.state.pos)?,
+                    None => writeln!(
+                        out,
+                        "{:>6}: data {}",
+                        self.state.pos, self.state.mem[self.state.pos]
+                    )?,
+                }
+            }
+
+            let mut event = None;
+            let inputs = &mut self.inputs;
+            let mut input = None;
+            let code = self.state.execute_operation(
+                || {
+                    let value = inputs.pop_front().unwrap();
+                    input = Some(IoEvent::Input(value));
+                    value
+                },
+                |value| event = Some(IoEvent::Output(value)),
+            );
+            if let Some(event) = input.or(event) {
+                if self.log_io {
+                    writeln!(out, "{event}")?;
+                }
+                self.io_log.push(event);
+            }
+            match code {
+                Ok(Code::Success) => {}
+                Ok(Code::Stop) => return Ok(Stop::Halted),
+                Err(e) => return Ok(Stop::Fault(e)),
+            }
+        }
+        Ok(Stop::Stepped)
+    }
+
+    /// Reads commands from `commands` until it runs out or `quit`, writing results and prompts to
+    /// `out`.
+    pub fn repl(&mut self, commands: impl BufRead, mut out: impl Write) -> io::Result<()> {
+        write!(out, "(intcode) ")?;
+        out.flush()?;
+        for line in commands.lines() {
+            let line = line?;
+            let words: Vec<&str> = line.split_whitespace().collect();
+            if let Some(&command) = words.first() {
+                if command == "quit" || command == "q" {
+                    break;
+                }
+                match self.command(command, &words[1..], &mut out) {
+                    Ok(()) => {}
+                    Err(CommandError::Invalid(message)) => writeln!(out, "{message}")?,
+                    Err(CommandError::Io(e)) => return Err(e),
+                }
+            }
+            write!(out, "(intcode) ")?;
+            out.flush()?;
+        }
+        writeln!(out)
+    }
+
+    /// Runs the debugger on stdin and stdout.
+    pub fn run_interactive(&mut self) {
+        let stdin = io::stdin();
+        self.repl(stdin.lock(), io::stdout()).unwrap();
+    }
+
+    fn command(
+        &mut self,
+        command: &str,
+        args: &[&str],
+        out: &mut impl Write,
+    ) -> Result<(), CommandError> {
+        let numbers = match args
+            .iter()
+            .map(|arg| {
+                arg.parse::<i128>()
+                    .map_err(|_| format!("Invalid number {arg:?}"))
+            })
+            .collect::<Result<Vec<_>, _>>()
+        {
+            Ok(numbers) => numbers,
+            Err(_) if matches!(command, "break" | "b" | "delete" | "d" | "log") => Vec::new(),
+            Err(message) => return Err(message.into()),
+        };
+        let addr = |i: usize| -> Result<Option<usize>, String> {
+            numbers
+                .get(i)
+                .map(|&n| usize::try_from(n).map_err(|_| format!("Invalid address {n}")))
+                .transpose()
+        };
+
+        match command {
+            "step" | "s" => {
+                let steps = addr(0)?.unwrap_or(1);
+                let stop = self.run(steps, true, out)?;
+                self.report(stop, out)?;
+            }
+            "continue" | "c" => {
+                let stop = self.run(usize::MAX, false, out)?;
+                self.report(stop, out)?;
+            }
+            "break" | "b" | "delete" | "d" => {
+                let [target] = args else {
+                    return Err(format!("Expected an address or mnemonic to {command}").into());
+                };
+                let add = matches!(command, "break" | "b");
+                if let Ok(target) = target.parse() {
+                    if add {
+                        self.breakpoints.insert(target);
+                    } else {
+                        self.breakpoints.remove(&target);
+                    }
+                } else if let Some(op) = Op::from_mnemonic(target) {
+                    if add {
+                        self.break_on.insert(op.mnemonic());
+                    } else {
+                        self.break_on.remove(op.mnemonic());
+                    }
+                } else {
+                    return Err(format!("Invalid address or mnemonic {target:?}").into());
+                }
+            }
+            "breaks" => {
+                for addr in &self.breakpoints {
+                    writeln!(out, "at {addr}")?;
+                }
+                for mnemonic in &self.break_on {
+                    writeln!(out, "on {mnemonic}")?;
+                }
+            }
+            "input" | "i" => self.inputs.extend(numbers),
+            "mem" | "m" => {
+                let Some(start) = addr(0)? else {
+                    return Err("Expected an address".to_owned().into());
+                };
+                let end = addr(1)?.unwrap_or(start + 8);
+                for (row_start, row) in (start..end)
+                    .step_by(8)
+                    .map(|row| (row, row..end.min(row + 8)))
+                {
+                    let values: Vec<String> = self
+                        .state
+                        .mem
+                        .range(row)
+                        .iter()
+                        .map(i128::to_string)
+                        .collect();
+                    writeln!(out, "{row_start:>6}: {}", values.join(" "))?;
+                }
+            }
+            "set" => {
+                let (Some(start), [_, values @ ..]) = (addr(0)?, numbers.as_slice()) else {
+                    return Err("Expected an address and values".to_owned().into());
+                };
+                for (i, &value) in values.iter().enumerate() {
+                    self.state.mem[start + i] = value;
+                }
+            }
+            "regs" | "r" => writeln!(
+                out,
+                "pc: {}  relative base: {}",
+                self.state.pos, self.state.relative_base
+            )?,
+            "dis" => {
+                let mut pos = addr(0)?.unwrap_or(self.state.pos);
+                for _ in 0..addr(1)?.unwrap_or(8) {
+                    match Instruction::decode(&self.state.mem.range(pos..pos + 4)) {
+                        Some(instruction) => {
+                            let marker = if pos == self.state.pos { '>' } else { ' ' };
+                            writeln!(out, "{marker}{pos:>5}: {instruction}")?;
+                            pos += instruction.size();
+                        }
+                        None => {
+                            writeln!(out, "{pos:>6}: data {}", self.state.mem[pos])?;
+                            pos += 1;
+                        }
+                    }
+                }
+            }
+            "log" => match args {
+                ["on"] => self.log_io = true,
+                ["off"] => self.log_io = false,
+                _ => return Err("Expected on or off".to_owned().into()),
+            },
+            "io" => {
+                for event in &self.io_log {
+                    writeln!(out, "{event}")?;
+                }
+            }
+            "help" | "h" => writeln!(out, "{HELP}")?,
+            _ => return Err(format!("Unknown command {command:?}; try help").into()),
+        }
+        Ok(())
+    }
+
+    fn report(&self, stop: Stop, out: &mut impl Write) -> io::Result<()> {
+        let pos = self.state.pos;
+        match stop {
+            Stop::Stepped => Ok(()),
+            Stop::Breakpoint => match self.current() {
+                Some(instruction) => writeln!(out, "Breakpoint at {pos}: {instruction}"),
+                None => writeln!(out, "Breakpoint at {pos}"),
+            },
+            Stop::NeedsInput => writeln!(out, "Waiting for input at {pos}; queue some with input"),
+            Stop::Halted => writeln!(out, "Halted at {pos}"),
+            Stop::Fault(e) => writeln!(out, "{e}"),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use std::fmt;
+
+/// What was wrong with the instruction a program faulted on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// The opcode doesn't name an operation.
+    UnknownOpcode,
+    /// Parameter `param` has a mode digit other than 0, 1 or 2.
+    InvalidMode { param: usize, mode: i128 },
+    /// Parameter `param` is written to, but is in immediate mode.
+    ImmediateWrite { param: usize },
+    /// Parameter `param` refers to, or jumps to, a negative address.
+    NegativeAddress { param: usize, addr: i128 },
+}
+
+/// A fault in a running program, which leaves the machine at the faulting instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Error {
+    /// The instruction pointer.
+    pub pos: usize,
+    pub opcode: i128,
+    pub kind: ErrorKind,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Error { pos, opcode, kind } = self;
+        match kind {
+            ErrorKind::UnknownOpcode => write!(f, "Unknown opcode {opcode} at {pos}"),
+            ErrorKind::InvalidMode { param, mode } => write!(
+                f,
+                "Invalid mode {mode} for parameter {param} of {opcode} at {pos}"
+            ),
+            ErrorKind::ImmediateWrite { param } => write!(
+                f,
+                "Parameter {param} of {opcode} at {pos} is written to in immediate mode"
+            ),
+            ErrorKind::NegativeAddress { param, addr } => write!(
+                f,
+                "Parameter {param} of {opcode} at {pos} refers to negative address {addr}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,202 @@
+use std::fmt;
+
+/// An Intcode operation, as given by the last two digits of an instruction's opcode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Op {
+    Add,
+    Mul,
+    Input,
+    Output,
+    JumpIfTrue,
+    JumpIfFalse,
+    LessThan,
+    Equals,
+    AdjustRelativeBase,
+    Halt,
+}
+
+impl Op {
+    pub const ALL: [Op; 10] = [
+        Op::Add,
+        Op::Mul,
+        Op::Input,
+        Op::Output,
+        Op::JumpIfTrue,
+        Op::JumpIfFalse,
+        Op::LessThan,
+        Op::Equals,
+        Op::AdjustRelativeBase,
+        Op::Halt,
+    ];
+
+    pub fn opcode(self) -> i128 {
+        match self {
+            Op::Add => 1,
+            Op::Mul => 2,
+            Op::Input => 3,
+            Op::Output => 4,
+            Op::JumpIfTrue => 5,
+            Op::JumpIfFalse => 6,
+            Op::LessThan => 7,
+            Op::Equals => 8,
+            Op::AdjustRelativeBase => 9,
+            Op::Halt => 99,
+        }
+    }
+
+    pub fn from_opcode(opcode: i128) -> Option<Self> {
+        Self::ALL.into_iter().find(|op| op.opcode() == opcode)
+    }
+
+    pub fn mnemonic(self) -> &'static str {
+        match self {
+            Op::Add => "add",
+            Op::Mul => "mul",
+            Op::Input => "in",
+            Op::Output => "out",
+            Op::JumpIfTrue => "jnz",
+            Op::JumpIfFalse => "jz",
+            Op::LessThan => "lt",
+            Op::Equals => "eq",
+            Op::AdjustRelativeBase => "arb",
+            Op::Halt => "hlt",
+        }
+    }
+
+    pub fn from_mnemonic(mnemonic: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|op| op.mnemonic() == mnemonic)
+    }
+
+    /// The number of parameters following the opcode.
+    pub fn arity(self) -> usize {
+        match self {
+            Op::Add | Op::Mul | Op::LessThan | Op::Equals => 3,
+            Op::JumpIfTrue | Op::JumpIfFalse => 2,
+            Op::Input | Op::Output | Op::AdjustRelativeBase => 1,
+            Op::Halt => 0,
+        }
+    }
+
+    /// The index of the parameter this operation writes to, if any.
+    pub fn write_param(self) -> Option<usize> {
+        match self {
+            Op::Add | Op::Mul | Op::LessThan | Op::Equals => Some(2),
+            Op::Input => Some(0),
+            _ => None,
+        }
+    }
+
+    /// The index of the parameter this operation jumps to, if any.
+    pub fn jump_param(self) -> Option<usize> {
+        match self {
+            Op::JumpIfTrue | Op::JumpIfFalse => Some(1),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Mode {
+    Position,
+    Immediate,
+    Relative,
+}
+
+impl Mode {
+    pub fn from_digit(digit: i128) -> Option<Self> {
+        match digit {
+            0 => Some(Mode::Position),
+            1 => Some(Mode::Immediate),
+            2 => Some(Mode::Relative),
+            _ => None,
+        }
+    }
+
+    pub fn digit(self) -> i128 {
+        match self {
+            Mode::Position => 0,
+            Mode::Immediate => 1,
+            Mode::Relative => 2,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Param {
+    pub mode: Mode,
+    pub value: i128,
+}
+
+impl fmt::Display for Param {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.mode {
+            Mode::Position => write!(f, "[{}]", self.value),
+            Mode::Immediate => write!(f, "{}", self.value),
+            Mode::Relative if self.value == 0 => write!(f, "[rb]"),
+            Mode::Relative if self.value < 0 => write!(f, "[rb-{}]", -self.value),
+            Mode::Relative => write!(f, "[rb+{}]", self.value),
+        }
+    }
+}
+
+/// A decoded instruction, which encodes back to exactly the words it was decoded from.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Instruction {
+    pub op: Op,
+    pub params: Vec<Param>,
+}
+
+impl Instruction {
+    /// Decodes the instruction at the start of `words`. Returns `None` if the first word isn't a
+    /// valid opcode with a valid mode for each parameter and no others, or if `words` ends before
+    /// the instruction does.
+    pub fn decode(words: &[i128]) -> Option<Self> {
+        let &opcode = words.first()?;
+        if opcode < 0 {
+            return None;
+        }
+        let op = Op::from_opcode(opcode % 100)?;
+        let mut modes = opcode / 100;
+        let mut params = Vec::with_capacity(op.arity());
+        for i in 0..op.arity() {
+            let mode = Mode::from_digit(modes % 10)?;
+            if op.write_param() == Some(i) && mode == Mode::Immediate {
+                return None;
+            }
+            params.push(Param {
+                mode,
+                value: *words.get(1 + i)?,
+            });
+            modes /= 10;
+        }
+        (modes == 0).then_some(Instruction { op, params })
+    }
+
+    pub fn encode(&self) -> Vec<i128> {
+        let mut place = 100;
+        let mut opcode = self.op.opcode();
+        for param in &self.params {
+            opcode += param.mode.digit() * place;
+            place *= 10;
+        }
+        let mut words = vec![opcode];
+        words.extend(self.params.iter().map(|param| param.value));
+        words
+    }
+
+    /// The number of words the instruction takes up.
+    pub fn size(&self) -> usize {
+        1 + self.params.len()
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.op.mnemonic())?;
+        for (i, param) in self.params.iter().enumerate() {
+            let separator = if i == 0 { " " } else { ", " };
+            write!(f, "{separator}{param}")?;
+        }
+        Ok(())
+    }
+}
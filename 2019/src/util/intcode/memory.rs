@@ -0,0 +1,96 @@
+//! The memory a machine runs in. [`Mem`](super::Mem) shares pages between clones, for searches
+//! that fork machines, and [`FlatMem`] is a plain vector, for programs that just run.
+
+use std::{
+    hash::{Hash, Hasher},
+    ops::{Index, IndexMut},
+};
+
+/// Memory that grows as it's written to. Words that were never written are zero, however far
+/// past the program they are.
+pub trait Memory: Clone + Index<usize, Output = i128> + IndexMut<usize> {
+    fn new(program: Vec<i128>) -> Self;
+
+    /// One past the highest word that was loaded or written to. Words past the end are zero, but
+    /// words before it may be too.
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn range<B: Iterator<Item = usize>>(&self, range: B) -> Box<[i128]> {
+        range.map(|i| self[i]).collect()
+    }
+}
+
+static ZERO: i128 = 0;
+
+/// Memory in a single vector, which is quickest to read and write but is copied in full when
+/// cloned.
+#[derive(Clone, Debug, Default)]
+pub struct FlatMem {
+    words: Vec<i128>,
+    /// One past the highest word that was loaded or written to, as `words` grows ahead of it.
+    len: usize,
+}
+
+impl FlatMem {
+    /// The words up to and including the last non-zero one.
+    fn trimmed(&self) -> &[i128] {
+        let len = self
+            .words
+            .iter()
+            .rposition(|&word| word != 0)
+            .map_or(0, |i| i + 1);
+        &self.words[..len]
+    }
+}
+
+impl Memory for FlatMem {
+    fn new(program: Vec<i128>) -> Self {
+        let len = program.len();
+        Self {
+            words: program,
+            len,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl Index<usize> for FlatMem {
+    type Output = i128;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.words.get(index).unwrap_or(&ZERO)
+    }
+}
+
+impl IndexMut<usize> for FlatMem {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.len = self.len.max(index + 1);
+        if index >= self.words.len() {
+            // Grow by at least double, so that writing upwards through memory is amortised.
+            let len = (index + 1).max(self.words.len() * 2);
+            self.words.resize(len, 0);
+        }
+        &mut self.words[index]
+    }
+}
+
+impl PartialEq for FlatMem {
+    fn eq(&self, other: &Self) -> bool {
+        self.trimmed() == other.trimmed()
+    }
+}
+
+impl Eq for FlatMem {}
+
+impl Hash for FlatMem {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.trimmed().hash(state);
+    }
+}
@@ -0,0 +1,88 @@
+//! Several machines wired together through input queues, for amplifier feedback loops and
+//! packet-routing networks alike.
+
+use std::collections::VecDeque;
+
+use super::{Error, Event, Mem, Memory, State};
+
+pub struct Network<M = Mem> {
+    pub machines: Vec<State<M>>,
+    /// Each machine's pending input.
+    pub queues: Vec<VecDeque<i128>>,
+    /// How many consecutive outputs make up one packet.
+    packet_size: usize,
+    /// The outputs of each machine's packet so far.
+    partial: Vec<Vec<i128>>,
+    halted: Vec<bool>,
+    /// Given to a machine that needs input when its queue is empty, instead of blocking it.
+    idle_input: Option<i128>,
+}
+
+impl<M: Memory> Network<M> {
+    /// Wires up `machines`, which send their outputs in packets of `packet_size` values.
+    pub fn new(machines: Vec<State<M>>, packet_size: usize) -> Self {
+        let n = machines.len();
+        Self {
+            machines,
+            queues: vec![VecDeque::new(); n],
+            packet_size,
+            partial: vec![Vec::new(); n],
+            halted: vec![false; n],
+            idle_input: None,
+        }
+    }
+
+    /// Gives `value` to a machine that needs input when its queue is empty, once each round,
+    /// rather than leaving it blocked until some arrives.
+    pub fn with_idle_input(mut self, value: i128) -> Self {
+        self.idle_input = Some(value);
+        self
+    }
+
+    pub fn send(&mut self, to: usize, values: &[i128]) {
+        self.queues[to].extend(values);
+    }
+
+    pub fn all_halted(&self) -> bool {
+        self.halted.iter().all(|&halted| halted)
+    }
+
+    /// Runs every machine in turn until it halts or is blocked on input, handing each packet it
+    /// sends to `route` along with the index of the sender. `route` returns the machine to deliver
+    /// it to and the values to deliver, or `None` to take the packet out of the network. Rounds
+    /// repeat until one delivers nothing, so the network is left idle or halted, or until a
+    /// machine faults.
+    pub fn run(
+        &mut self,
+        mut route: impl FnMut(usize, Vec<i128>) -> Option<(usize, Vec<i128>)>,
+    ) -> Result<(), Error> {
+        loop {
+            let mut delivered = false;
+            for i in 0..self.machines.len() {
+                let mut idle_input = self.idle_input;
+                while !self.halted[i] {
+                    match self.machines[i].resume(&mut self.queues[i])? {
+                        Event::NeedsInput => match idle_input.take() {
+                            Some(value) => self.queues[i].push_back(value),
+                            None => break,
+                        },
+                        Event::Output(value) => {
+                            self.partial[i].push(value);
+                            if self.partial[i].len() == self.packet_size {
+                                let packet = std::mem::take(&mut self.partial[i]);
+                                if let Some((to, values)) = route(i, packet) {
+                                    self.queues[to].extend(values);
+                                    delivered = true;
+                                }
+                            }
+                        }
+                        Event::Halted => self.halted[i] = true,
+                    }
+                }
+            }
+            if !delivered {
+                return Ok(());
+            }
+        }
+    }
+}
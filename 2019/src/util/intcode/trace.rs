@@ -0,0 +1,173 @@
+//! A record of every instruction a [`State`](super::State) executes, for finding a program's hot
+//! loops and where two runs part ways.
+//!
+//! Set a machine's `trace` to keep the most recent steps in a ring buffer and count how often each
+//! address is executed. Every step can also be written to a file as it happens, one line each, so
+//! that the traces of two runs can be compared with `diff`.
+
+use std::{
+    collections::{BTreeMap, VecDeque},
+    fmt,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use super::Instruction;
+
+/// An executed instruction.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Step {
+    /// How many instructions were executed before this one.
+    pub index: u64,
+    pub pos: usize,
+    pub instruction: Instruction,
+    /// The values of the parameters that were read, in order.
+    pub args: Vec<i128>,
+    /// The address written to, and the value written.
+    pub write: Option<(usize, i128)>,
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:>6}: {}", self.pos, self.instruction)?;
+        if !self.args.is_empty() {
+            let args: Vec<String> = self.args.iter().map(i128::to_string).collect();
+            write!(f, "  ({})", args.join(", "))?;
+        }
+        if let Some((addr, value)) = self.write {
+            write!(f, "  [{addr}] <- {value}")?;
+        }
+        Ok(())
+    }
+}
+
+type Sink = Arc<Mutex<dyn Write + Send>>;
+
+#[derive(Clone)]
+pub struct Trace {
+    steps: VecDeque<Step>,
+    capacity: usize,
+    executed: u64,
+    hits: BTreeMap<usize, u64>,
+    /// Where to write every step, shared between clones of the machine.
+    sink: Option<Sink>,
+    /// The first error writing to the sink, after which it's no longer written to.
+    error: Option<Arc<io::Error>>,
+}
+
+impl Trace {
+    /// Keeps the last `capacity` steps.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            steps: VecDeque::with_capacity(capacity),
+            capacity,
+            executed: 0,
+            hits: BTreeMap::new(),
+            sink: None,
+            error: None,
+        }
+    }
+
+    /// Also writes every step to `writer`, one per line.
+    pub fn with_writer(mut self, writer: impl Write + Send + 'static) -> Self {
+        self.sink = Some(Arc::new(Mutex::new(writer)));
+        self
+    }
+
+    /// Also writes every step to the file at `path`, one per line.
+    pub fn with_file(self, path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(self.with_writer(BufWriter::new(File::create(path)?)))
+    }
+
+    pub(super) fn record(&mut self, step: Step) {
+        self.executed += 1;
+        *self.hits.entry(step.pos).or_default() += 1;
+        if let Some(sink) = &self.sink {
+            let result = writeln!(sink.lock().unwrap(), "{step}");
+            if let Err(e) = result {
+                self.error = Some(Arc::new(e));
+                self.sink = None;
+            }
+        }
+        if self.capacity == 0 {
+            return;
+        }
+        if self.steps.len() == self.capacity {
+            self.steps.pop_front();
+        }
+        self.steps.push_back(step);
+    }
+
+    /// The steps still held, oldest first.
+    pub fn steps(&self) -> impl Iterator<Item = &Step> {
+        self.steps.iter()
+    }
+
+    /// How many instructions have been executed since tracing started.
+    pub fn executed(&self) -> u64 {
+        self.executed
+    }
+
+    /// How many times the instruction at `pos` has been executed.
+    pub fn hits(&self, pos: usize) -> u64 {
+        self.hits.get(&pos).copied().unwrap_or(0)
+    }
+
+    /// Every executed address with its hit count, most executed first.
+    pub fn profile(&self) -> Vec<(usize, u64)> {
+        let mut profile: Vec<(usize, u64)> = self.hits.iter().map(|(&pos, &n)| (pos, n)).collect();
+        profile.sort_by(|(pos_a, a), (pos_b, b)| b.cmp(a).then(pos_a.cmp(pos_b)));
+        profile
+    }
+
+    /// The index of the first step at which this run and `other` differ, comparing only the steps
+    /// both still hold. A run that went on after the other stopped differs at the step it stopped
+    /// at. Returns `None` if the runs are the same as far as can be told.
+    pub fn divergence(&self, other: &Trace) -> Option<u64> {
+        let start = |trace: &Trace| {
+            trace
+                .steps
+                .front()
+                .map_or(trace.executed, |step| step.index)
+        };
+        let start = start(self).max(start(other));
+        if let Some(step) = self
+            .held_from(start)
+            .zip(other.held_from(start))
+            .find_map(|(a, b)| (a != b).then_some(a.index))
+        {
+            return Some(step);
+        }
+        (self.executed != other.executed).then(|| self.executed.min(other.executed))
+    }
+
+    fn held_from(&self, index: u64) -> impl Iterator<Item = &Step> {
+        let start = self.steps.partition_point(|step| step.index < index);
+        self.steps.range(start..)
+    }
+
+    /// Flushes the file or writer steps are written to, returning the first error writing to it.
+    pub fn flush(&self) -> io::Result<()> {
+        if let Some(e) = &self.error {
+            return Err(io::Error::new(e.kind(), e.to_string()));
+        }
+        match &self.sink {
+            Some(sink) => sink.lock().unwrap().flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl fmt::Debug for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Trace")
+            .field("steps", &self.steps)
+            .field("capacity", &self.capacity)
+            .field("executed", &self.executed)
+            .field("hits", &self.hits)
+            .field("sink", &self.sink.is_some())
+            .finish()
+    }
+}
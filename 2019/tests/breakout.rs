@@ -0,0 +1,41 @@
+use advent_of_code_2019::{
+    breakout::{Breakout, Tile},
+    util::intcode::assemble,
+};
+
+/// Draws a wall, a block, the ball and the paddle, then reads the joystick and reports it as the
+/// score.
+const GAME: &str = "
+    out 0
+    out 0
+    out 1
+    out 1
+    out 0
+    out 2
+    out 2
+    out 0
+    out 4
+    out 0
+    out 1
+    out 3
+    in [joystick]
+    out -1
+    out 0
+    out [joystick]
+    hlt
+    joystick: data 0
+";
+
+#[test]
+fn plays_headless() {
+    let mut game = Breakout::new(assemble(GAME).unwrap(), false);
+    let mut frames = Vec::new();
+    let score = game
+        .play_headless(Breakout::autopilot, |frame| frames.push(frame))
+        .unwrap();
+    assert_eq!(score, 1);
+    assert_eq!(frames, ["Score: 0\n#%O\n-..\n", "Score: 1\n#%O\n-..\n"]);
+    assert_eq!(game.blocks(), 1);
+    assert_eq!(game.screen[&(2, 0)], Tile::Ball);
+    assert_eq!((game.ball, game.paddle), (Some((2, 0)), Some((0, 1))));
+}
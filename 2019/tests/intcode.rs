@@ -0,0 +1,379 @@
+use std::collections::{HashSet, VecDeque};
+
+use advent_of_code_2019::{
+    util::intcode::{
+        self, assemble, disassemble, Ascii, AsciiOutput, Debugger, Error, ErrorKind, Event,
+        FlatMem, IoEvent, Memory, Network, State, Step, Trace,
+    },
+    YEAR,
+};
+
+/// Day 9's example that outputs a copy of itself.
+const QUINE: &str = "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99";
+
+#[test]
+fn disassembles_with_modes_addresses_and_labels() {
+    let program = intcode::parse_input(QUINE);
+    assert_eq!(
+        disassemble(&program),
+        "\
+L0:
+     0: arb 1
+     2: out [rb-1]
+     4: add [100], 1, [100]
+     8: eq [100], 16, [101]
+    12: jz [101], L0
+    15: hlt
+"
+    );
+}
+
+#[test]
+fn round_trips_every_program() {
+    let mut programs = vec![intcode::parse_input(QUINE)];
+    for day in [2, 5, 7, 9, 11, 13, 15] {
+        if let Ok(input) = YEAR.read_input(day) {
+            programs.push(intcode::parse_input(&input));
+        }
+    }
+    for program in programs {
+        assert_eq!(assemble(&disassemble(&program)).unwrap(), program);
+    }
+}
+
+#[test]
+fn assembles_hand_written_programs() {
+    let program = assemble(DOUBLER).unwrap();
+    assert_eq!(
+        program,
+        [3, 15, 1006, 15, 14, 1002, 15, 2, 15, 4, 15, 1105, 1, 0, 99, 0]
+    );
+
+    let mut inputs = [3, 5, 0].into_iter();
+    let mut outputs = Vec::new();
+    State::new(program)
+        .run_to_completion(|| inputs.next().unwrap(), |x| outputs.push(x))
+        .unwrap();
+    assert_eq!(outputs, [6, 10]);
+}
+
+/// Outputs its input doubled, until it reads a zero.
+const DOUBLER: &str = "
+    loop: in [n]
+          jz [n], end
+          mul [n], 2, [n]
+          out [n]
+          jnz 1, loop
+    end:  hlt
+    n:    data 0
+";
+
+#[test]
+fn debugs_a_scripted_session() {
+    let mut debugger = Debugger::new(State::new(assemble(DOUBLER).unwrap()));
+    let commands = "\
+        break out\n\
+        continue\n\
+        input 21 0\n\
+        continue\n\
+        regs\n\
+        mem 15\n\
+        set 15 50\n\
+        step 2\n\
+        delete out\n\
+        continue\n\
+        bogus\n";
+    let mut out = Vec::new();
+    debugger.repl(commands.as_bytes(), &mut out).unwrap();
+
+    let out = String::from_utf8(out).unwrap();
+    let out: Vec<&str> = out.split("(intcode) ").collect();
+    assert_eq!(
+        out,
+        [
+            "",
+            "",
+            "Waiting for input at 0; queue some with input\n",
+            "",
+            "input: 21\nBreakpoint at 9: out [15]\n",
+            "pc: 9  relative base: 0\n",
+            "    15: 42 0 0 0 0 0 0 0\n",
+            "",
+            "     9: out [15]\noutput: 50\n    11: jnz 1, 0\n",
+            "",
+            "input: 0\nHalted at 14\n",
+            "Unknown command \"bogus\"; try help\n",
+            "\n",
+        ]
+    );
+    assert_eq!(
+        debugger.io_log,
+        [IoEvent::Input(21), IoEvent::Output(50), IoEvent::Input(0)]
+    );
+}
+
+#[test]
+fn yields_events() {
+    let mut state = State::new(assemble(DOUBLER).unwrap());
+    let mut inputs = VecDeque::new();
+    assert_eq!(state.resume(&mut inputs), Ok(Event::NeedsInput));
+    inputs.extend([4, 0]);
+    assert_eq!(state.resume(&mut inputs), Ok(Event::Output(8)));
+    assert_eq!(state.resume(&mut inputs), Ok(Event::Halted));
+}
+
+#[test]
+fn runs_a_feedback_loop() {
+    // Day 7's second feedback loop example.
+    let program = intcode::parse_input(
+        "3,26,1001,26,-4,26,3,27,1002,27,2,27,1,27,26,27,4,27,1001,28,-1,28,1005,28,6,99,0,0,5",
+    );
+    let mut network = Network::new(vec![State::new(program); 5], 1);
+    for (i, phase) in [9, 8, 7, 6, 5].into_iter().enumerate() {
+        network.send(i, &[phase]);
+    }
+    network.send(0, &[0]);
+    let mut thrust = None;
+    network
+        .run(|from, signal| {
+            if from == 4 {
+                thrust = Some(signal[0]);
+            }
+            Some(((from + 1) % 5, signal))
+        })
+        .unwrap();
+    assert!(network.all_halted());
+    assert_eq!(thrust, Some(139629729));
+}
+
+#[test]
+fn routes_packets() {
+    // Reads its address and sends the next machine a packet of its address and the first value it
+    // receives, forever. Machine 0 receives nothing but the idle -1.
+    let program = assemble(
+        "
+              in [addr]
+              add [addr], 1, [next]
+        loop: in [value]
+              eq [value], -1, [idle]
+              jnz [idle], loop
+              out [next]
+              out [addr]
+              out [value]
+              jnz 1, loop
+        addr: data 0
+        next: data 0
+        value: data 0
+        idle: data 0
+        ",
+    )
+    .unwrap();
+    let mut network = Network::new(vec![State::new(program); 3], 3).with_idle_input(-1);
+    for i in 0..3 {
+        network.send(i, &[i as i128]);
+    }
+    network.send(1, &[10]);
+
+    let mut left = Vec::new();
+    network
+        .run(|from, packet| match packet[..] {
+            [to @ 0..=2, x, y] => Some((to as usize, vec![x * 10 + y])),
+            _ => {
+                left.push((from, packet));
+                None
+            }
+        })
+        .unwrap();
+    assert!(!network.all_halted());
+    assert_eq!(left, [(2, vec![3, 2, 20])]);
+}
+
+#[test]
+fn faults_without_executing() {
+    let fault = |program: Vec<i128>| {
+        let mut state = State::new(program);
+        let error = state.resume(&mut VecDeque::new()).unwrap_err();
+        (state, error)
+    };
+
+    let (state, error) = fault(vec![1101, 1, 2, 5, 42, 0]);
+    assert_eq!(state.pos, 4);
+    assert_eq!(
+        error,
+        Error {
+            pos: 4,
+            opcode: 42,
+            kind: ErrorKind::UnknownOpcode
+        }
+    );
+    assert_eq!(error.to_string(), "Unknown opcode 42 at 4");
+
+    let (state, error) = fault(vec![11101, 1, 2, 3, 99]);
+    assert_eq!(state.mem[3], 3);
+    assert_eq!(error.kind, ErrorKind::ImmediateWrite { param: 2 });
+    assert_eq!(
+        error.to_string(),
+        "Parameter 2 of 11101 at 0 is written to in immediate mode"
+    );
+
+    let (_, error) = fault(vec![109, -5, 204, 1, 99]);
+    assert_eq!(
+        error.kind,
+        ErrorKind::NegativeAddress { param: 0, addr: -4 }
+    );
+
+    let (_, error) = fault(vec![301, 0, 0, 0, 99]);
+    assert_eq!(error.kind, ErrorKind::InvalidMode { param: 0, mode: 3 });
+}
+
+#[test]
+fn traces_and_profiles() {
+    let path = std::env::temp_dir().join(format!("intcode-trace-{}.txt", std::process::id()));
+    let mut state = State::new(intcode::parse_input(QUINE));
+    state.trace = Some(Trace::new(4).with_file(&path).unwrap());
+    state.run_to_completion(|| 0, |_| ()).unwrap();
+
+    let trace = state.trace.take().unwrap();
+    trace.flush().unwrap();
+    assert_eq!(trace.executed(), 81);
+    assert_eq!(trace.hits(0), 16);
+    assert_eq!(trace.hits(15), 1);
+    assert_eq!(trace.profile()[..2], [(0, 16), (2, 16)]);
+    let steps: Vec<&Step> = trace.steps().collect();
+    assert_eq!(steps.len(), 4);
+    assert_eq!((steps[0].index, steps[3].index), (77, 80));
+    assert_eq!(
+        steps[0].to_string(),
+        "     4: add [100], 1, [100]  (15, 1)  [100] <- 16"
+    );
+
+    let lines = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let lines: Vec<&str> = lines.lines().collect();
+    assert_eq!(lines.len(), 81);
+    assert_eq!(
+        lines[..2],
+        ["     0: arb 1  (1)", "     2: out [rb-1]  (109)"]
+    );
+
+    let run = |first: i128| {
+        let mut program = intcode::parse_input(QUINE);
+        program.resize(101, 0);
+        program[100] = first;
+        let mut state = State::new(program);
+        state.trace = Some(Trace::new(100));
+        state.run_to_completion(|| 0, |_| ()).unwrap();
+        state.trace.unwrap()
+    };
+    assert_eq!(run(0).divergence(&run(0)), None);
+    assert_eq!(run(0).divergence(&run(1)), Some(2));
+
+    // The target of a jump that isn't taken is never read, even when it's out of bounds.
+    let mut state = State::new(vec![105, 0, -1, 99]);
+    state.trace = Some(Trace::new(2));
+    state.run_to_completion(|| 0, |_| ()).unwrap();
+    let trace = state.trace.unwrap();
+    assert_eq!(trace.steps().next().unwrap().args, [0]);
+}
+
+#[test]
+fn forks_and_deduplicates_states() {
+    let mut state = State::new(assemble(DOUBLER).unwrap());
+    state.mem[1000] = 7;
+    let snapshot = state.clone();
+    let mut forks = [state.clone(), state];
+    assert_eq!(forks[0].run_one_in_one_out(3), Ok(Some(6)));
+    assert_eq!(forks[1].run_one_in_one_out(4), Ok(Some(8)));
+    assert_ne!(forks[0], forks[1]);
+    assert_eq!(snapshot.mem[15], 0);
+    assert_eq!((forks[0].mem[15], forks[1].mem[15]), (6, 8));
+
+    // Writing back the same values makes the machines equal again, though they no longer share
+    // their memory, and a page of zeros is the same as no page.
+    forks[1].mem[15] = 6;
+    forks[1].mem[5000] = 0;
+    assert_eq!(forks[0], forks[1]);
+    let seen: HashSet<State> = [snapshot, forks[0].clone(), forks[1].clone()].into();
+    assert_eq!(seen.len(), 2);
+    assert_eq!((forks[0].mem.len(), forks[1].mem.len()), (1001, 5001));
+}
+
+#[test]
+fn runs_the_same_in_flat_memory() {
+    let program = intcode::parse_input(QUINE);
+    let mut outputs = [Vec::new(), Vec::new()];
+    State::new(program.clone())
+        .run_to_completion(|| 0, |x| outputs[0].push(x))
+        .unwrap();
+    let mut state = State::with_memory(FlatMem::new(program.clone()));
+    state
+        .run_to_completion(|| 0, |x| outputs[1].push(x))
+        .unwrap();
+    assert_eq!(outputs[0], program);
+    assert_eq!(outputs[1], program);
+    assert_eq!(state.mem[100], 16);
+    assert_eq!(state.mem[100_000], 0);
+
+    state.mem[100_000] = 1;
+    assert_eq!(state.mem.len(), 100_001);
+    let mut copy = state.clone();
+    copy.mem[200_000] = 0;
+    assert_eq!(copy, state);
+}
+
+/// Echoes what it reads until it reads a `!`, then outputs a value that isn't a character.
+const ECHO: &str = "
+    loop: in [c]
+          eq [c], 33, [done]
+          jnz [done], end
+          out [c]
+          jnz 1, loop
+    end:  out 12345
+          hlt
+    c:    data 0
+    done: data 0
+";
+
+#[test]
+fn speaks_ascii() {
+    let mut ascii = Ascii::new(State::new(assemble(ECHO).unwrap()));
+    ascii.push_line("hello");
+    ascii.push_str("world");
+    let output = ascii.run().unwrap();
+    assert_eq!(output.lines().collect::<Vec<_>>(), ["hello", "world"]);
+    assert!(output.answers.is_empty() && !output.halted);
+
+    ascii.push_line("!");
+    assert_eq!(
+        ascii.run().unwrap(),
+        AsciiOutput {
+            text: String::new(),
+            answers: vec![12345],
+            halted: true,
+        }
+    );
+
+    let mut ascii = Ascii::new(State::new(assemble(ECHO).unwrap()));
+    let mut out = Vec::new();
+    let output = ascii
+        .play("hi\nthere!\nunread\n".as_bytes(), &mut out)
+        .unwrap();
+    assert!(output.halted);
+    assert_eq!(String::from_utf8(out).unwrap(), "hi\nthere12345\n");
+}
+
+#[test]
+fn reports_errors_by_line() {
+    assert_eq!(
+        assemble("in [x]\nadd 1, 2, 3").unwrap_err(),
+        "Line 2: add can't write to an immediate operand"
+    );
+    assert_eq!(
+        assemble("jz 0, nowhere").unwrap_err(),
+        "Line 1: label nowhere isn't defined"
+    );
+    assert_eq!(
+        assemble("hlt\n2: hlt").unwrap_err(),
+        "Line 2: expected address 2, but it's 1"
+    );
+}
@@ -0,0 +1,27 @@
+use std::fmt::Display;
+
+use crate::util::Solution;
+
+pub(crate) struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Self::Input {
+        input.lines().map(|x| x.parse().unwrap()).collect()
+    }
+
+    fn part_1(depths: &Self::Input) -> impl Display {
+        depths.array_windows().filter(|[x, y]| x < y).count()
+    }
+
+    fn part_2(depths: &Self::Input) -> impl Display {
+        depths
+            .array_windows()
+            .map(|[x, y, z]| x + y + z)
+            .collect::<Vec<i32>>()
+            .array_windows()
+            .filter(|[x, y]| x < y)
+            .count()
+    }
+}
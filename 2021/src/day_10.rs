@@ -1,12 +1,17 @@
-use util::PerfTimer;
+use std::fmt::Display;
 
-extern crate util;
+use crate::util::Solution;
 
-fn main() {
-    let input = util::get_day_input(10);
+pub(crate) struct Day10;
 
-    {
-        let _timer = PerfTimer::new("Part 1");
+impl Solution for Day10 {
+    type Input = String;
+
+    fn parse(input: &str) -> Self::Input {
+        input.to_owned()
+    }
+
+    fn part_1(input: &Self::Input) -> impl Display {
         let mut part1 = 0i128;
         for line in input.lines() {
             let mut remaining = vec![];
@@ -30,11 +35,10 @@ fn main() {
                 }
             }
         }
-        println!("Part 1: {}", part1);
+        part1
     }
 
-    {
-        let _timer = PerfTimer::new("Part 2");
+    fn part_2(input: &Self::Input) -> impl Display {
         let mut scores = vec![];
         for line in input.lines() {
             let mut remaining = vec![];
@@ -70,7 +74,6 @@ fn main() {
             scores.push(score)
         }
         scores.sort_unstable();
-        let part2 = scores[scores.len() / 2];
-        println!("Part 2: {}", part2);
+        scores[scores.len() / 2]
     }
 }
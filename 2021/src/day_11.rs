@@ -0,0 +1,53 @@
+use std::fmt::Display;
+
+use aoc_common::Grid;
+
+use crate::util::Solution;
+
+/// Advances the octopuses by one step, returning how many of them flashed.
+fn step(grid: &mut Grid<i32>) -> usize {
+    let mut to_flash = vec![];
+    for pos in grid.positions() {
+        grid[pos] += 1;
+        if grid[pos] == 10 {
+            to_flash.push(pos);
+        }
+    }
+
+    let mut flashed = vec![];
+    while let Some(pos) = to_flash.pop() {
+        for neighbour in grid.neighbours8(pos).collect::<Vec<_>>() {
+            grid[neighbour] += 1;
+            if grid[neighbour] == 10 {
+                to_flash.push(neighbour);
+            }
+        }
+        flashed.push(pos);
+    }
+    let flash_count = flashed.len();
+    for pos in flashed {
+        grid[pos] = 0;
+    }
+    flash_count
+}
+
+pub(crate) struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid<i32>;
+
+    fn parse(input: &str) -> Self::Input {
+        Grid::parse(input, |c| Some(c as i32 - '0' as i32)).unwrap()
+    }
+
+    fn part_1(grid: &Self::Input) -> impl Display {
+        let mut grid = grid.clone();
+        (0..100).map(|_| step(&mut grid)).sum::<usize>()
+    }
+
+    fn part_2(grid: &Self::Input) -> impl Display {
+        let mut grid = grid.clone();
+        let octopus_count = grid.width() * grid.height();
+        (1..).find(|_| step(&mut grid) == octopus_count).unwrap()
+    }
+}
@@ -1,31 +1,33 @@
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
 
-use util::PerfTimer;
+use crate::util::Solution;
 
-extern crate util;
+pub(crate) struct Day12;
 
-fn input() -> HashMap<String, HashSet<String>> {
-    util::get_day_input(12)
-        .lines()
-        .flat_map(|l| {
-            let (left, right) = l.split_once('-').unwrap();
-            [(left, right), (right, left)]
-        })
-        .fold(HashMap::new(), |mut m, (l, r)| {
-            if let Some(s) = m.get_mut(l) {
-                s.insert(r.to_owned());
-            } else {
-                m.insert(l.to_owned(), HashSet::from([r.to_owned()]));
-            }
-            m
-        })
-}
+impl Solution for Day12 {
+    type Input = HashMap<String, HashSet<String>>;
 
-fn main() {
-    let graph = input();
+    fn parse(input: &str) -> Self::Input {
+        input
+            .lines()
+            .flat_map(|l| {
+                let (left, right) = l.split_once('-').unwrap();
+                [(left, right), (right, left)]
+            })
+            .fold(HashMap::new(), |mut m, (l, r)| {
+                if let Some(s) = m.get_mut(l) {
+                    s.insert(r.to_owned());
+                } else {
+                    m.insert(l.to_owned(), HashSet::from([r.to_owned()]));
+                }
+                m
+            })
+    }
 
-    {
-        let _timer = PerfTimer::new("Part 1");
+    fn part_1(graph: &Self::Input) -> impl Display {
         let mut paths = vec![(HashSet::<String>::new(), "start")];
         let mut path_count = 0;
         while let Some((mut visited, location)) = paths.pop() {
@@ -41,11 +43,11 @@ fn main() {
                 paths.push((visited.clone(), cave))
             }
         }
-        println!("Part 1: {}", path_count);
+
+        path_count
     }
 
-    {
-        let _timer = PerfTimer::new("Part 2");
+    fn part_2(graph: &Self::Input) -> impl Display {
         let mut paths = vec![(HashSet::<String>::new(), false, "start")];
         let mut path_count = 0;
         while let Some((mut visited, twice, location)) = paths.pop() {
@@ -69,6 +71,7 @@ fn main() {
                 paths.push((visited.clone(), twice, cave))
             }
         }
-        println!("Part 2: {}", path_count);
+
+        path_count
     }
 }
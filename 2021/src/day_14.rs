@@ -1,34 +1,11 @@
-#![feature(array_windows)]
-#![feature(iter_intersperse)]
-#![feature(slice_group_by)]
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt::Display};
 
+use crate::util::Solution;
 use itertools::Itertools;
-use util::PerfTimer;
 
-extern crate util;
-
-fn input() -> (Vec<char>, HashMap<(char, char), char>) {
-    let raw = util::get_day_input(14);
-    let mut lines = raw.lines();
-    let template = lines.next().unwrap().chars().collect();
-    lines.next();
-    let rules = lines
-        .map(|l| {
-            let mut chars = l.chars();
-            let a = chars.next().unwrap();
-            let b = chars.next().unwrap();
-            let c = chars.nth(4).unwrap();
-            ((a, b), c)
-        })
-        .collect();
-    (template, rules)
-}
-
-fn main() {
-    let (template, rules) = input();
-
-    let _timer = PerfTimer::new("Both parts");
+/// Applies the insertion rules `steps` times, returning the difference between the counts of the
+/// most and least common elements.
+fn polymerize(template: &[char], rules: &HashMap<(char, char), char>, steps: usize) -> u128 {
     let polymer_counts = template.array_windows().map(|&[a, b]| (a, b)).counts();
     let mut polymer_counts: HashMap<(char, char), u128> = polymer_counts
         .into_iter()
@@ -41,7 +18,7 @@ fn main() {
         .map(|(&k, v)| (k, v as u128))
         .collect();
 
-    for step in 1..=40 {
+    for _ in 0..steps {
         let mut new_polymers = HashMap::new();
         for (&(a, b), &count) in polymer_counts.iter() {
             let &c = rules.get(&(a, b)).unwrap();
@@ -62,16 +39,39 @@ fn main() {
             }
         }
         polymer_counts = new_polymers;
-        if step == 10 {
-            let count_min = counts.values().min().unwrap();
-            let count_max = counts.values().max().unwrap();
-            let part1 = count_max - count_min;
-            println!("Part 1: {}", part1);
-        }
     }
 
     let count_min = counts.values().min().unwrap();
     let count_max = counts.values().max().unwrap();
-    let part2 = count_max - count_min;
-    println!("Part 2: {}", part2);
+    count_max - count_min
+}
+
+pub(crate) struct Day14;
+
+impl Solution for Day14 {
+    type Input = (Vec<char>, HashMap<(char, char), char>);
+
+    fn parse(input: &str) -> Self::Input {
+        let mut lines = input.lines();
+        let template = lines.next().unwrap().chars().collect();
+        lines.next();
+        let rules = lines
+            .map(|l| {
+                let mut chars = l.chars();
+                let a = chars.next().unwrap();
+                let b = chars.next().unwrap();
+                let c = chars.nth(4).unwrap();
+                ((a, b), c)
+            })
+            .collect();
+        (template, rules)
+    }
+
+    fn part_1((template, rules): &Self::Input) -> impl Display {
+        polymerize(template, rules, 10)
+    }
+
+    fn part_2((template, rules): &Self::Input) -> impl Display {
+        polymerize(template, rules, 40)
+    }
 }
@@ -0,0 +1,58 @@
+use crate::util::Solution;
+use ansi_term::Colour;
+use aoc_common::{dijkstra, Grid, Pos};
+use std::fmt::Display;
+
+fn find_min_cost(grid: &Grid<i32>) -> i32 {
+    let target = (grid.width() - 1, grid.height() - 1);
+    let path = dijkstra(
+        [(0, 0)],
+        |&pos: &Pos| grid.neighbours4(pos).map(|next| (next, grid[next])),
+        |&pos| pos == target,
+    )
+    .unwrap();
+    // print_path(grid, &path.states);
+    path.cost
+}
+
+#[allow(dead_code)]
+fn print_path(grid: &Grid<i32>, path: &[Pos]) {
+    for (y, l) in grid.rows().enumerate() {
+        for (x, v) in l.iter().enumerate() {
+            if path.contains(&(x, y)) {
+                print!("{}", Colour::Green.paint(v.to_string()));
+            } else {
+                print!("{}", v);
+            }
+        }
+        println!();
+    }
+}
+
+pub(crate) struct Day15;
+
+impl Solution for Day15 {
+    type Input = Grid<i32>;
+
+    fn parse(input: &str) -> Self::Input {
+        Grid::parse(input, |c| Some(c.to_digit(10)? as i32)).unwrap()
+    }
+
+    fn part_1(grid: &Self::Input) -> impl Display {
+        find_min_cost(grid)
+    }
+
+    fn part_2(grid: &Self::Input) -> impl Display {
+        let (width, height) = (grid.width(), grid.height());
+        let large_grid = Grid::from_fn(width * 5, height * 5, |(x, y)| {
+            let tile = (x / width + y / height) as i32;
+            let mut new_v = grid[(x % width, y % height)] + tile;
+            while new_v > 9 {
+                new_v -= 9
+            }
+            new_v
+        });
+
+        find_min_cost(&large_grid)
+    }
+}
@@ -1,37 +1,9 @@
-use util::PerfTimer;
+use std::fmt::Display;
+
+use crate::util::Solution;
 use Contents::*;
 use OperatorType::*;
 
-extern crate util;
-
-fn input() -> Vec<i32> {
-    util::get_day_input(16)
-        .lines()
-        .next()
-        .unwrap()
-        .chars()
-        .flat_map(|c| match c {
-            '0' => [0, 0, 0, 0],
-            '1' => [0, 0, 0, 1],
-            '2' => [0, 0, 1, 0],
-            '3' => [0, 0, 1, 1],
-            '4' => [0, 1, 0, 0],
-            '5' => [0, 1, 0, 1],
-            '6' => [0, 1, 1, 0],
-            '7' => [0, 1, 1, 1],
-            '8' => [1, 0, 0, 0],
-            '9' => [1, 0, 0, 1],
-            'A' => [1, 0, 1, 0],
-            'B' => [1, 0, 1, 1],
-            'C' => [1, 1, 0, 0],
-            'D' => [1, 1, 0, 1],
-            'E' => [1, 1, 1, 0],
-            'F' => [1, 1, 1, 1],
-            _ => panic!(),
-        })
-        .collect()
-}
-
 #[derive(Debug)]
 enum OperatorType {
     Sum,
@@ -65,7 +37,7 @@ enum Contents {
 }
 
 #[derive(Debug)]
-struct Packet {
+pub(crate) struct Packet {
     version: i128,
     contents: Contents,
 }
@@ -184,18 +156,46 @@ fn eval_packet(packet: &Packet) -> i128 {
     }
 }
 
-fn main() {
-    let inp = input();
-
-    let part_1_timer = PerfTimer::new("Part 1");
-    let part_2_timer = PerfTimer::new("Part 2");
+pub(crate) struct Day16;
+
+impl Solution for Day16 {
+    type Input = Packet;
+
+    fn parse(input: &str) -> Self::Input {
+        let bits: Vec<i32> = input
+            .lines()
+            .next()
+            .unwrap()
+            .chars()
+            .flat_map(|c| match c {
+                '0' => [0, 0, 0, 0],
+                '1' => [0, 0, 0, 1],
+                '2' => [0, 0, 1, 0],
+                '3' => [0, 0, 1, 1],
+                '4' => [0, 1, 0, 0],
+                '5' => [0, 1, 0, 1],
+                '6' => [0, 1, 1, 0],
+                '7' => [0, 1, 1, 1],
+                '8' => [1, 0, 0, 0],
+                '9' => [1, 0, 0, 1],
+                'A' => [1, 0, 1, 0],
+                'B' => [1, 0, 1, 1],
+                'C' => [1, 1, 0, 0],
+                'D' => [1, 1, 0, 1],
+                'E' => [1, 1, 1, 0],
+                'F' => [1, 1, 1, 1],
+                _ => panic!(),
+            })
+            .collect();
+        let (_left, root_packet) = parse_packet(&bits);
+        root_packet
+    }
 
-    let (_left, root_packet) = parse_packet(&inp);
-    let part1 = sum_versions(&root_packet);
-    println!("Part 1: {}", part1);
-    drop(part_1_timer);
+    fn part_1(root_packet: &Self::Input) -> impl Display {
+        sum_versions(root_packet)
+    }
 
-    let part2 = eval_packet(&root_packet);
-    println!("Part 2: {}", part2);
-    drop(part_2_timer);
+    fn part_2(root_packet: &Self::Input) -> impl Display {
+        eval_packet(root_packet)
+    }
 }
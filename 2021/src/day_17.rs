@@ -0,0 +1,68 @@
+use std::fmt::Display;
+
+use crate::util::Solution;
+
+/// The lowest horizontal velocity that still leaves the probe within the target area once drag
+/// has stopped it.
+fn min_x_vel(x_min: i32, x_max: i32) -> i32 {
+    (1..)
+        .find(|x| {
+            let p = (x * (x + 1)) / 2;
+            p >= x_min && p <= x_max
+        })
+        .unwrap()
+}
+
+pub(crate) struct Day17;
+
+impl Solution for Day17 {
+    type Input = ((i32, i32), (i32, i32));
+
+    fn parse(input: &str) -> Self::Input {
+        let (x_range, y_range) = input
+            .trim()
+            .strip_prefix("target area: x=")
+            .unwrap()
+            .split_once(", y=")
+            .unwrap();
+        let parse_range = |range: &str| {
+            let (min, max) = range.split_once("..").unwrap();
+            (min.parse().unwrap(), max.parse().unwrap())
+        };
+        (parse_range(x_range), parse_range(y_range))
+    }
+
+    fn part_1(&(_, (y_min, _)): &Self::Input) -> impl Display {
+        let y_vel = -y_min - 1;
+        (y_vel * (y_vel + 1)) / 2
+    }
+
+    fn part_2(&((x_min, x_max), (y_min, y_max)): &Self::Input) -> impl Display {
+        let x_vel = min_x_vel(x_min, x_max);
+        let y_vel = -y_min - 1;
+        let x_vel_min = x_vel;
+        let x_vel_max = x_max;
+        let y_vel_min = y_min;
+        let y_vel_max = y_vel;
+        let mut part2 = 0;
+        for x in x_vel_min..=x_vel_max {
+            for y in y_vel_min..=y_vel_max {
+                let (mut x_vel, mut y_vel) = (x, y);
+                let (mut x_pos, mut y_pos) = (0, 0);
+                while x_pos <= x_max && y_pos >= y_min {
+                    x_pos += x_vel;
+                    y_pos += y_vel;
+                    if x_pos >= x_min && x_pos <= x_max && y_pos >= y_min && y_pos <= y_max {
+                        part2 += 1;
+                        break;
+                    }
+                    if x_vel > 0 {
+                        x_vel -= 1;
+                    }
+                    y_vel -= 1;
+                }
+            }
+        }
+        part2
+    }
+}
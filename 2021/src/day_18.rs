@@ -1,10 +1,11 @@
-extern crate util;
+use std::fmt;
+
+use crate::util::Solution;
 use itertools::Itertools;
-use util::PerfTimer;
 use Number::*;
 
 #[derive(PartialEq, Eq, Clone)]
-enum Number {
+pub(crate) enum Number {
     Literal(i32),
     Pair(Box<Number>, Box<Number>),
 }
@@ -26,22 +27,12 @@ fn parse_number(inp: &[char]) -> (&[char], Number) {
         assert_eq!(inp[0], ']');
         (&inp[1..], Pair(Box::new(left), Box::new(right)))
     } else {
-        let num: String = inp
-            .iter()
-            .take_while(|&&c| ('0'..='9').contains(&c))
-            .collect();
+        let num: String = inp.iter().take_while(|&&c| c.is_ascii_digit()).collect();
         let inp = &inp[num.len()..];
         (inp, Literal(num.parse().unwrap()))
     }
 }
 
-fn input() -> Vec<Number> {
-    let raw = util::get_day_input(18);
-    raw.lines()
-        .map(|l| parse_number(&l.chars().collect_vec()).1)
-        .collect()
-}
-
 fn reduce(mut num: Number) -> Number {
     fn explode_down_left(number: Number, i: i32) -> Number {
         match number {
@@ -139,24 +130,32 @@ fn magnitude(num: Number) -> i32 {
     }
 }
 
-fn main() {
-    let numbers = input();
+pub(crate) struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Number>;
 
-    {
-        let _timer = PerfTimer::new("Part 1");
+    fn parse(input: &str) -> Self::Input {
+        input
+            .lines()
+            .map(|l| parse_number(&l.chars().collect_vec()).1)
+            .collect()
+    }
+
+    fn part_1(numbers: &Self::Input) -> impl fmt::Display {
         let added = numbers.iter().cloned().reduce(add).unwrap();
-        let part1 = magnitude(added);
-        println!("Part 1: {}", part1);
+
+        magnitude(added)
     }
 
-    {
-        let _timer = PerfTimer::new("Part 2");
+    fn part_2(numbers: &Self::Input) -> impl fmt::Display {
         let mut part2 = 0;
         for i in numbers.iter().cloned() {
-            for j in numbers.iter().cloned().filter(|x| *x != i) {
+            for j in numbers.iter().filter(|&x| *x != i).cloned() {
                 part2 = std::cmp::max(part2, magnitude(add(i.clone(), j)))
             }
         }
-        println!("Part 2: {}", part2)
+
+        part2
     }
 }
@@ -1,42 +1,15 @@
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
 
+use crate::util::Solution;
 use itertools::Itertools;
-use util::PerfTimer;
-
-extern crate util;
 
 type Point = (i32, i32, i32);
 
 type Scanner = Vec<Point>;
 
-fn input() -> Vec<Scanner> {
-    let raw = util::get_day_input(19);
-    let mut scanners = Vec::new();
-    let mut lines = raw.lines();
-    loop {
-        let _scanner_line = lines.next().unwrap();
-        let mut scanner = Vec::new();
-        loop {
-            let line = lines.next();
-            if line.is_none() {
-                scanners.push(scanner);
-                return scanners;
-            }
-            if line.unwrap().is_empty() {
-                break;
-            }
-            scanner.push(
-                line.unwrap()
-                    .split(',')
-                    .map(|s| s.parse().unwrap())
-                    .collect_tuple()
-                    .unwrap(),
-            )
-        }
-        scanners.push(scanner);
-    }
-}
-
 fn mul(point_a: Point, point_b: Point) -> Point {
     (
         point_a.0 * point_b.0,
@@ -133,47 +106,84 @@ fn locate_scanner(
     }
 }
 
-fn main() {
-    let inp = input();
-    // dbg!(inp);
-
-    let part_1_timer = PerfTimer::new("Part 1");
-    let part_2_timer = PerfTimer::new("Part 2");
-    let mut origin = inp[0].clone();
-    let mut remaining: HashMap<usize, Vec<(i32, i32, i32)>> =
-        inp[1..].iter().cloned().enumerate().collect();
-    let mut scanner_locations: HashMap<usize, Point> = HashMap::new();
-    let mut locations: HashSet<Point> = HashSet::new();
-    for p in origin.clone() {
-        locations.insert(p);
+fn parse_scanners(input: &str) -> Vec<Scanner> {
+    let mut scanners = Vec::new();
+    let mut lines = input.lines();
+    loop {
+        let _scanner_line = lines.next().unwrap();
+        let mut scanner = Vec::new();
+        loop {
+            let line = lines.next();
+            if line.is_none() {
+                scanners.push(scanner);
+                return scanners;
+            }
+            if line.unwrap().is_empty() {
+                break;
+            }
+            scanner.push(
+                line.unwrap()
+                    .split(',')
+                    .map(|s| s.parse().unwrap())
+                    .collect_tuple()
+                    .unwrap(),
+            )
+        }
+        scanners.push(scanner);
     }
-    while !remaining.is_empty() {
-        let mut left = HashMap::new();
-        for (k, v) in remaining {
-            if let Some((rotation_index, scanner_loc)) = locate_scanner(&origin, &v) {
-                scanner_locations.insert(k, scanner_loc);
-                for beacon in v {
-                    let beacon_loc = relative_beacon_to_origin(scanner_loc, rotation_index, beacon);
-                    if locations.insert(beacon_loc) {
-                        origin.push(beacon_loc);
+}
+
+pub(crate) struct Day19;
+
+impl Solution for Day19 {
+    /// Every beacon location, and the location of each scanner other than the first, relative to
+    /// the first scanner.
+    type Input = (HashSet<Point>, HashMap<usize, Point>);
+
+    // Aligning the scanners is the bulk of the work for both parts, so it's done once here.
+    fn parse(input: &str) -> Self::Input {
+        let scanners = parse_scanners(input);
+        let mut origin = scanners[0].clone();
+        let mut remaining: HashMap<usize, Vec<(i32, i32, i32)>> =
+            scanners[1..].iter().cloned().enumerate().collect();
+        let mut scanner_locations: HashMap<usize, Point> = HashMap::new();
+        let mut locations: HashSet<Point> = HashSet::new();
+        for p in origin.clone() {
+            locations.insert(p);
+        }
+        while !remaining.is_empty() {
+            let mut left = HashMap::new();
+            for (k, v) in remaining {
+                if let Some((rotation_index, scanner_loc)) = locate_scanner(&origin, &v) {
+                    scanner_locations.insert(k, scanner_loc);
+                    for beacon in v {
+                        let beacon_loc =
+                            relative_beacon_to_origin(scanner_loc, rotation_index, beacon);
+                        if locations.insert(beacon_loc) {
+                            origin.push(beacon_loc);
+                        }
                     }
+                } else {
+                    left.insert(k, v);
                 }
-            } else {
-                left.insert(k, v);
             }
+            remaining = left;
         }
-        remaining = left;
+        (locations, scanner_locations)
     }
-    println!("Part 1: {}", locations.len());
-    drop(part_1_timer);
-
-    let mut part2 = 0;
-    for &(x_a, y_a, z_a) in scanner_locations.values() {
-        for &(x_b, y_b, z_b) in scanner_locations.values() {
-            let distance = x_a.abs_diff(x_b) + y_a.abs_diff(y_b) + z_a.abs_diff(z_b);
-            part2 = std::cmp::max(part2, distance);
+
+    fn part_1((locations, _): &Self::Input) -> impl Display {
+        locations.len()
+    }
+
+    fn part_2((_, scanner_locations): &Self::Input) -> impl Display {
+        let mut part2 = 0;
+        for &(x_a, y_a, z_a) in scanner_locations.values() {
+            for &(x_b, y_b, z_b) in scanner_locations.values() {
+                let distance = x_a.abs_diff(x_b) + y_a.abs_diff(y_b) + z_a.abs_diff(z_b);
+                part2 = std::cmp::max(part2, distance);
+            }
         }
+        part2
     }
-    println!("Part 2: {}", part2);
-    drop(part_2_timer);
 }
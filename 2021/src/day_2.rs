@@ -1,11 +1,9 @@
-use std::str::FromStr;
+use std::{fmt::Display, str::FromStr};
 
-use util::PerfTimer;
-
-extern crate util;
+use crate::util::Solution;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Instruction {
+pub(crate) enum Instruction {
     Forward(i32),
     Down(i32),
     Up(i32),
@@ -28,18 +26,16 @@ impl FromStr for Instruction {
     }
 }
 
-fn input() -> Vec<Instruction> {
-    util::get_day_input(2)
-        .lines()
-        .map(|x| x.parse().unwrap())
-        .collect()
-}
+pub(crate) struct Day2;
 
-fn main() {
-    let instructions = input();
+impl Solution for Day2 {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Self::Input {
+        input.lines().map(|x| x.parse().unwrap()).collect()
+    }
 
-    {
-        let _timer = PerfTimer::new("Part 1");
+    fn part_1(instructions: &Self::Input) -> impl Display {
         let destination = instructions
             .iter()
             .map(|&instruction| match instruction {
@@ -50,11 +46,10 @@ fn main() {
             .reduce(|(x1, y1), (x2, y2)| (x1 + x2, y1 + y2))
             .unwrap();
 
-        println!("Part 1: {}", destination.0 * destination.1);
+        destination.0 * destination.1
     }
 
-    {
-        let _timer = PerfTimer::new("Part 2");
+    fn part_2(instructions: &Self::Input) -> impl Display {
         let destination_aim = instructions
             .iter()
             .fold((0, 0, 0), |(x, y, aim), &instruction| match instruction {
@@ -63,6 +58,6 @@ fn main() {
                 Instruction::Up(i) => (x, y, aim - i),
             });
 
-        println!("Part 2: {}", destination_aim.0 * destination_aim.1);
+        destination_aim.0 * destination_aim.1
     }
 }
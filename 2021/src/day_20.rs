@@ -0,0 +1,87 @@
+use std::fmt::Display;
+
+use aoc_common::Grid;
+
+use crate::util::Solution;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Image {
+    pixels: Grid<bool>,
+    /// The colour of every pixel outside `pixels`, out to infinity.
+    default: bool,
+}
+
+impl Image {
+    fn get(&self, x: i32, y: i32) -> bool {
+        match (usize::try_from(x), usize::try_from(y)) {
+            (Ok(x), Ok(y)) => self.pixels.get((x, y)).copied().unwrap_or(self.default),
+            _ => self.default,
+        }
+    }
+}
+
+fn enhance(alg: &[bool], image: &Image) -> Image {
+    let pixels = Grid::from_fn(
+        image.pixels.width() + 4,
+        image.pixels.height() + 4,
+        |(x, y)| {
+            let x_old = x as i32 - 2;
+            let y_old = y as i32 - 2;
+
+            let mut num = 0;
+            for j in y_old - 1..=y_old + 1 {
+                for i in x_old - 1..=x_old + 1 {
+                    num *= 2;
+                    if image.get(i, j) {
+                        num += 1;
+                    }
+                }
+            }
+            alg[num]
+        },
+    );
+    let default = if image.default { alg[511] } else { alg[0] };
+    Image { pixels, default }
+}
+
+pub(crate) struct Day20;
+
+impl Solution for Day20 {
+    type Input = (Vec<bool>, Image);
+
+    fn parse(input: &str) -> Self::Input {
+        let (raw_alg, raw_image) = input.split_once("\n\n").unwrap();
+        let alg: Vec<bool> = raw_alg.chars().map(parse_symbol).collect();
+
+        fn parse_symbol(c: char) -> bool {
+            match c {
+                '#' => true,
+                '.' => false,
+                _ => panic!(),
+            }
+        }
+
+        let image = Grid::parse(raw_image, |c| Some(parse_symbol(c))).unwrap();
+        (
+            alg,
+            Image {
+                default: false,
+                pixels: image,
+            },
+        )
+    }
+
+    fn part_1((alg, image): &Self::Input) -> impl Display {
+        let result = enhance(alg, &enhance(alg, image));
+
+        result.pixels.iter().filter(|&(_, &x)| x).count()
+    }
+
+    fn part_2((alg, image): &Self::Input) -> impl Display {
+        let mut image = image.clone();
+        for _ in 0..50 {
+            image = enhance(alg, &image);
+        }
+        image.pixels.iter().filter(|&(_, &x)| x).count()
+    }
+}
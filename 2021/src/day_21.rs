@@ -1,14 +1,6 @@
-use util::PerfTimer;
+use std::fmt::Display;
 
-extern crate util;
-
-fn input() -> (i32, i32) {
-    let raw = util::get_day_input(21);
-    let mut lines = raw.lines();
-    let p1_start = lines.next().unwrap()[28..].parse().unwrap();
-    let p2_start = lines.next().unwrap()[28..].parse().unwrap();
-    (p1_start, p2_start)
-}
+use crate::util::Solution;
 
 fn roll(dice: &mut i32) -> i32 {
     *dice += 1;
@@ -49,11 +41,19 @@ fn play_part_2(
     (p1_wins, p2_wins)
 }
 
-fn main() {
-    let (p1_start, p2_start) = input();
+pub(crate) struct Day21;
+
+impl Solution for Day21 {
+    type Input = (i32, i32);
+
+    fn parse(input: &str) -> Self::Input {
+        let mut lines = input.lines();
+        let p1_start = lines.next().unwrap()[28..].parse().unwrap();
+        let p2_start = lines.next().unwrap()[28..].parse().unwrap();
+        (p1_start, p2_start)
+    }
 
-    {
-        let _timer = PerfTimer::new("Part 1");
+    fn part_1(&(p1_start, p2_start): &Self::Input) -> impl Display {
         let mut p1_pos = p1_start;
         let mut p2_pos = p2_start;
         let mut p1_score = 0;
@@ -69,9 +69,7 @@ fn main() {
             p1_score += p1_pos;
 
             if p1_score >= 1000 {
-                let part1 = p2_score * dice;
-                println!("Part 1: {part1}");
-                break;
+                return p2_score * dice;
             }
 
             let p2_move = get_move(&mut dice);
@@ -82,18 +80,14 @@ fn main() {
             p2_score += p2_pos;
 
             if p2_score >= 1000 {
-                let part1 = p1_score * dice;
-                println!("Part 1: {part1}");
-                break;
+                return p1_score * dice;
             }
         }
     }
 
-    {
-        let _timer = PerfTimer::new("Part 2");
+    fn part_2(&(p1_start, p2_start): &Self::Input) -> impl Display {
         let dirac_dice = [(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)];
         let (part2_p1, part2_p2) = play_part_2(&dirac_dice, p1_start, p2_start, 0, 0);
-        let part2 = std::cmp::max(part2_p1, part2_p2);
-        println!("Part 2: {part2}");
+        std::cmp::max(part2_p1, part2_p2)
     }
 }
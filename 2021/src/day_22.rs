@@ -1,44 +1,18 @@
+use crate::util::Solution;
 use regex::Regex;
-use std::cmp::{max, min};
-use util::PerfTimer;
-
-extern crate util;
+use std::{
+    cmp::{max, min},
+    fmt::Display,
+};
 
 #[derive(Debug, Clone, Copy)]
-struct Instruction {
+pub(crate) struct Instruction {
     on: bool,
     x_range: (i32, i32),
     y_range: (i32, i32),
     z_range: (i32, i32),
 }
 
-fn input() -> Vec<Instruction> {
-    let raw = util::get_day_input(22);
-    let re =
-        Regex::new(r"^(on|off) x=(-?\d+)\.\.(-?\d+),y=(-?\d+)\.\.(-?\d+),z=(-?\d+)\.\.(-?\d+)$")
-            .unwrap();
-    raw.lines()
-        .map(|line| {
-            let captures = re.captures(line).unwrap();
-            let on = match &captures[1] {
-                "on" => true,
-                "off" => false,
-                _ => panic!(),
-            };
-            let x_range = (captures[2].parse().unwrap(), captures[3].parse().unwrap());
-            let y_range = (captures[4].parse().unwrap(), captures[5].parse().unwrap());
-            let z_range = (captures[6].parse().unwrap(), captures[7].parse().unwrap());
-
-            Instruction {
-                on,
-                x_range,
-                y_range,
-                z_range,
-            }
-        })
-        .collect()
-}
-
 fn calc_instruction_volume(instruction: Instruction) -> i128 {
     let calc_range = |(a, b)| (b - a + 1) as i128;
     calc_range(instruction.x_range)
@@ -46,12 +20,40 @@ fn calc_instruction_volume(instruction: Instruction) -> i128 {
         * calc_range(instruction.z_range)
 }
 
-fn main() {
-    let inp = input();
-    // dbg!(inp);
+pub(crate) struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Self::Input {
+        let re = Regex::new(
+            r"^(on|off) x=(-?\d+)\.\.(-?\d+),y=(-?\d+)\.\.(-?\d+),z=(-?\d+)\.\.(-?\d+)$",
+        )
+        .unwrap();
+        input
+            .lines()
+            .map(|line| {
+                let captures = re.captures(line).unwrap();
+                let on = match &captures[1] {
+                    "on" => true,
+                    "off" => false,
+                    _ => panic!(),
+                };
+                let x_range = (captures[2].parse().unwrap(), captures[3].parse().unwrap());
+                let y_range = (captures[4].parse().unwrap(), captures[5].parse().unwrap());
+                let z_range = (captures[6].parse().unwrap(), captures[7].parse().unwrap());
+
+                Instruction {
+                    on,
+                    x_range,
+                    y_range,
+                    z_range,
+                }
+            })
+            .collect()
+    }
 
-    {
-        let _timer = PerfTimer::new("Part 1");
+    fn part_1(inp: &Self::Input) -> impl Display {
         let rev: Vec<Instruction> = inp.iter().copied().rev().collect();
         let mut part1 = 0;
         for x in -50..=50 {
@@ -71,14 +73,13 @@ fn main() {
                 }
             }
         }
-        println!("Part 1: {}", part1);
+        part1
     }
 
-    {
-        let _timer = PerfTimer::new("Part 2");
+    fn part_2(inp: &Self::Input) -> impl Display {
         let mut layers: Vec<Instruction> = vec![];
 
-        for &inst in &inp {
+        for &inst in inp {
             let mut new_layers = vec![];
             for &layer in &layers {
                 let x_min = max(inst.x_range.0, layer.x_range.0);
@@ -107,7 +108,7 @@ fn main() {
             }
         }
 
-        let part2: i128 = layers
+        layers
             .into_iter()
             .map(|l| {
                 if l.on {
@@ -116,7 +117,6 @@ fn main() {
                     -calc_instruction_volume(l)
                 }
             })
-            .sum();
-        println!("Part 2: {}", part2);
+            .sum::<i128>()
     }
 }
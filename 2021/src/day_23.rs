@@ -1,15 +1,13 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt::Display};
 
+use crate::util::Solution;
+use aoc_common::dijkstra;
 use lazy_static::lazy_static;
-use priority_queue::PriorityQueue;
-use util::{get_day_input, PerfTimer};
 use Piece::*;
 use Tile::*;
 
-extern crate util;
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-enum Piece {
+pub(crate) enum Piece {
     A,
     B,
     C,
@@ -17,7 +15,7 @@ enum Piece {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-enum Tile {
+pub(crate) enum Tile {
     Wall,
     Empty,
     Has(Piece),
@@ -37,45 +35,6 @@ lazy_static! {
     static ref COLUMN: HashMap<Piece, usize> = HashMap::from([(A, 3), (B, 5), (C, 7), (D, 9)]);
 }
 
-fn input() -> (Grid<5>, Grid<7>) {
-    let raw = get_day_input(23);
-    let lines_1 = raw.lines().take_while(|&l| !l.is_empty());
-    let lines_2 = raw.lines().skip_while(|&l| !l.is_empty()).skip(1);
-
-    let mut grid_1 = [[Wall; 5]; 13];
-    for (y, line) in lines_1.enumerate() {
-        for (x, c) in line.chars().enumerate() {
-            grid_1[x][y] = match c {
-                '.' => Empty,
-                '#' => Wall,
-                'A' => Has(A),
-                'B' => Has(B),
-                'C' => Has(C),
-                'D' => Has(D),
-                ' ' => Wall,
-                _ => panic!(),
-            }
-        }
-    }
-
-    let mut grid_2 = [[Wall; 7]; 13];
-    for (y, line) in lines_2.enumerate() {
-        for (x, c) in line.chars().enumerate() {
-            grid_2[x][y] = match c {
-                '.' => Empty,
-                '#' => Wall,
-                'A' => Has(A),
-                'B' => Has(B),
-                'C' => Has(C),
-                'D' => Has(D),
-                ' ' => Wall,
-                _ => panic!(),
-            }
-        }
-    }
-    (grid_1, grid_2)
-}
-
 fn swap<const H: usize>(
     (grid, cost): &State<H>,
     (x_1, y_1): Point,
@@ -149,62 +108,54 @@ fn is_done<const H: usize>(grid: &Grid<H>) -> bool {
         .all(|(&piece, &x)| (2..H - 1).all(|y| grid[x][y] == Has(piece)))
 }
 
-fn cost_lower_bound<const H: usize>((grid, cost): &State<H>) -> usize {
-    let mut lower_bound = 0usize;
-    for (x, line) in grid.iter().enumerate() {
-        for (y, &c) in line.iter().enumerate() {
-            if let Has(ref piece) = c {
-                let mut bound_x = 0;
-                if x == COLUMN[piece] {
-                    if !(y + 1..H - 1).all(|y| grid[x][y] == Has(*piece)) {
-                        bound_x += y * 2;
+fn play<const H: usize>(grid: Grid<H>) -> usize {
+    // Each move is costed from zero, so the moves from a grid are its successors with their costs.
+    dijkstra([grid], |&grid| gen_moves((grid, 0)), is_done)
+        .unwrap()
+        .cost
+}
+
+pub(crate) struct Day23;
+
+impl Solution for Day23 {
+    type Input = (Grid<5>, Grid<7>);
+
+    fn parse(input: &str) -> Self::Input {
+        fn read_grid<const H: usize>(lines: &[&str]) -> Grid<H> {
+            let mut grid = [[Wall; H]; 13];
+            for (y, line) in lines.iter().enumerate() {
+                for (x, c) in line.chars().enumerate() {
+                    grid[x][y] = match c {
+                        '.' => Empty,
+                        '#' => Wall,
+                        'A' => Has(A),
+                        'B' => Has(B),
+                        'C' => Has(C),
+                        'D' => Has(D),
+                        ' ' => Wall,
+                        _ => panic!(),
                     }
-                } else {
-                    bound_x += (y - 1) + x.abs_diff(COLUMN[piece]) + 1;
                 }
-                lower_bound += bound_x * COST[piece];
-            }
-        }
-    }
-    for (&piece, &x) in COLUMN.iter() {
-        for y in 3..H - 1 {
-            if grid[x][y] != Has(piece) {
-                lower_bound += y - 2
             }
+            grid
         }
-    }
-    lower_bound + cost
-}
 
-fn play<const H: usize>(grid: Grid<H>) -> usize {
-    let mut pq = PriorityQueue::new();
-    let initial_state = (grid, 0);
-    let initial_lower_bound_estimate = cost_lower_bound(&initial_state);
-    pq.push(initial_state, -(initial_lower_bound_estimate as i64));
-    loop {
-        let (current_state, _) = pq.pop().unwrap();
-        let next_states = gen_moves(current_state);
-        for state in next_states {
-            if is_done(&state.0) {
-                return state.1;
-            }
-            let lower_bound_estimate = cost_lower_bound(&state);
-            pq.push(state, -(lower_bound_estimate as i64));
-        }
+        let mut lines: Vec<&str> = input.lines().take_while(|&l| !l.is_empty()).collect();
+        let grid_1 = read_grid(&lines);
+
+        // Part 2 unfolds the diagram, adding two rows between the first and second amphipods of
+        // each room.
+        lines.splice(3..3, ["  #D#C#B#A#", "  #D#B#A#C#"]);
+        let grid_2 = read_grid(&lines);
+
+        (grid_1, grid_2)
     }
-}
 
-fn main() {
-    let (grid_1, grid_2) = input();
-    {
-        let _timer = PerfTimer::new("Part 1");
-        let part_1 = play(grid_1);
-        println!("Part 1: {}", part_1);
+    fn part_1((grid_1, _): &Self::Input) -> impl Display {
+        play(*grid_1)
     }
 
-    {
-        let _timer = PerfTimer::new("Part 2");
-        let part_2 = play(grid_2);
-        println!("Part 2: {}", part_2);
+    fn part_2((_, grid_2): &Self::Input) -> impl Display {
+        play(*grid_2)
     }
 }
@@ -1,6 +1,6 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt::Display};
 
-use util::get_day_input;
+use crate::util::Solution;
 use Argument::*;
 use Instruction::*;
 
@@ -20,13 +20,13 @@ impl<'a> State<'a> {
 }
 
 #[derive(Clone, Copy, Debug)]
-enum Argument {
+pub(crate) enum Argument {
     Literal(isize),
     Var(char),
 }
 
 #[derive(Clone, Copy, Debug)]
-enum Instruction {
+pub(crate) enum Instruction {
     Inp(char),
     Add(char, Argument),
     Mul(char, Argument),
@@ -50,25 +50,6 @@ fn parse_arg(arg: &str) -> Argument {
     }
 }
 
-fn input() -> Vec<Instruction> {
-    get_day_input(24)
-        .lines()
-        .map(|l| {
-            let parts: Vec<&str> = l.split(' ').collect();
-            let var = parts[1].chars().next().unwrap();
-            match parts[0] {
-                "inp" => Inp(var),
-                "add" => Add(var, parse_arg(parts[2])),
-                "mul" => Mul(var, parse_arg(parts[2])),
-                "div" => Div(var, parse_arg(parts[2])),
-                "mod" => Mod(var, parse_arg(parts[2])),
-                "eql" => Eql(var, parse_arg(parts[2])),
-                _ => panic!(),
-            }
-        })
-        .collect()
-}
-
 fn exec_instruction(instruction: Instruction, mut state: State) -> State {
     match instruction {
         Inp(var) => {
@@ -87,26 +68,58 @@ fn exec_instruction(instruction: Instruction, mut state: State) -> State {
     state
 }
 
-fn main() {
-    let instructions = input();
-
-    // let model_number = 91599994399395isize;  // Part 1
-    let model_number = 71111591176151isize; // Part 2
-    let input = model_number.to_string();
-    // if input.chars().contains(&'0') {
-    //     continue;
-    // }
-    let input: Vec<isize> = input
+fn accepts_model_number(instructions: &[Instruction], model_number: isize) -> bool {
+    let input: Vec<isize> = model_number
+        .to_string()
         .chars()
         .map(|c| c.to_string().parse().unwrap())
         .collect();
 
     let mut state = State::new(&input);
-    for &instruction in &instructions {
+    for &instruction in instructions {
         state = exec_instruction(instruction, state)
     }
 
-    println!("z = {}", state.vars[&'z']);
+    state.vars[&'z'] == 0
+}
+
+// The model numbers were found by hand (see the pseudocode and spreadsheet below), so this only
+// checks that MONAD accepts them.
+pub(crate) struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .lines()
+            .map(|l| {
+                let parts: Vec<&str> = l.split(' ').collect();
+                let var = parts[1].chars().next().unwrap();
+                match parts[0] {
+                    "inp" => Inp(var),
+                    "add" => Add(var, parse_arg(parts[2])),
+                    "mul" => Mul(var, parse_arg(parts[2])),
+                    "div" => Div(var, parse_arg(parts[2])),
+                    "mod" => Mod(var, parse_arg(parts[2])),
+                    "eql" => Eql(var, parse_arg(parts[2])),
+                    _ => panic!(),
+                }
+            })
+            .collect()
+    }
+
+    fn part_1(instructions: &Self::Input) -> impl Display {
+        let part1 = 91599994399395isize;
+        assert!(accepts_model_number(instructions, part1));
+        part1
+    }
+
+    fn part_2(instructions: &Self::Input) -> impl Display {
+        let part2 = 71111591176151isize;
+        assert!(accepts_model_number(instructions, part2));
+        part2
+    }
 }
 
 // Pseudocode:
@@ -0,0 +1,92 @@
+use std::fmt::Display;
+
+use aoc_common::Grid;
+
+use crate::util::Solution;
+use Position::*;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum Position {
+    Empty,
+    EastCucumber,
+    SouthCucumber,
+}
+
+impl TryFrom<char> for Position {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, char> {
+        match c {
+            '.' => Ok(Empty),
+            '>' => Ok(EastCucumber),
+            'v' => Ok(SouthCucumber),
+            c => Err(c),
+        }
+    }
+}
+
+impl From<Position> for char {
+    fn from(position: Position) -> Self {
+        match position {
+            Empty => '.',
+            EastCucumber => '>',
+            SouthCucumber => 'v',
+        }
+    }
+}
+
+/// Moves every cucumber in the `herd` that can by `step`, wrapping around the edges.
+fn move_herd(
+    grid: &Grid<Position>,
+    herd: Position,
+    step: (usize, usize),
+) -> (Grid<Position>, bool) {
+    let mut moved = false;
+    let mut new_grid = grid.clone();
+    for ((x, y), &p) in grid.iter() {
+        if p != herd {
+            continue;
+        }
+        let new_pos = ((x + step.0) % grid.width(), (y + step.1) % grid.height());
+        if grid[new_pos] == Empty {
+            new_grid[new_pos] = herd;
+            new_grid[(x, y)] = Empty;
+            moved = true;
+        }
+    }
+    (new_grid, moved)
+}
+
+fn generate_next_state(grid: &Grid<Position>) -> (Grid<Position>, bool) {
+    let (grid, moved_east) = move_herd(grid, EastCucumber, (1, 0));
+    let (grid, moved_south) = move_herd(&grid, SouthCucumber, (0, 1));
+    (grid, moved_east || moved_south)
+}
+
+pub(crate) struct Day25;
+
+impl Solution for Day25 {
+    type Input = Grid<Position>;
+
+    const HAS_PART_2: bool = false;
+
+    fn parse(input: &str) -> Self::Input {
+        input.parse().unwrap()
+    }
+
+    fn part_1(grid: &Self::Input) -> impl Display {
+        let mut grid = grid.clone();
+        let mut step = 0;
+
+        loop {
+            step += 1;
+            let (g, changed) = generate_next_state(&grid);
+            grid = g;
+            if !changed {
+                break;
+            }
+        }
+
+        step
+    }
+}
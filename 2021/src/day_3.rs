@@ -1,13 +1,6 @@
-use util::PerfTimer;
+use std::fmt::Display;
 
-extern crate util;
-
-pub fn input() -> Vec<Vec<char>> {
-    util::get_day_input(3)
-        .lines()
-        .map(|s| s.chars().collect())
-        .collect()
-}
+use crate::util::Solution;
 
 fn common_bits<'a, I: IntoIterator<Item = &'a char>>(
     bits: I,
@@ -44,11 +37,16 @@ fn part2(inp: &[Vec<char>], selector: impl Fn((i32, i32)) -> char) -> i64 {
     bin_to_dec(remaining[0])
 }
 
-pub fn main() {
-    let inp = input();
+pub(crate) struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Vec<char>>;
+
+    fn parse(input: &str) -> Self::Input {
+        input.lines().map(|s| s.chars().collect()).collect()
+    }
 
-    {
-        let _timer = PerfTimer::new("Part 1");
+    fn part_1(inp: &Self::Input) -> impl Display {
         let (gamma, epsilon) = inp
             .iter()
             .fold(vec![(0, 0); inp[0].len()], |mut counts, num| {
@@ -66,13 +64,12 @@ pub fn main() {
             .fold((0i64, 0i64), |(acc_a, acc_b), (x_a, x_b)| {
                 (acc_a * 2 + x_a, acc_b * 2 + x_b)
             });
-        println!("Part 1: {}", gamma * epsilon);
+        gamma * epsilon
     }
 
-    {
-        let _timer = PerfTimer::new("Part 2");
-        let oxygen = part2(&inp, |x| if x.0 > x.1 { '0' } else { '1' });
-        let co2 = part2(&inp, |x| if x.1 < x.0 { '1' } else { '0' });
-        println!("Part 2: {}", oxygen * co2);
+    fn part_2(inp: &Self::Input) -> impl Display {
+        let oxygen = part2(inp, |x| if x.0 > x.1 { '0' } else { '1' });
+        let co2 = part2(inp, |x| if x.1 < x.0 { '1' } else { '0' });
+        oxygen * co2
     }
 }
@@ -1,27 +1,9 @@
-use util::PerfTimer;
+use std::fmt::Display;
 
-extern crate util;
-
-fn input() -> (Vec<i32>, Vec<Vec<Vec<i32>>>) {
-    let lines: Vec<String> = util::get_day_input(4).lines().map(str::to_owned).collect();
-
-    let calls: Vec<i32> = lines[0].split(',').map(|s| s.parse().unwrap()).collect();
-
-    let grid_lines = &lines[2..];
-    let grid_chunks = grid_lines.split(|s| s.is_empty());
-    let grids: Vec<Vec<Vec<i32>>> = grid_chunks
-        .map(|ls| {
-            ls.iter()
-                .map(|l| l.split_whitespace().map(|s| s.parse().unwrap()).collect())
-                .collect()
-        })
-        .collect();
-
-    (calls, grids)
-}
+use crate::util::Solution;
 
 #[derive(Clone)]
-struct Grid {
+pub(crate) struct Grid {
     grid: Vec<Vec<i32>>,
     calls: Vec<Vec<bool>>,
 }
@@ -56,7 +38,7 @@ impl Grid {
         }
 
         for (i, j) in wins {
-            if self.calls[i].iter().all(|x| *x) | self.calls.iter().map(|l| l[j]).all(|x| x) {
+            if self.calls[i].iter().all(|x| *x) | self.calls.iter().all(|l| l[j]) {
                 return Some(self.calc_win(self.grid[i][j]));
             }
         }
@@ -65,7 +47,7 @@ impl Grid {
     }
 }
 
-fn part_1(calls: &[i32], mut grids: Vec<Grid>) -> i32 {
+fn first_winning_score(calls: &[i32], mut grids: Vec<Grid>) -> i32 {
     for &call in calls {
         for grid in grids.iter_mut() {
             if let Some(score) = grid.call(call) {
@@ -76,7 +58,7 @@ fn part_1(calls: &[i32], mut grids: Vec<Grid>) -> i32 {
     panic!("Nobody won!!!");
 }
 
-fn part_2(calls: &[i32], mut grids: Vec<Grid>) -> i32 {
+fn last_winning_score(calls: &[i32], mut grids: Vec<Grid>) -> i32 {
     let mut winners = vec![false; grids.len()];
     let mut losing_score = -1;
 
@@ -94,19 +76,34 @@ fn part_2(calls: &[i32], mut grids: Vec<Grid>) -> i32 {
     losing_score
 }
 
-fn main() {
-    let (calls, grids) = input();
-    let grids: Vec<Grid> = grids.iter().map(|s| Grid::new(s.clone())).collect();
+pub(crate) struct Day4;
+
+impl Solution for Day4 {
+    type Input = (Vec<i32>, Vec<Grid>);
+
+    fn parse(input: &str) -> Self::Input {
+        let lines: Vec<String> = input.lines().map(str::to_owned).collect();
+
+        let calls: Vec<i32> = lines[0].split(',').map(|s| s.parse().unwrap()).collect();
+
+        let grid_lines = &lines[2..];
+        let grid_chunks = grid_lines.split(|s| s.is_empty());
+        let grids: Vec<Vec<Vec<i32>>> = grid_chunks
+            .map(|ls| {
+                ls.iter()
+                    .map(|l| l.split_whitespace().map(|s| s.parse().unwrap()).collect())
+                    .collect()
+            })
+            .collect();
+
+        (calls, grids.into_iter().map(Grid::new).collect())
+    }
 
-    {
-        let _timer = PerfTimer::new("Part 1");
-        let p1 = part_1(&calls, grids.clone());
-        println!("Part 1: {p1}");
+    fn part_1((calls, grids): &Self::Input) -> impl Display {
+        first_winning_score(calls, grids.clone())
     }
 
-    {
-        let _timer = PerfTimer::new("Part 2");
-        let p2 = part_2(&calls, grids);
-        println!("Part 2: {p2}");
+    fn part_2((calls, grids): &Self::Input) -> impl Display {
+        last_winning_score(calls, grids.clone())
     }
 }
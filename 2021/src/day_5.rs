@@ -1,31 +1,11 @@
 #![allow(clippy::type_complexity)]
 
 use std::cmp::{max, min};
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt::Display};
 
-use util::PerfTimer;
+use crate::util::Solution;
 
-extern crate util;
-
-fn input() -> Vec<((usize, usize), (usize, usize))> {
-    util::get_day_input(5)
-        .lines()
-        .map(|l| {
-            let mut parts = l.split(" -> ");
-            let mut left = parts.next().unwrap().split(',');
-            let mut right = parts.next().unwrap().split(',');
-
-            let x1 = left.next().unwrap().parse().unwrap();
-            let y1 = left.next().unwrap().parse().unwrap();
-            let x2 = right.next().unwrap().parse().unwrap();
-            let y2 = right.next().unwrap().parse().unwrap();
-
-            ((x1, y1), (x2, y2))
-        })
-        .collect()
-}
-
-fn run(inp: &[((usize, usize), (usize, usize))], part2: bool) -> usize {
+fn count_overlaps(inp: &[((usize, usize), (usize, usize))], part2: bool) -> usize {
     let mut map: HashMap<(usize, usize), i32> = HashMap::new();
     for &((x1, y1), (x2, y2)) in inp {
         let x_min = min(x1, x2);
@@ -65,18 +45,34 @@ fn run(inp: &[((usize, usize), (usize, usize))], part2: bool) -> usize {
     map.values().filter(|&&x| x >= 2).count()
 }
 
-fn main() {
-    let inp = input();
+pub(crate) struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vec<((usize, usize), (usize, usize))>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .lines()
+            .map(|l| {
+                let mut parts = l.split(" -> ");
+                let mut left = parts.next().unwrap().split(',');
+                let mut right = parts.next().unwrap().split(',');
+
+                let x1 = left.next().unwrap().parse().unwrap();
+                let y1 = left.next().unwrap().parse().unwrap();
+                let x2 = right.next().unwrap().parse().unwrap();
+                let y2 = right.next().unwrap().parse().unwrap();
+
+                ((x1, y1), (x2, y2))
+            })
+            .collect()
+    }
 
-    {
-        let _timer = PerfTimer::new("Part 1");
-        let part1 = run(&inp, false);
-        println!("Part 1: {part1}");
+    fn part_1(inp: &Self::Input) -> impl Display {
+        count_overlaps(inp, false)
     }
 
-    {
-        let _timer = PerfTimer::new("Part 2");
-        let part2 = run(&inp, true);
-        println!("Part 2: {part2}");
+    fn part_2(inp: &Self::Input) -> impl Display {
+        count_overlaps(inp, true)
     }
 }
@@ -0,0 +1,33 @@
+use std::fmt::Display;
+
+use crate::util::Solution;
+
+pub(crate) struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Self::Input {
+        let mut inp: Vec<i32> = input
+            .trim()
+            .split(',')
+            .map(|x| x.parse().unwrap())
+            .collect();
+        inp.sort_unstable();
+        inp
+    }
+
+    fn part_1(inp: &Self::Input) -> impl Display {
+        let p1_index = inp.len() / 2;
+        let p1_point = inp[p1_index];
+        inp.iter().map(|x| x.abs_diff(p1_point)).sum::<u32>()
+    }
+
+    fn part_2(inp: &Self::Input) -> impl Display {
+        let calc = |x| x * (x + 1) / 2;
+        (0..1000)
+            .map(|p| inp.iter().map(|x| calc(x.abs_diff(p) as u64)).sum::<u64>())
+            .min()
+            .unwrap()
+    }
+}
@@ -1,45 +1,44 @@
-#![feature(hash_drain_filter)]
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
 
-use std::collections::{HashMap, HashSet};
+use crate::util::Solution;
 
-use util::PerfTimer;
-
-extern crate util;
-
-struct Display {
+pub(crate) struct Display {
     digits: [String; 10],
     display: [String; 4],
 }
 
-fn input() -> Vec<Display> {
-    let raw = util::get_day_input(8);
-    raw.lines()
-        .into_iter()
-        .map(|s| {
-            if let Some((l, r)) = s.split_once(" | ") {
-                fn read_to_arr<const N: usize>(s: &str) -> [String; N] {
-                    s.split_whitespace()
-                        .map(|s| s.to_string())
-                        .collect::<Vec<String>>()
-                        .try_into()
-                        .unwrap()
-                }
-                Display {
-                    digits: read_to_arr(l),
-                    display: read_to_arr(r),
+pub(crate) struct Day8;
+
+impl Solution for Day8 {
+    type Input = Vec<Display>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .lines()
+            .map(|s| {
+                if let Some((l, r)) = s.split_once(" | ") {
+                    fn read_to_arr<const N: usize>(s: &str) -> [String; N] {
+                        s.split_whitespace()
+                            .map(|s| s.to_string())
+                            .collect::<Vec<String>>()
+                            .try_into()
+                            .unwrap()
+                    }
+                    Display {
+                        digits: read_to_arr(l),
+                        display: read_to_arr(r),
+                    }
+                } else {
+                    panic!("AHHHHHH");
                 }
-            } else {
-                panic!("AHHHHHH");
-            }
-        })
-        .collect()
-}
-
-fn main() {
-    let inp = input();
+            })
+            .collect()
+    }
 
-    {
-        let _timer = PerfTimer::new("Part 1");
+    fn part_1(inp: &Self::Input) -> impl fmt::Display {
         let p1_lens = [2, 4, 3, 7];
         let part1: usize = inp
             .iter()
@@ -50,11 +49,11 @@ fn main() {
                     .count()
             })
             .sum();
-        println!("Part 1: {}", part1);
+
+        part1
     }
 
-    {
-        let _timer = PerfTimer::new("Part 2");
+    fn part_2(inp: &Self::Input) -> impl fmt::Display {
         let digit_strings = [
             "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
         ];
@@ -76,7 +75,7 @@ fn main() {
 
             for (keys, values) in (2..=7).map(|i| {
                 (
-                    chars_in_digits_of_len(&digits, i),
+                    chars_in_digits_of_len(digits, i),
                     chars_in_digits_of_len(
                         &digit_strings
                             .iter()
@@ -89,9 +88,9 @@ fn main() {
                 for k in 'a'..='g' {
                     let v = possible_mappings.get_mut(&k).unwrap();
                     if keys.contains(&k) {
-                        v.drain_filter(|x| !values.contains(x));
+                        v.retain(|x| values.contains(x));
                     } else {
-                        v.drain_filter(|x| values.contains(x));
+                        v.retain(|x| !values.contains(x));
                     }
                 }
             }
@@ -116,7 +115,7 @@ fn main() {
                         .collect();
                 }
                 possible_displays
-                    .drain_filter(|s| HashSet::<char>::from_iter(s.chars()).len() != s.len());
+                    .retain(|s| HashSet::<char>::from_iter(s.chars()).len() == s.len());
 
                 possible_displays = possible_displays
                     .into_iter()
@@ -127,7 +126,7 @@ fn main() {
                     })
                     .collect();
 
-                possible_displays.drain_filter(|s| !digit_strings.contains(&s.as_str()));
+                possible_displays.retain(|s| digit_strings.contains(&s.as_str()));
                 assert!(possible_displays.len() == 1);
                 let final_display = possible_displays.into_iter().next().unwrap();
                 let final_digit = digit_strings
@@ -140,6 +139,6 @@ fn main() {
             sum += display_sum;
         }
 
-        println!("Part 2: {}", sum);
+        sum
     }
 }
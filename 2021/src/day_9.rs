@@ -0,0 +1,51 @@
+use std::{collections::HashSet, fmt::Display, ops::Mul};
+
+use aoc_common::{Grid, Pos};
+use itertools::Itertools;
+
+use crate::util::Solution;
+
+/// The size of the basin that `low_point` is in, found by flooding out to the 9s around it.
+fn basin_size(map: &Grid<i32>, low_point: Pos) -> usize {
+    let mut basin = HashSet::from([low_point]);
+    let mut to_visit = vec![low_point];
+    while let Some(pos) = to_visit.pop() {
+        for neighbour in map.neighbours4(pos) {
+            if map[neighbour] < 9 && basin.insert(neighbour) {
+                to_visit.push(neighbour);
+            }
+        }
+    }
+    basin.len()
+}
+
+fn low_points(map: &Grid<i32>) -> impl Iterator<Item = Pos> + '_ {
+    map.positions().filter(|&pos| {
+        map.neighbours4(pos)
+            .all(|neighbour| map[neighbour] > map[pos])
+    })
+}
+
+pub(crate) struct Day9;
+
+impl Solution for Day9 {
+    type Input = Grid<i32>;
+
+    fn parse(input: &str) -> Self::Input {
+        Grid::parse(input, |c| Some(c as i32 - '0' as i32)).unwrap()
+    }
+
+    fn part_1(map: &Self::Input) -> impl Display {
+        low_points(map).map(|pos| map[pos] + 1).sum::<i32>()
+    }
+
+    fn part_2(map: &Self::Input) -> impl Display {
+        low_points(map)
+            .map(|pos| basin_size(map, pos))
+            .sorted()
+            .rev()
+            .take(3)
+            .reduce(usize::mul)
+            .unwrap()
+    }
+}
@@ -0,0 +1,37 @@
+use std::fmt::Display;
+
+use itertools::Itertools;
+
+use crate::util::Solution;
+
+pub(crate) struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<Vec<u64>>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .trim()
+            .split("\n\n")
+            .map(|s| s.lines().map(|l| l.parse().unwrap()).collect::<Vec<_>>())
+            .collect()
+    }
+
+    fn part_1(inventory: &Self::Input) -> impl Display {
+        inventory
+            .iter()
+            .map(|ls| ls.iter().sum::<u64>())
+            .max()
+            .unwrap()
+    }
+
+    fn part_2(inventory: &Self::Input) -> impl Display {
+        inventory
+            .iter()
+            .map(|ls| ls.iter().sum::<u64>())
+            .sorted()
+            .rev()
+            .take(3)
+            .sum::<u64>()
+    }
+}
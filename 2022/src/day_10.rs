@@ -0,0 +1,91 @@
+use std::fmt::Display;
+
+use itertools::Itertools;
+
+use crate::util::Solution;
+
+#[derive(Clone, Copy)]
+pub(crate) enum Instruction {
+    NoOp,
+    AddX(i64),
+}
+
+fn cycle_v(cycle: i64, reg_x: i64) -> i64 {
+    if (cycle - 20) % 40 == 0 {
+        cycle * reg_x
+    } else {
+        0
+    }
+}
+
+fn draw_pixel(pixels: &mut [[bool; 40]; 6], cycle: i64, reg_x: i64) {
+    if reg_x.abs_diff((cycle - 1) % 40) < 2 {
+        let pos_x = (cycle as usize - 1) % 40;
+        let pos_y = (cycle as usize - 1) / 40;
+        pixels[pos_y][pos_x] = true;
+    }
+}
+
+/// Runs the program, returning the sum of the signal strengths and the pixels drawn on the CRT.
+fn run_program(instructions: &[Instruction]) -> (i64, [[bool; 40]; 6]) {
+    let mut cycle = 1;
+    let mut signal_strength = 0;
+    let mut pixels = [[false; 40]; 6];
+    let mut reg_x = 1;
+    for instruction in instructions.iter().copied() {
+        match instruction {
+            Instruction::NoOp => {
+                signal_strength += cycle_v(cycle, reg_x);
+                draw_pixel(&mut pixels, cycle, reg_x);
+                cycle += 1;
+            }
+            Instruction::AddX(v) => {
+                signal_strength += cycle_v(cycle, reg_x);
+                draw_pixel(&mut pixels, cycle, reg_x);
+                cycle += 1;
+                signal_strength += cycle_v(cycle, reg_x);
+                draw_pixel(&mut pixels, cycle, reg_x);
+                reg_x += v;
+                cycle += 1;
+            }
+        }
+    }
+    (signal_strength, pixels)
+}
+
+pub(crate) struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .trim()
+            .lines()
+            .map(|line| {
+                let line: Vec<&str> = line.split(' ').collect();
+                match line[0] {
+                    "noop" => Instruction::NoOp,
+                    "addx" => Instruction::AddX(line[1].parse().unwrap()),
+                    o => panic!("Unrecognised instruction {o:?}"),
+                }
+            })
+            .collect()
+    }
+
+    fn part_1(instructions: &Self::Input) -> impl Display {
+        run_program(instructions).0
+    }
+
+    fn part_2(instructions: &Self::Input) -> impl Display {
+        run_program(instructions)
+            .1
+            .iter()
+            .map(|line| {
+                line.iter()
+                    .map(|&pixel| if pixel { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .join("\n")
+    }
+}
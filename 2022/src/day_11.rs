@@ -0,0 +1,98 @@
+use std::fmt::Display;
+
+use crate::util::Solution;
+
+#[derive(Clone, Copy, Debug)]
+enum Operation {
+    Add(i128),
+    Multiply(i128),
+    Square,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct Monkey {
+    items: Vec<i128>,
+    operation: Operation,
+    test_divisor: i128,
+    true_monkey: usize,
+    false_monkey: usize,
+}
+
+fn step(monkeys: &mut [Monkey], inspections: &mut [usize], relief: impl Fn(i128) -> i128) {
+    for i in 0..monkeys.len() {
+        let items: Vec<i128> = monkeys[i].items.drain(..).collect();
+        for item in items {
+            let new_item = relief(match monkeys[i].operation {
+                Operation::Add(x) => item + x,
+                Operation::Multiply(x) => item * x,
+                Operation::Square => item * item,
+            });
+            let new_monkey = if new_item % monkeys[i].test_divisor == 0 {
+                monkeys[i].true_monkey
+            } else {
+                monkeys[i].false_monkey
+            };
+            monkeys[new_monkey].items.push(new_item);
+            inspections[i] += 1;
+        }
+    }
+}
+
+pub(crate) struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .trim()
+            .split("\n\n")
+            .map(|chunk| {
+                let lines: Vec<&str> = chunk.lines().collect();
+                let items: Vec<i128> = lines[1][18..]
+                    .split(", ")
+                    .map(|x| x.parse().unwrap())
+                    .collect();
+                let operation = match &lines[2][23..24] {
+                    "*" if lines[2].len() >= 28 && &lines[2][25..28] == "old" => Operation::Square,
+                    "+" => Operation::Add(lines[2][25..].parse().unwrap()),
+                    "*" => Operation::Multiply(lines[2][25..].parse().unwrap()),
+                    o => panic!("Unrecognised operator {o:?}"),
+                };
+                let test_divisor: i128 = lines[3][21..].parse().unwrap();
+                let true_monkey: usize = lines[4][29..].parse().unwrap();
+                let false_monkey: usize = lines[5][30..].parse().unwrap();
+                Monkey {
+                    items,
+                    operation,
+                    test_divisor,
+                    true_monkey,
+                    false_monkey,
+                }
+            })
+            .collect()
+    }
+
+    fn part_1(monkeys: &Self::Input) -> impl Display {
+        let mut monkeys = monkeys.clone();
+        let mut inspections = vec![0usize; monkeys.len()];
+        for _ in 0..20 {
+            step(&mut monkeys, &mut inspections, |item| item / 3);
+        }
+        inspections.sort();
+
+        inspections[inspections.len() - 1] * inspections[inspections.len() - 2]
+    }
+
+    fn part_2(monkeys: &Self::Input) -> impl Display {
+        let mut monkeys = monkeys.clone();
+        let mut inspections = vec![0usize; monkeys.len()];
+        let big_divisor: i128 = monkeys.iter().map(|m| m.test_divisor).product();
+        for _ in 0..10_000 {
+            step(&mut monkeys, &mut inspections, |item| item % big_divisor);
+        }
+        inspections.sort();
+
+        inspections[inspections.len() - 1] * inspections[inspections.len() - 2]
+    }
+}
@@ -0,0 +1,50 @@
+use std::fmt::Display;
+
+use aoc_common::{bfs, Grid, Pos};
+
+use crate::util::Solution;
+
+fn find_shortest_path(
+    grid: &Grid<u8>,
+    start_points: impl IntoIterator<Item = Pos>,
+    end_pos: Pos,
+) -> Option<usize> {
+    let climbable = |&pos: &Pos| {
+        let height = grid[pos];
+        grid.neighbours4(pos)
+            .filter(move |&next| grid[next] <= height + 1)
+    };
+    bfs(start_points, climbable, |&pos| pos == end_pos).map(|path| path.cost)
+}
+
+pub(crate) struct Day12;
+
+impl Solution for Day12 {
+    type Input = (Grid<u8>, Pos, Pos);
+
+    fn parse(input: &str) -> Self::Input {
+        let chars: Grid<char> = input.trim().parse().unwrap();
+        let start_pos = chars.position(|&c| c == 'S').unwrap();
+        let end_pos = chars.position(|&c| c == 'E').unwrap();
+        let grid = chars.map(|&c| match c {
+            'a'..='z' => c as u8 - b'a',
+            'S' => 0,
+            'E' => b'z' - b'a',
+            c => panic!("Unrecognised input character {c:?}"),
+        });
+        (grid, start_pos, end_pos)
+    }
+
+    fn part_1(&(ref grid, start_pos, end_pos): &Self::Input) -> impl Display {
+        find_shortest_path(grid, [start_pos], end_pos).unwrap()
+    }
+
+    fn part_2(&(ref grid, _, end_pos): &Self::Input) -> impl Display {
+        let start_points: Vec<Pos> = grid
+            .iter()
+            .filter(|&(_, &h)| h == 0)
+            .map(|(pos, _)| pos)
+            .collect();
+        find_shortest_path(grid, start_points, end_pos).unwrap()
+    }
+}
@@ -0,0 +1,123 @@
+use std::fmt::Display;
+
+use aoc_common::{Grid, Pos};
+
+use crate::util::Solution;
+use itertools::Itertools;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Tile {
+    Air,
+    Rock,
+    Sand,
+}
+
+fn find_floor(rock_paths: &[Vec<Pos>]) -> usize {
+    rock_paths.iter().flatten().map(|p| p.1).max().unwrap()
+}
+
+/// A cave just big enough for every rock, and for sand to pile up from 500 to the floor two
+/// below the lowest rock.
+fn build_cave(rock_paths: &[Vec<Pos>]) -> Grid<Tile> {
+    let max_y = find_floor(rock_paths);
+    let max_x = rock_paths.iter().flatten().map(|p| p.0).max().unwrap();
+    let width = (500 + max_y + 3).max(max_x + 2);
+    let mut cave = Grid::new(width, max_y + 2, Tile::Air);
+    for rock_path in rock_paths {
+        for ((x_1, y_1), (x_2, y_2)) in rock_path.iter().copied().tuple_windows() {
+            if x_1 == x_2 {
+                for y in y_1.min(y_2)..=y_1.max(y_2) {
+                    cave[(x_1, y)] = Tile::Rock;
+                }
+            } else if y_1 == y_2 {
+                for x in x_1.min(x_2)..=x_1.max(x_2) {
+                    cave[(x, y_1)] = Tile::Rock;
+                }
+            } else {
+                panic!();
+            }
+        }
+    }
+    cave
+}
+
+/// Where sand at `pos` falls next, or `None` if it's come to rest, or if it's on the row
+/// above the floor.
+fn step_sand(cave: &Grid<Tile>, pos: Pos) -> Option<Pos> {
+    [(0, 1), (-1, 1), (1, 1)]
+        .into_iter()
+        .filter_map(|step| cave.offset(pos, step))
+        .find(|&next| cave[next] == Tile::Air)
+}
+
+fn place_sand_1(cave: &Grid<Tile>, floor_level: usize) -> Option<Pos> {
+    let mut point = (500, 0);
+    loop {
+        if let Some((x, y)) = step_sand(cave, point) {
+            if y < floor_level {
+                point = (x, y);
+            } else {
+                return None;
+            }
+        } else {
+            return Some(point);
+        }
+    }
+}
+
+fn place_sand_2(cave: &Grid<Tile>) -> Pos {
+    let mut point = (500, 0);
+    while let Some(next) = step_sand(cave, point) {
+        point = next;
+    }
+    point
+}
+
+pub(crate) struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Vec<Pos>>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .lines()
+            .map(|line| {
+                line.split(" -> ")
+                    .map(|point| {
+                        let (left, right) = point.split_once(',').unwrap();
+                        (left.parse().unwrap(), right.parse().unwrap())
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part_1(rock_paths: &Self::Input) -> impl Display {
+        let mut cave = build_cave(rock_paths);
+        let max_y = find_floor(rock_paths);
+        let mut sand = 0;
+
+        while let Some(point) = place_sand_1(&cave, max_y) {
+            cave[point] = Tile::Sand;
+            sand += 1;
+        }
+
+        sand
+    }
+
+    fn part_2(rock_paths: &Self::Input) -> impl Display {
+        let mut cave = build_cave(rock_paths);
+        let mut sand = 0;
+
+        loop {
+            let point = place_sand_2(&cave);
+            cave[point] = Tile::Sand;
+            sand += 1;
+            if point == (500, 0) {
+                break;
+            }
+        }
+
+        sand
+    }
+}
@@ -1,37 +1,21 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, fmt::Display};
 
+use crate::util::{Params, Solution};
 use itertools::Itertools;
 use regex::Regex;
-use util::PerfTimer;
 
 type Point = (i128, i128);
 
 #[derive(Clone, Copy, Debug)]
-struct Sensor {
+pub(crate) struct Sensor {
     pos: Point,
     beacon_pos: Point,
 }
 
-fn input() -> Vec<Sensor> {
-    let re =
-        Regex::new(r"Sensor at x=(?P<sensor_x>-?\d+), y=(?P<sensor_y>-?\d+): closest beacon is at x=(?P<beacon_x>-?\d+), y=(?P<beacon_y>-?\d+)")
-            .unwrap();
-    util::get_day_input(15)
-        .trim()
-        .lines()
-        .map(|line| {
-            let captures = re.captures(line).unwrap();
-            let pos = (
-                captures.name("sensor_x").unwrap().as_str().parse().unwrap(),
-                captures.name("sensor_y").unwrap().as_str().parse().unwrap(),
-            );
-            let beacon_pos = (
-                captures.name("beacon_x").unwrap().as_str().parse().unwrap(),
-                captures.name("beacon_y").unwrap().as_str().parse().unwrap(),
-            );
-            Sensor { pos, beacon_pos }
-        })
-        .collect()
+pub(crate) struct Survey {
+    sensors: Vec<Sensor>,
+    search_line: i128,
+    range_max: i128,
 }
 
 #[derive(Default)]
@@ -95,17 +79,50 @@ fn manhattan((x_a, y_a): Point, (x_b, y_b): Point) -> u128 {
     y_b.abs_diff(y_a) + x_b.abs_diff(x_a)
 }
 
-fn main() {
-    let sensors = input();
+pub(crate) struct Day15;
 
-    {
-        let _timer = PerfTimer::new("Part 1");
-        let search_line = 2000000;
+impl Solution for Day15 {
+    type Input = Survey;
+
+    fn parse(input: &str) -> Self::Input {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Self::Input {
+        let re =
+            Regex::new(r"Sensor at x=(?P<sensor_x>-?\d+), y=(?P<sensor_y>-?\d+): closest beacon is at x=(?P<beacon_x>-?\d+), y=(?P<beacon_y>-?\d+)")
+                .unwrap();
+        let sensors = input
+            .trim()
+            .lines()
+            .map(|line| {
+                let captures = re.captures(line).unwrap();
+                let pos = (
+                    captures.name("sensor_x").unwrap().as_str().parse().unwrap(),
+                    captures.name("sensor_y").unwrap().as_str().parse().unwrap(),
+                );
+                let beacon_pos = (
+                    captures.name("beacon_x").unwrap().as_str().parse().unwrap(),
+                    captures.name("beacon_y").unwrap().as_str().parse().unwrap(),
+                );
+                Sensor { pos, beacon_pos }
+            })
+            .collect();
+
+        Survey {
+            sensors,
+            search_line: params.get("search line", 2_000_000),
+            range_max: params.get("range max", 4_000_000),
+        }
+    }
+
+    fn part_1(survey: &Self::Input) -> impl Display {
+        let search_line = survey.search_line;
 
         let mut line_ranges = RangeManager::default();
         let mut beacons_on_line = HashSet::new();
 
-        for sensor in &sensors {
+        for sensor in &survey.sensors {
             if sensor.beacon_pos.1 == search_line {
                 beacons_on_line.insert(sensor.beacon_pos);
             }
@@ -118,20 +135,18 @@ fn main() {
             }
         }
 
-        let part_1 = line_ranges.sum_ranges() - beacons_on_line.len() as i128;
-        println!("Part 1: {part_1}");
+        line_ranges.sum_ranges() - beacons_on_line.len() as i128
     }
 
-    {
-        let _timer = PerfTimer::new("Part 2");
+    fn part_2(survey: &Self::Input) -> impl Display {
         let range_min = 0;
-        let range_max = 4_000_000;
+        let range_max = survey.range_max;
 
         for search_line in range_min..=range_max {
             let mut line_ranges = RangeManager::default();
             let mut beacons_on_line = HashSet::new();
 
-            for sensor in &sensors {
+            for sensor in &survey.sensors {
                 if sensor.beacon_pos.1 == search_line {
                     beacons_on_line.insert(sensor.beacon_pos);
                 }
@@ -145,7 +160,6 @@ fn main() {
             }
 
             if line_ranges.sum_ranges() < range_max - range_min + 1 {
-                println!("Line: {search_line}");
                 let line_ranges = line_ranges.ranges.iter().collect_vec();
                 let x_coord = if line_ranges.len() == 2 {
                     let over = line_ranges.iter().max_by_key(|r| r.0).unwrap().0;
@@ -164,10 +178,9 @@ fn main() {
                 } else {
                     panic!("Expected 1 or 2 line ranges, but got {}", line_ranges.len())
                 };
-                let part_2 = x_coord * 4_000_000 + search_line;
-                println!("Part 2: {part_2}");
-                break;
+                return x_coord * 4_000_000 + search_line;
             }
         }
+        panic!("No gap found in the search area")
     }
 }
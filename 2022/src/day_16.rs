@@ -1,38 +1,19 @@
-use std::collections::{BinaryHeap, HashMap};
+use std::{
+    collections::{BinaryHeap, HashMap},
+    fmt::Display,
+};
 
+use crate::util::Solution;
 use itertools::Itertools;
 use rayon::prelude::*;
 use regex::Regex;
-use util::PerfTimer;
 
 #[derive(Clone, Debug)]
-struct InputValve {
+pub(crate) struct InputValve {
     rate: u64,
     leads_to: HashMap<String, u64>,
 }
 
-fn input() -> HashMap<String, InputValve> {
-    let re =
-        Regex::new(
-            r"^Valve (?P<name>[A-Z]{2}) has flow rate=(?P<rate>\d+); tunnels? leads? to valves? (?P<leads_to>(?:[A-Z]{2}, )*[A-Z]{2})$"
-        ).unwrap();
-
-    util::get_day_input(16)
-        .trim()
-        .lines()
-        .map(|line| {
-            let captures = re.captures(line).unwrap();
-            let name = String::from(&captures["name"]);
-            let rate = captures["rate"].parse().unwrap();
-            let leads_to = captures["leads_to"]
-                .split(", ")
-                .map(|v| (String::from(v), 1))
-                .collect();
-            (name, InputValve { rate, leads_to })
-        })
-        .collect()
-}
-
 #[allow(dead_code)]
 fn encode_path(valve_a: usize, valve_b: usize) -> usize {
     valve_a * 16 + valve_b
@@ -55,20 +36,23 @@ impl GameMap {
 }
 
 fn make_game_map(input_valves: HashMap<&str, InputValve>) -> GameMap {
-    assert_eq!(input_valves.len(), 16);
+    // Any valves past the real ones have no flow and no tunnels, so they're never visited.
+    assert!(input_valves.len() <= 16);
     let names: Vec<&str> = input_valves.keys().copied().sorted().collect();
     let mut valves = [0_u64; 16];
-    for (i, valve) in valves.iter_mut().enumerate() {
-        *valve = input_valves[names[i]].rate;
+    for (valve, name) in valves.iter_mut().zip(&names) {
+        *valve = input_valves[name].rate;
     }
 
     let mut paths = [None; 256];
     for (i, path) in paths.iter_mut().enumerate() {
         let (valve_a, valve_b) = decode_path(i);
-        *path = input_valves[names[valve_a]]
-            .leads_to
-            .get(names[valve_b])
-            .copied();
+        if valve_a < names.len() && valve_b < names.len() {
+            *path = input_valves[names[valve_a]]
+                .leads_to
+                .get(names[valve_b])
+                .copied();
+        }
     }
 
     GameMap {
@@ -253,11 +237,34 @@ fn find_max_path(map: &GameMap, mut initial_state: GameState) -> u64 {
     }
 }
 
-fn main() {
-    let valves = input();
+pub(crate) struct Day16;
+
+impl Solution for Day16 {
+    type Input = HashMap<String, InputValve>;
+
+    fn parse(input: &str) -> Self::Input {
+        let re =
+            Regex::new(
+                r"^Valve (?P<name>[A-Z]{2}) has flow rate=(?P<rate>\d+); tunnels? leads? to valves? (?P<leads_to>(?:[A-Z]{2}, )*[A-Z]{2})$"
+            ).unwrap();
+
+        input
+            .trim()
+            .lines()
+            .map(|line| {
+                let captures = re.captures(line).unwrap();
+                let name = String::from(&captures["name"]);
+                let rate = captures["rate"].parse().unwrap();
+                let leads_to = captures["leads_to"]
+                    .split(", ")
+                    .map(|v| (String::from(v), 1))
+                    .collect();
+                (name, InputValve { rate, leads_to })
+            })
+            .collect()
+    }
 
-    {
-        let _timer = PerfTimer::new("Part 1");
+    fn part_1(valves: &Self::Input) -> impl Display {
         let valves: HashMap<&str, InputValve> = valves
             .iter()
             .map(|(k, v)| (k.as_str(), v.clone()))
@@ -271,12 +278,10 @@ fn main() {
             total_released: 0,
         };
 
-        let part_1 = find_max_path(&map, initial_state);
-        println!("Part 1: {part_1}");
+        find_max_path(&map, initial_state)
     }
 
-    {
-        let _timer = PerfTimer::new("Part 2");
+    fn part_2(valves: &Self::Input) -> impl Display {
         let valves: HashMap<&str, InputValve> = valves
             .iter()
             .map(|(k, v)| (k.as_str(), v.clone()))
@@ -286,7 +291,8 @@ fn main() {
         // Split the search space in two, considering each possible split.
         // We find the best paths through each half of the search space and add together the total amount of gas
         // released by the paths. We then find best
-        let part_2 = (0..16)
+
+        (0..16)
             .powerset()
             .par_bridge()
             .map(|valves_a| {
@@ -326,8 +332,6 @@ fn main() {
                 max_a + max_b
             })
             .max()
-            .unwrap();
-
-        println!("Part 2: {part_2}");
+            .unwrap()
     }
 }
@@ -0,0 +1,197 @@
+use std::{collections::HashSet, fmt::Display};
+
+use aoc_common::{find_cycle, Point2};
+
+use crate::util::Solution;
+
+type Point = Point2;
+
+const WIDTH: i64 = 7;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Movement {
+    Left,
+    Right,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct RoughState<const N: usize> {
+    movement_index: usize,
+    shape_index: usize,
+    positions_sort_of: [Point; N],
+}
+
+fn simulate_falling_rock(
+    rock: &[Point],
+    rocks: &mut HashSet<Point>,
+    movements: &mut impl Iterator<Item = Movement>,
+    tower_height: &mut i64,
+) -> Point {
+    let mut rock_offset = Point::new(2, *tower_height + 3);
+    assert!(rock
+        .iter()
+        .map(|&p| p + rock_offset)
+        .all(|r| !rocks.contains(&r)));
+
+    loop {
+        // move left/right
+        let new_rock_offset = match movements.next().unwrap() {
+            Movement::Left => rock_offset + Point::new(-1, 0),
+            Movement::Right => rock_offset + Point::new(1, 0),
+        };
+
+        if new_rock_offset.x >= 0
+            && rock
+                .iter()
+                .map(|&p| p + new_rock_offset)
+                .all(|p| p.x < WIDTH && !rocks.contains(&p))
+        {
+            rock_offset = new_rock_offset;
+        }
+
+        // move down
+        let new_rock_offset = rock_offset + Point::new(0, -1);
+
+        if new_rock_offset.y < 0
+            || rock
+                .iter()
+                .map(|&p| p + new_rock_offset)
+                .any(|p| rocks.contains(&p))
+        {
+            break;
+        }
+
+        rock_offset = new_rock_offset;
+    }
+
+    for p in rock.iter().map(|&p| p + rock_offset) {
+        *tower_height = (*tower_height).max(p.y + 1);
+        rocks.insert(p);
+    }
+    rock_offset
+}
+
+#[derive(Default)]
+struct Chamber {
+    rocks: HashSet<Point>,
+    tower_height: i64,
+    rock_positions: Vec<Point>,
+    /// The index of the next movement to push a rock.
+    movement_index: usize,
+}
+
+impl Chamber {
+    fn drop_rock(&mut self, movements: &[Movement]) {
+        let rock = SHAPES[self.rock_positions.len() % SHAPES.len()];
+        let movement_index = &mut self.movement_index;
+        let mut movements = std::iter::from_fn(|| {
+            let movement = movements[*movement_index];
+            *movement_index = (*movement_index + 1) % movements.len();
+            Some(movement)
+        });
+        let rock_pos = simulate_falling_rock(
+            rock,
+            &mut self.rocks,
+            &mut movements,
+            &mut self.tower_height,
+        );
+        self.rock_positions.push(rock_pos);
+    }
+
+    /// The next shape and movement, and where the last `N` rocks came to rest relative to the
+    /// first of them, which between them are very likely to decide how the tower grows from here.
+    fn rough_state<const N: usize>(&self) -> RoughState<N> {
+        let recent = &self.rock_positions[self.rock_positions.len().saturating_sub(N)..];
+        let first_height = recent.first().map_or(0, |p| p.y);
+        let mut positions_sort_of = [Point::ORIGIN; N];
+        for (position_sort_of, &p) in positions_sort_of.iter_mut().zip(recent) {
+            *position_sort_of = p + Point::new(0, -first_height);
+        }
+        RoughState {
+            movement_index: self.movement_index,
+            shape_index: self.rock_positions.len() % SHAPES.len(),
+            positions_sort_of,
+        }
+    }
+}
+
+const SHAPES: [&[Point]; 5] = [
+    // horizontal line
+    &[
+        Point::new(0, 0),
+        Point::new(1, 0),
+        Point::new(2, 0),
+        Point::new(3, 0),
+    ],
+    // + shape
+    &[
+        Point::new(1, 0),
+        Point::new(0, 1),
+        Point::new(1, 1),
+        Point::new(2, 1),
+        Point::new(1, 2),
+    ],
+    // backwards L shape
+    &[
+        Point::new(0, 0),
+        Point::new(1, 0),
+        Point::new(2, 0),
+        Point::new(2, 1),
+        Point::new(2, 2),
+    ],
+    // vertical line
+    &[
+        Point::new(0, 0),
+        Point::new(0, 1),
+        Point::new(0, 2),
+        Point::new(0, 3),
+    ],
+    // square
+    &[
+        Point::new(0, 0),
+        Point::new(1, 0),
+        Point::new(0, 1),
+        Point::new(1, 1),
+    ],
+];
+
+pub(crate) struct Day17;
+
+impl Solution for Day17 {
+    type Input = Vec<Movement>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .trim()
+            .chars()
+            .map(|c| match c {
+                '<' => Movement::Left,
+                '>' => Movement::Right,
+                c => panic!("found unexpected character {c:?} in input"),
+            })
+            .collect()
+    }
+
+    fn part_1(movements: &Self::Input) -> impl Display {
+        let mut chamber = Chamber::default();
+        for _ in 0..2022 {
+            chamber.drop_rock(movements);
+        }
+        chamber.tower_height
+    }
+
+    fn part_2(movements: &Self::Input) -> impl Display {
+        const LOOK_BACK_LENGTH: usize = 10;
+        const TARGET: usize = 1_000_000_000_000;
+        let mut tower_heights = vec![0];
+        let (cycle, _) = find_cycle(
+            Chamber::default(),
+            |chamber| {
+                chamber.drop_rock(movements);
+                tower_heights.push(chamber.tower_height);
+            },
+            Chamber::rough_state::<LOOK_BACK_LENGTH>,
+        );
+        cycle.extrapolate(TARGET, |rocks| tower_heights[rocks])
+    }
+}
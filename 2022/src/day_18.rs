@@ -1,84 +1,71 @@
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
 
+use aoc_common::Point3;
 use itertools::Itertools;
-use util::PerfTimer;
-
-type Point = (i64, i64, i64);
-
-fn input() -> Vec<Point> {
-    let raw = util::get_day_input(18);
-    raw.lines()
-        .map(|l| {
-            l.splitn(3, ',')
-                .map(|s| s.parse().unwrap())
-                .next_tuple()
-                .unwrap()
-        })
-        .collect()
-}
 
-fn add_point((a_x, a_y, a_z): Point, (b_x, b_y, b_z): Point) -> Point {
-    (a_x + b_x, a_y + b_y, a_z + b_z)
-}
+use crate::util::Solution;
 
-fn get_adjacent_positions(point: Point) -> [Point; 6] {
-    let mut positions = [
-        (0, 0, 1),
-        (0, 1, 0),
-        (1, 0, 0),
-        (0, 0, -1),
-        (0, -1, 0),
-        (-1, 0, 0),
-    ];
-    for p in &mut positions {
-        *p = add_point(*p, point)
-    }
-    positions
-}
+pub(crate) struct Day18;
 
-fn main() {
-    let positions = input();
+impl Solution for Day18 {
+    type Input = Vec<Point3>;
 
-    {
-        let _timer = PerfTimer::new("Part 1");
-        let positions: HashSet<Point> = HashSet::from_iter(positions.clone());
+    fn parse(input: &str) -> Self::Input {
+        input
+            .lines()
+            .map(|l| {
+                let (x, y, z) = l
+                    .splitn(3, ',')
+                    .map(|s| s.parse().unwrap())
+                    .next_tuple()
+                    .unwrap();
+                Point3::new(x, y, z)
+            })
+            .collect()
+    }
+
+    fn part_1(positions: &Self::Input) -> impl Display {
+        let positions: HashSet<Point3> = HashSet::from_iter(positions.clone());
         let part_1: usize = positions
             .iter()
             .map(|&position| {
-                get_adjacent_positions(position)
+                position
+                    .neighbours6()
                     .into_iter()
                     .filter(|p| !positions.contains(p))
                     .count()
             })
             .sum();
 
-        println!("Part 1: {part_1}");
+        part_1
     }
 
-    {
-        let _timer = PerfTimer::new("Part 2");
-
-        let min_x = positions.iter().map(|p| p.0).min().unwrap() - 1;
-        let min_y = positions.iter().map(|p| p.1).min().unwrap() - 1;
-        let min_z = positions.iter().map(|p| p.2).min().unwrap() - 1;
-        let max_x = positions.iter().map(|p| p.0).max().unwrap() + 1;
-        let max_y = positions.iter().map(|p| p.1).max().unwrap() + 1;
-        let max_z = positions.iter().map(|p| p.2).max().unwrap() + 1;
+    fn part_2(positions: &Self::Input) -> impl Display {
+        let min_x = positions.iter().map(|p| p.x).min().unwrap() - 1;
+        let min_y = positions.iter().map(|p| p.y).min().unwrap() - 1;
+        let min_z = positions.iter().map(|p| p.z).min().unwrap() - 1;
+        let max_x = positions.iter().map(|p| p.x).max().unwrap() + 1;
+        let max_y = positions.iter().map(|p| p.y).max().unwrap() + 1;
+        let max_z = positions.iter().map(|p| p.z).max().unwrap() + 1;
 
-        let lava_cells: HashSet<Point> = positions.iter().copied().collect();
-        let mut cells_to_sets: HashMap<Point, Point> = HashMap::new();
-        let mut sets_to_cells: HashMap<Point, Vec<Point>> = HashMap::new();
+        let lava_cells: HashSet<Point3> = positions.iter().copied().collect();
+        let mut cells_to_sets: HashMap<Point3, Point3> = HashMap::new();
+        let mut sets_to_cells: HashMap<Point3, Vec<Point3>> = HashMap::new();
 
         for x in min_x..=max_x {
             for y in min_y..=max_y {
                 for z in min_z..=max_z {
-                    let cell = (x, y, z);
+                    let cell = Point3::new(x, y, z);
 
                     if lava_cells.contains(&cell) {
                         continue;
                     }
 
-                    let sets: Vec<Point> = get_adjacent_positions(cell)
+                    let sets: Vec<Point3> = cell
+                        .neighbours6()
                         .iter()
                         .filter_map(|p| cells_to_sets.get(p))
                         .copied()
@@ -110,18 +97,19 @@ fn main() {
             }
         }
 
-        let outside_set: &[Point] = &sets_to_cells[&(min_x, min_y, min_z)];
+        let outside_set: &[Point3] = &sets_to_cells[&Point3::new(min_x, min_y, min_z)];
 
         let part_2: usize = positions
             .iter()
             .map(|&position| {
-                get_adjacent_positions(position)
+                position
+                    .neighbours6()
                     .into_iter()
                     .filter(|p| outside_set.contains(p))
                     .count()
             })
             .sum();
 
-        println!("Part 2: {part_2}");
+        part_2
     }
 }
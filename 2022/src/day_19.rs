@@ -1,9 +1,10 @@
-use std::{cell::RefCell, collections::BinaryHeap};
+use std::{cell::RefCell, collections::BinaryHeap, fmt::Display};
 
+use crate::util::Solution;
+use aoc_common::{add_timings, collect_timings, PerfTimer, Timing};
 use rayon::prelude::*;
 use regex::Regex;
 use smallvec::{smallvec, SmallVec};
-use util::PerfTimer;
 
 #[derive(Clone, Copy)]
 struct Recipe {
@@ -22,7 +23,7 @@ impl Recipe {
 }
 
 #[derive(Clone, Copy)]
-struct Blueprint {
+pub(crate) struct Blueprint {
     id: i64,
     ore_robot_recipe: Recipe,
     clay_robot_recipe: Recipe,
@@ -30,61 +31,6 @@ struct Blueprint {
     geode_robot_recipe: Recipe,
 }
 
-fn input() -> Vec<Blueprint> {
-    let blueprint_regex = Regex::new(r"^Blueprint (\d+): (.+)\.$").unwrap();
-    let recipe_regex = Regex::new(
-        r"^Each (?P<robot>\w+) robot costs (?P<ore>\d+) ore(?: and (?:(?P<clay>\d+) clay|(?P<obsidian>\d+) obsidian))?$",
-    ).unwrap();
-
-    let raw = util::get_day_input(19);
-    raw.lines()
-        .map(|line| {
-            let blueprint_match = blueprint_regex.captures(line).unwrap();
-            let id = blueprint_match[1].parse().unwrap();
-
-            let mut ore_robot_recipe = None;
-            let mut clay_robot_recipe = None;
-            let mut obsidian_robot_recipe = None;
-            let mut geode_robot_recipe = None;
-
-            for recipe in blueprint_match[2].split(". ") {
-                let captures = recipe_regex.captures(recipe).unwrap();
-                let robot = match &captures["robot"] {
-                    "ore" => &mut ore_robot_recipe,
-                    "clay" => &mut clay_robot_recipe,
-                    "obsidian" => &mut obsidian_robot_recipe,
-                    "geode" => &mut geode_robot_recipe,
-                    other => panic!("Unknown robot type {other:?}"),
-                };
-
-                let ore = captures["ore"].parse().unwrap();
-                let clay = captures
-                    .name("clay")
-                    .map(|m| m.as_str().parse().unwrap())
-                    .unwrap_or(0);
-                let obsidian = captures
-                    .name("obsidian")
-                    .map(|m| m.as_str().parse().unwrap())
-                    .unwrap_or(0);
-
-                *robot = Some(Recipe {
-                    ore,
-                    clay,
-                    obsidian,
-                });
-            }
-
-            Blueprint {
-                id,
-                ore_robot_recipe: ore_robot_recipe.unwrap(),
-                clay_robot_recipe: clay_robot_recipe.unwrap(),
-                obsidian_robot_recipe: obsidian_robot_recipe.unwrap(),
-                geode_robot_recipe: geode_robot_recipe.unwrap(),
-            }
-        })
-        .collect()
-}
-
 struct State<'a> {
     blueprint: &'a Blueprint,
     time_left: i64,
@@ -143,6 +89,7 @@ impl<'a> State<'a> {
     }
 
     fn next<'b>(&'b self) -> SmallVec<[State<'a>; 5]> {
+        let _timer = PerfTimer::new("State::next");
         if self.blueprint.geode_robot_recipe.obsidian <= self.obsidian_robots.min(self.obsidian)
             && self.blueprint.geode_robot_recipe.clay <= self.clay_robots.min(self.clay)
             && self.blueprint.geode_robot_recipe.ore <= self.ore_robots.min(self.ore)
@@ -304,7 +251,16 @@ impl<'a> PartialOrd for State<'a> {
     }
 }
 
-fn find_max_geodes<'a>(initial_state: State<'a>) -> i64 {
+/// The most geodes that can be opened from `initial_state`, along with the timings of the search,
+/// as it runs on one of rayon's threads.
+fn find_max_geodes(initial_state: State) -> (i64, Vec<Timing>) {
+    collect_timings(|| {
+        let _timer = PerfTimer::new("find_max_geodes");
+        search(initial_state)
+    })
+}
+
+fn search<'a>(initial_state: State<'a>) -> i64 {
     let mut pq: BinaryHeap<State<'a>> = BinaryHeap::new();
     pq.push(initial_state);
 
@@ -319,11 +275,66 @@ fn find_max_geodes<'a>(initial_state: State<'a>) -> i64 {
     }
 }
 
-fn main() {
-    let blueprints = input();
-    {
-        let _timer = PerfTimer::new("Part 1");
+pub(crate) struct Day19;
+
+impl Solution for Day19 {
+    type Input = Vec<Blueprint>;
+
+    fn parse(input: &str) -> Self::Input {
+        let blueprint_regex = Regex::new(r"^Blueprint (\d+): (.+)\.$").unwrap();
+        let recipe_regex = Regex::new(
+            r"^Each (?P<robot>\w+) robot costs (?P<ore>\d+) ore(?: and (?:(?P<clay>\d+) clay|(?P<obsidian>\d+) obsidian))?$",
+        ).unwrap();
+        input
+            .lines()
+            .map(|line| {
+                let blueprint_match = blueprint_regex.captures(line).unwrap();
+                let id = blueprint_match[1].parse().unwrap();
+
+                let mut ore_robot_recipe = None;
+                let mut clay_robot_recipe = None;
+                let mut obsidian_robot_recipe = None;
+                let mut geode_robot_recipe = None;
+
+                for recipe in blueprint_match[2].split(". ") {
+                    let captures = recipe_regex.captures(recipe).unwrap();
+                    let robot = match &captures["robot"] {
+                        "ore" => &mut ore_robot_recipe,
+                        "clay" => &mut clay_robot_recipe,
+                        "obsidian" => &mut obsidian_robot_recipe,
+                        "geode" => &mut geode_robot_recipe,
+                        other => panic!("Unknown robot type {other:?}"),
+                    };
+
+                    let ore = captures["ore"].parse().unwrap();
+                    let clay = captures
+                        .name("clay")
+                        .map(|m| m.as_str().parse().unwrap())
+                        .unwrap_or(0);
+                    let obsidian = captures
+                        .name("obsidian")
+                        .map(|m| m.as_str().parse().unwrap())
+                        .unwrap_or(0);
+
+                    *robot = Some(Recipe {
+                        ore,
+                        clay,
+                        obsidian,
+                    });
+                }
+
+                Blueprint {
+                    id,
+                    ore_robot_recipe: ore_robot_recipe.unwrap(),
+                    clay_robot_recipe: clay_robot_recipe.unwrap(),
+                    obsidian_robot_recipe: obsidian_robot_recipe.unwrap(),
+                    geode_robot_recipe: geode_robot_recipe.unwrap(),
+                }
+            })
+            .collect()
+    }
 
+    fn part_1(blueprints: &Self::Input) -> impl Display {
         let part_1: i64 = blueprints
             .par_iter()
             .map(|blueprint| {
@@ -341,16 +352,22 @@ fn main() {
                     upper_bound: RefCell::new(None),
                     do_not_build: [false; 4],
                 };
-                blueprint.id * find_max_geodes(initial_state)
+                let (geodes, timings) = find_max_geodes(initial_state);
+                (blueprint.id * geodes, timings)
             })
             // .inspect(|g| println!("{g}"))
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|(quality, timings)| {
+                add_timings(&timings);
+                quality
+            })
             .sum();
 
-        println!("Part 1: {part_1}");
+        part_1
     }
-    {
-        let _timer = PerfTimer::new("Part 2");
 
+    fn part_2(blueprints: &Self::Input) -> impl Display {
         let part_2: i64 = blueprints
             .par_iter()
             .take(3)
@@ -372,8 +389,14 @@ fn main() {
                 find_max_geodes(initial_state)
             })
             // .inspect(|g| println!("{g}"))
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|(geodes, timings)| {
+                add_timings(&timings);
+                geodes
+            })
             .product();
 
-        println!("Part 2: {part_2}");
+        part_2
     }
 }
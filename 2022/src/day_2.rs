@@ -1,44 +1,50 @@
+use std::fmt::Display;
+
+use crate::util::Solution;
+
 #[derive(Clone, Copy, Debug)]
-enum Opponent {
+pub(crate) enum Opponent {
     A,
     B,
     C,
 }
 
 #[derive(Clone, Copy, Debug)]
-enum Player {
+pub(crate) enum Player {
     X,
     Y,
     Z,
 }
 
-fn input() -> Vec<(Opponent, Player)> {
-    util::get_day_input(2)
-        .trim()
-        .lines()
-        .map(|line| {
-            let (l, r) = line.split_once(' ').unwrap();
-            let l = match l {
-                "A" => Opponent::A,
-                "B" => Opponent::B,
-                "C" => Opponent::C,
-                _ => panic!("Unrecognised opponent move {l:?}"),
-            };
-            let r = match r {
-                "X" => Player::X,
-                "Y" => Player::Y,
-                "Z" => Player::Z,
-                _ => panic!("Unrecognised player move {r:?}"),
-            };
-            (l, r)
-        })
-        .collect()
-}
+pub(crate) struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<(Opponent, Player)>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .trim()
+            .lines()
+            .map(|line| {
+                let (l, r) = line.split_once(' ').unwrap();
+                let l = match l {
+                    "A" => Opponent::A,
+                    "B" => Opponent::B,
+                    "C" => Opponent::C,
+                    _ => panic!("Unrecognised opponent move {l:?}"),
+                };
+                let r = match r {
+                    "X" => Player::X,
+                    "Y" => Player::Y,
+                    "Z" => Player::Z,
+                    _ => panic!("Unrecognised player move {r:?}"),
+                };
+                (l, r)
+            })
+            .collect()
+    }
 
-fn main() {
-    let move_table = input();
-    {
-        let _timer = util::PerfTimer::new("Part 1");
+    fn part_1(move_table: &Self::Input) -> impl Display {
         let part_1: u64 = move_table
             .iter()
             .copied()
@@ -62,10 +68,11 @@ fn main() {
                 move_score + outcome_score
             })
             .sum();
-        println!("Part 1: {part_1}");
+
+        part_1
     }
-    {
-        let _timer = util::PerfTimer::new("Part 2");
+
+    fn part_2(move_table: &Self::Input) -> impl Display {
         #[allow(clippy::identity_op)]
         let part_2: u64 = move_table
             .iter()
@@ -84,6 +91,7 @@ fn main() {
                 },
             )
             .sum();
-        println!("Part 2: {part_2}");
+
+        part_2
     }
 }
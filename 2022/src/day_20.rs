@@ -1,18 +1,18 @@
+use std::fmt::Display;
+
+use crate::util::Solution;
 use itertools::Itertools;
-use util::PerfTimer;
 
-fn input() -> Vec<i128> {
-    util::get_day_input(20)
-        .lines()
-        .map(|x| x.parse().unwrap())
-        .collect()
-}
+pub(crate) struct Day20;
 
-fn main() {
-    let input = input();
-    {
-        let _timer = PerfTimer::new("Part 1");
+impl Solution for Day20 {
+    type Input = Vec<i128>;
+
+    fn parse(input: &str) -> Self::Input {
+        input.lines().map(|x| x.parse().unwrap()).collect()
+    }
 
+    fn part_1(input: &Self::Input) -> impl Display {
         let mut state: Vec<(i128, bool)> = input.iter().map(|&x| (x, false)).collect();
         let mut i = 0;
         while i < state.len() {
@@ -49,14 +49,12 @@ fn main() {
             .chain(final_state.iter().copied().take_while(|&x| x != 0))
             .collect();
 
-        let part_1 = final_state[1000 % final_state.len()]
+        final_state[1000 % final_state.len()]
             + final_state[2000 % final_state.len()]
-            + final_state[3000 % final_state.len()];
-        println!("Part 1: {part_1}");
+            + final_state[3000 % final_state.len()]
     }
 
-    {
-        let _timer = PerfTimer::new("Part 2");
+    fn part_2(input: &Self::Input) -> impl Display {
         let initial_state: Vec<(usize, i128)> =
             input.iter().map(|&x| x * 811589153).enumerate().collect();
         let mut state = initial_state.clone();
@@ -90,9 +88,8 @@ fn main() {
             .chain(final_state.iter().copied().take_while(|&x| x != 0))
             .collect();
 
-        let part_2 = final_state[1000 % final_state.len()]
+        final_state[1000 % final_state.len()]
             + final_state[2000 % final_state.len()]
-            + final_state[3000 % final_state.len()];
-        println!("Part 2: {part_2}");
+            + final_state[3000 % final_state.len()]
     }
 }
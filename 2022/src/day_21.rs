@@ -1,52 +1,22 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt::Display};
 
+use crate::util::Solution;
 use regex::Regex;
-use util::PerfTimer;
 
 #[derive(Clone, Debug)]
-enum Job {
+pub(crate) enum Job {
     Number(i64),
     Operation { a: String, b: String, op: Op },
 }
 
 #[derive(Clone, Debug)]
-enum Op {
+pub(crate) enum Op {
     Add,
     Sub,
     Mul,
     Div,
 }
 
-fn input() -> HashMap<String, Job> {
-    let raw = util::get_day_input(21);
-    let regex = Regex::new(
-        r"^(?P<key>[a-z]+): (?:(?P<number>\d+)|(?:(?P<a>[a-z]+) (?P<op>.) (?P<b>[a-z]+)))$",
-    )
-    .unwrap();
-    raw.lines()
-        .map(|line| {
-            let groups = regex.captures(line).unwrap();
-            let key = groups["key"].to_string();
-            let job = if let Some(num) = groups.name("number") {
-                Job::Number(num.as_str().parse().unwrap())
-            } else {
-                let a = groups["a"].to_string();
-                let b = groups["b"].to_string();
-                let op = match &groups["op"] {
-                    "+" => Op::Add,
-                    "-" => Op::Sub,
-                    "*" => Op::Mul,
-                    "/" => Op::Div,
-                    _ => panic!(),
-                };
-
-                Job::Operation { a, b, op }
-            };
-            (key, job)
-        })
-        .collect()
-}
-
 fn calculate(jobs: &HashMap<String, Job>, key: &str) -> Option<i64> {
     match jobs.get(key)? {
         &Job::Number(x) => Some(x),
@@ -107,16 +77,46 @@ fn drill(jobs: &HashMap<String, Job>, target_key: &str) -> i64 {
     inner(&jobs, target_key, 0, "root")
 }
 
-fn main() {
-    let jobs = input();
-    {
-        let _timer = PerfTimer::new("Part 1");
-        let part_1 = calculate(&jobs, "root").unwrap();
-        println!("Part 1: {part_1}");
+pub(crate) struct Day21;
+
+impl Solution for Day21 {
+    type Input = HashMap<String, Job>;
+
+    fn parse(input: &str) -> Self::Input {
+        let regex = Regex::new(
+            r"^(?P<key>[a-z]+): (?:(?P<number>\d+)|(?:(?P<a>[a-z]+) (?P<op>.) (?P<b>[a-z]+)))$",
+        )
+        .unwrap();
+        input
+            .lines()
+            .map(|line| {
+                let groups = regex.captures(line).unwrap();
+                let key = groups["key"].to_string();
+                let job = if let Some(num) = groups.name("number") {
+                    Job::Number(num.as_str().parse().unwrap())
+                } else {
+                    let a = groups["a"].to_string();
+                    let b = groups["b"].to_string();
+                    let op = match &groups["op"] {
+                        "+" => Op::Add,
+                        "-" => Op::Sub,
+                        "*" => Op::Mul,
+                        "/" => Op::Div,
+                        _ => panic!(),
+                    };
+
+                    Job::Operation { a, b, op }
+                };
+                (key, job)
+            })
+            .collect()
     }
-    {
-        let _timer = PerfTimer::new("Part 2");
-        let part_2 = drill(&jobs, "humn");
-        println!("Part 2: {part_2}");
+
+    fn part_1(jobs: &Self::Input) -> impl Display {
+        calculate(jobs, "root").unwrap()
+    }
+
+    fn part_2(jobs: &Self::Input) -> impl Display {
+        drill(jobs, "humn")
     }
 }
@@ -1,9 +1,11 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt::Display};
 
-use util::PerfTimer;
+use aoc_common::{Direction, Grid};
+
+use crate::util::Solution;
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
-enum Tile {
+pub(crate) enum Tile {
     #[default]
     Void,
     Open,
@@ -11,62 +13,15 @@ enum Tile {
 }
 
 #[derive(Copy, Clone, Debug)]
-enum Instruction {
+pub(crate) enum Instruction {
     Move(usize),
     TurnRight,
     TurnLeft,
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-enum Direction {
-    Right,
-    Down,
-    Left,
-    Up,
-}
-
-impl std::ops::Add<usize> for Direction {
-    type Output = Direction;
-
-    fn add(mut self, rhs: usize) -> Self::Output {
-        for _ in 0..rhs {
-            self.turn_right();
-        }
-        self
-    }
-}
-
-impl std::ops::Sub<usize> for Direction {
-    type Output = Direction;
-
-    fn sub(mut self, rhs: usize) -> Self::Output {
-        for _ in 0..rhs {
-            self.turn_left();
-        }
-        self
-    }
-}
-
-impl Direction {
-    fn turn_right(&mut self) {
-        use Direction::*;
-        *self = match self {
-            Right => Down,
-            Down => Left,
-            Left => Up,
-            Up => Right,
-        }
-    }
-
-    fn turn_left(&mut self) {
-        use Direction::*;
-        *self = match self {
-            Right => Up,
-            Down => Right,
-            Left => Down,
-            Up => Left,
-        }
-    }
+/// `direction` turned `quarter_turns` quarter turns clockwise.
+fn turn(direction: Direction, quarter_turns: usize) -> Direction {
+    (0..quarter_turns).fold(direction, |direction, _| direction.turn_right())
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -128,70 +83,16 @@ impl Face {
     }
 }
 
-fn input() -> (Vec<Vec<Tile>>, Vec<Instruction>) {
-    let raw = util::get_day_input(22);
-    let raw_rows: Vec<&str> = raw.lines().take_while(|l| !l.trim().is_empty()).collect();
-    let raw_instructions = raw.lines().last().unwrap();
-
-    let row_count = raw_rows.len();
-    let column_count = raw_rows.iter().map(|r| r.len()).max().unwrap();
-
-    let mut grid: Vec<Vec<Tile>> = (0..row_count)
-        .map(|_| (0..column_count).map(|_| Tile::Void).collect())
-        .collect();
-    for (i, raw_row) in raw_rows.iter().copied().enumerate() {
-        for (j, c) in raw_row.chars().enumerate() {
-            grid[i][j] = match c {
-                ' ' => Tile::Void,
-                '.' => Tile::Open,
-                '#' => Tile::Wall,
-                c => panic!("unrecognised tile {c:?}"),
-            }
-        }
-    }
-
-    let mut instructions: Vec<Instruction> = Vec::new();
-
-    let mut next_move = String::new();
-    for c in raw_instructions.chars() {
-        if c.is_numeric() {
-            next_move.push(c);
-        } else {
-            if !next_move.is_empty() {
-                let v: usize = next_move.parse().unwrap();
-                instructions.push(Instruction::Move(v));
-                next_move.clear();
-            }
-            match c {
-                'R' => instructions.push(Instruction::TurnRight),
-                'L' => instructions.push(Instruction::TurnLeft),
-                c => panic!("unrecognised instruction {c:?}"),
-            }
-        }
-    }
-    if !next_move.is_empty() {
-        let v: usize = next_move.parse().unwrap();
-        instructions.push(Instruction::Move(v));
-    }
-
-    (grid, instructions)
-}
-
 struct State<'a> {
-    grid: &'a Vec<Vec<Tile>>,
+    grid: &'a Grid<Tile>,
     row: usize,
     column: usize,
     direction: Direction,
 }
 
 impl<'a> State<'a> {
-    pub fn new(grid: &'a Vec<Vec<Tile>>) -> Self {
-        let column = grid[0]
-            .iter()
-            .enumerate()
-            .find(|(_, &t)| t == Tile::Open)
-            .unwrap()
-            .0;
+    pub fn new(grid: &'a Grid<Tile>) -> Self {
+        let column = grid.row(0).iter().position(|&t| t == Tile::Open).unwrap();
         State {
             grid,
             row: 0,
@@ -203,8 +104,8 @@ impl<'a> State<'a> {
     pub fn execute(&mut self, instruction: Instruction) {
         match instruction {
             Instruction::Move(x) => self.mv(x),
-            Instruction::TurnRight => self.direction.turn_right(),
-            Instruction::TurnLeft => self.direction.turn_left(),
+            Instruction::TurnRight => self.direction = self.direction.turn_right(),
+            Instruction::TurnLeft => self.direction = self.direction.turn_left(),
         }
     }
 
@@ -212,34 +113,17 @@ impl<'a> State<'a> {
         for _ in 0..spaces {
             let (mut row, mut column) = (self.row, self.column);
             loop {
-                (row, column) = match self.direction {
-                    Direction::Right => (row, (column + 1) % self.grid[row].len()),
-                    Direction::Down => ((row + 1) % self.grid.len(), column),
-                    Direction::Left => (
-                        row,
-                        if column == 0 {
-                            self.grid[row].len() - 1
-                        } else {
-                            column - 1
-                        },
-                    ),
-                    Direction::Up => (
-                        if row == 0 {
-                            self.grid.len() - 1
-                        } else {
-                            row - 1
-                        },
-                        column,
-                    ),
-                };
-                if self.grid[row][column] != Tile::Void {
+                let step = self.direction.step();
+                row = (row as i64 + step.y).rem_euclid(self.grid.height() as i64) as usize;
+                column = (column as i64 + step.x).rem_euclid(self.grid.width() as i64) as usize;
+                if self.grid[(column, row)] != Tile::Void {
                     break;
                 }
             }
-            if self.grid[row][column] == Tile::Wall {
+            if self.grid[(column, row)] == Tile::Wall {
                 break;
             }
-            assert_eq!(self.grid[row][column], Tile::Open);
+            assert_eq!(self.grid[(column, row)], Tile::Open);
             self.row = row;
             self.column = column;
         }
@@ -247,36 +131,22 @@ impl<'a> State<'a> {
 }
 
 struct CubeStateBuilder<'a> {
-    grid: &'a Vec<Vec<Tile>>,
+    grid: &'a Grid<Tile>,
     side_len: usize,
 }
 
 impl<'a> CubeStateBuilder<'a> {
-    fn new(grid: &'a Vec<Vec<Tile>>) -> Self {
-        let max_grid_dim = grid.len().max(grid[0].len());
+    fn new(grid: &'a Grid<Tile>) -> Self {
+        let max_grid_dim = grid.height().max(grid.width());
         let side_len = max_grid_dim / 4;
         Self { grid, side_len }
     }
 
-    fn rotate_right(grid: &Vec<Vec<Tile>>) -> Vec<Vec<Tile>> {
-        (0..grid[0].len())
-            .map(|i| {
-                (0..grid.len())
-                    .map(|j| grid[grid.len() - j - 1][i])
-                    .collect()
-            })
-            .collect()
-    }
-
     /// Extract a face from the grid. `up_is` indicates the direction on the grid that up is for this face.
-    fn extract_face(&self, coords: (usize, usize), up_is: Direction) -> Vec<Vec<Tile>> {
-        let mut face = (coords.0..coords.0 + self.side_len)
-            .map(|i| {
-                (coords.1..coords.1 + self.side_len)
-                    .map(|j| self.grid[i][j])
-                    .collect()
-            })
-            .collect();
+    fn extract_face(&self, coords: (usize, usize), up_is: Direction) -> Grid<Tile> {
+        let mut face = Grid::from_fn(self.side_len, self.side_len, |(x, y)| {
+            self.grid[(coords.1 + x, coords.0 + y)]
+        });
         let rotations = match up_is {
             Direction::Up => 0,
             Direction::Right => 3,
@@ -285,13 +155,13 @@ impl<'a> CubeStateBuilder<'a> {
         };
 
         for _ in 0..rotations {
-            face = Self::rotate_right(&face);
+            face = face.rotate_right();
         }
         face
     }
 
     fn find_first_face(&self) -> (usize, usize) {
-        for (i, &tile) in self.grid[0].iter().enumerate().step_by(self.side_len) {
+        for (i, &tile) in self.grid.row(0).iter().enumerate().step_by(self.side_len) {
             if tile != Tile::Void {
                 return (0, i);
             }
@@ -317,50 +187,32 @@ impl<'a> CubeStateBuilder<'a> {
             Direction::Left => 3,
         };
 
-        let search_grid_direction = up_is + search_offset;
+        let search_grid_direction = turn(up_is, search_offset);
 
         // top left of face relative to grid
-        let new_face_grid_coords = match search_grid_direction {
-            Direction::Right => {
-                let new_column = coords.1 + self.side_len;
-                if new_column >= self.grid[0].len() {
-                    return None;
-                }
-                (coords.0, new_column)
-            }
-            Direction::Down => {
-                let new_row = coords.0 + self.side_len;
-                if new_row >= self.grid.len() {
-                    return None;
-                }
-                (new_row, coords.1)
-            }
-            Direction::Left => {
-                if coords.1 < self.side_len {
-                    return None;
-                }
-                (coords.0, coords.1 - self.side_len)
-            }
-            Direction::Up => {
-                if coords.0 < self.side_len {
-                    return None;
-                }
-                (coords.0 - self.side_len, coords.1)
-            }
-        };
+        let step = search_grid_direction.step() * self.side_len as i64;
+        let new_face_grid_coords = (
+            coords.0.checked_add_signed(step.y as isize)?,
+            coords.1.checked_add_signed(step.x as isize)?,
+        );
+        if new_face_grid_coords.0 >= self.grid.height()
+            || new_face_grid_coords.1 >= self.grid.width()
+        {
+            return None;
+        }
 
-        if self.grid[new_face_grid_coords.0][new_face_grid_coords.1] == Tile::Void {
+        if self.grid[(new_face_grid_coords.1, new_face_grid_coords.0)] == Tile::Void {
             return None;
         }
 
         let (new_face, rotation_offset) = face.face_in(search_face_direction);
-        let new_face_up_is = up_is + rotation_offset;
+        let new_face_up_is = turn(up_is, rotation_offset);
 
         Some((new_face, new_face_grid_coords, new_face_up_is))
     }
 
     fn build(&self) -> CubeState {
-        let mut faces: HashMap<Face, Vec<Vec<Tile>>> = HashMap::new();
+        let mut faces: HashMap<Face, Grid<Tile>> = HashMap::new();
         let mut face_coords: HashMap<Face, (usize, usize)> = HashMap::new();
         let mut face_up_is: HashMap<Face, Direction> = HashMap::new();
 
@@ -376,12 +228,7 @@ impl<'a> CubeStateBuilder<'a> {
         to_search.push((Face::Top, top_face_coords, Direction::Up));
 
         while let Some((face, coords, up_is)) = to_search.pop() {
-            for direction in [
-                Direction::Up,
-                Direction::Right,
-                Direction::Down,
-                Direction::Left,
-            ] {
+            for direction in Direction::ALL {
                 if let Some((new_face, new_coords, new_up_is)) =
                     self.find_next_face(coords, up_is, face, direction)
                 {
@@ -412,7 +259,7 @@ impl<'a> CubeStateBuilder<'a> {
 
 #[derive(Clone, Debug)]
 struct CubeState {
-    faces: HashMap<Face, Vec<Vec<Tile>>>,
+    faces: HashMap<Face, Grid<Tile>>,
     row: usize,
     column: usize,
     direction: Direction,
@@ -427,8 +274,8 @@ impl CubeState {
     pub fn execute(&mut self, instruction: Instruction) {
         match instruction {
             Instruction::Move(x) => self.mv(x),
-            Instruction::TurnRight => self.direction.turn_right(),
-            Instruction::TurnLeft => self.direction.turn_left(),
+            Instruction::TurnRight => self.direction = self.direction.turn_right(),
+            Instruction::TurnLeft => self.direction = self.direction.turn_left(),
         }
     }
 
@@ -554,10 +401,10 @@ impl CubeState {
                 }
             };
             let next_face = &self.faces[&face];
-            if next_face[row][column] == Tile::Wall {
+            if next_face[(column, row)] == Tile::Wall {
                 break;
             }
-            assert_eq!(next_face[row][column], Tile::Open);
+            assert_eq!(next_face[(column, row)], Tile::Open);
             self.face = face;
             self.row = row;
             self.column = column;
@@ -598,18 +445,66 @@ impl CubeState {
     ) -> ((usize, usize), Direction) {
         let new_coords = (self.side_len - coords.1 - 1, coords.0);
 
-        let new_direction = direction - 1;
+        let new_direction = direction.turn_left();
         (new_coords, new_direction)
     }
 }
 
-fn main() {
-    let (grid, instructions) = input();
+pub(crate) struct Day22;
 
-    {
-        let _timer = PerfTimer::new("Part 1");
-        let mut state = State::new(&grid);
-        for &instruction in &instructions {
+impl Solution for Day22 {
+    type Input = (Grid<Tile>, Vec<Instruction>);
+
+    fn parse(input: &str) -> Self::Input {
+        let raw_rows: Vec<&str> = input.lines().take_while(|l| !l.trim().is_empty()).collect();
+        let raw_instructions = input.lines().last().unwrap();
+
+        let row_count = raw_rows.len();
+        let column_count = raw_rows.iter().map(|r| r.len()).max().unwrap();
+
+        // The lines of the map stop at its last tile, so they aren't all the same length.
+        let mut grid = Grid::new(column_count, row_count, Tile::Void);
+        for (i, raw_row) in raw_rows.iter().copied().enumerate() {
+            for (j, c) in raw_row.chars().enumerate() {
+                grid[(j, i)] = match c {
+                    ' ' => Tile::Void,
+                    '.' => Tile::Open,
+                    '#' => Tile::Wall,
+                    c => panic!("unrecognised tile {c:?}"),
+                }
+            }
+        }
+
+        let mut instructions: Vec<Instruction> = Vec::new();
+
+        let mut next_move = String::new();
+        for c in raw_instructions.chars() {
+            if c.is_numeric() {
+                next_move.push(c);
+            } else {
+                if !next_move.is_empty() {
+                    let v: usize = next_move.parse().unwrap();
+                    instructions.push(Instruction::Move(v));
+                    next_move.clear();
+                }
+                match c {
+                    'R' => instructions.push(Instruction::TurnRight),
+                    'L' => instructions.push(Instruction::TurnLeft),
+                    c => panic!("unrecognised instruction {c:?}"),
+                }
+            }
+        }
+        if !next_move.is_empty() {
+            let v: usize = next_move.parse().unwrap();
+            instructions.push(Instruction::Move(v));
+        }
+
+        (grid, instructions)
+    }
+
+    fn part_1((grid, instructions): &Self::Input) -> impl Display {
+        let mut state = State::new(grid);
+        for &instruction in instructions {
             state.execute(instruction);
         }
 
@@ -622,19 +517,15 @@ fn main() {
             Direction::Up => 3,
         };
 
-        let part_1 = 1000 * final_row + 4 * final_column + final_facing;
-
-        println!("Part 1: {part_1}");
+        1000 * final_row + 4 * final_column + final_facing
     }
-    {
-        let _timer = PerfTimer::new("Part 2");
-        let mut state = CubeStateBuilder::new(&grid).build();
-        for (_, &instruction) in instructions.iter().enumerate() {
+
+    fn part_2((grid, instructions): &Self::Input) -> impl Display {
+        let mut state = CubeStateBuilder::new(grid).build();
+        for &instruction in instructions.iter() {
             state.execute(instruction);
         }
 
-        let part_2 = state.score();
-
-        println!("Part 2: {part_2}");
+        state.score()
     }
 }
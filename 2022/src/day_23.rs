@@ -1,26 +1,12 @@
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
 
-use util::PerfTimer;
+use crate::util::Solution;
 
 type Point = (i64, i64);
 
-fn input() -> HashSet<Point> {
-    let raw = util::get_day_input(23);
-    let mut elf_positions = HashSet::new();
-    for (row, line) in raw.lines().enumerate() {
-        for (column, cell) in line.chars().enumerate() {
-            match cell {
-                '.' => {}
-                '#' => {
-                    elf_positions.insert((row as i64, column as i64));
-                }
-                c => panic!("unexpected character {c:?} in input at ({row},{column})"),
-            }
-        }
-    }
-    elf_positions
-}
-
 fn propose_north(elf_positions: &HashSet<Point>, (elf_row, elf_column): Point) -> Option<Point> {
     for i in -1..=1 {
         if elf_positions.contains(&(elf_row - 1, elf_column + i)) {
@@ -153,10 +139,28 @@ fn move_elves(elf_positions: &mut HashSet<Point>, turn_offset: usize) -> bool {
 //     println!();
 // }
 
-fn main() {
-    let input = input();
-    {
-        let _timer = PerfTimer::new("Part 1");
+pub(crate) struct Day23;
+
+impl Solution for Day23 {
+    type Input = HashSet<Point>;
+
+    fn parse(input: &str) -> Self::Input {
+        let mut elf_positions = HashSet::new();
+        for (row, line) in input.lines().enumerate() {
+            for (column, cell) in line.chars().enumerate() {
+                match cell {
+                    '.' => {}
+                    '#' => {
+                        elf_positions.insert((row as i64, column as i64));
+                    }
+                    c => panic!("unexpected character {c:?} in input at ({row},{column})"),
+                }
+            }
+        }
+        elf_positions
+    }
+
+    fn part_1(input: &Self::Input) -> impl Display {
         let mut elf_positions = input.clone();
         // print_elves(&elf_positions);
         for i in 0..10 {
@@ -191,18 +195,16 @@ fn main() {
             .unwrap();
 
         let total_area = (max_row - min_row + 1) * (max_col - min_col + 1);
-        let part_1 = total_area - elf_positions.len() as i64;
-
-        println!("Part 1: {part_1}");
+        total_area - elf_positions.len() as i64
     }
-    {
-        let _timer = PerfTimer::new("Part 2");
+
+    fn part_2(input: &Self::Input) -> impl Display {
         let mut elf_positions = input.clone();
         for i in 0.. {
             if !move_elves(&mut elf_positions, i) {
-                println!("Part 2: {}", i + 1);
-                break;
+                return i + 1;
             }
         }
+        unreachable!("elves never stopped moving")
     }
 }
@@ -0,0 +1,244 @@
+use std::{collections::HashSet, fmt::Display};
+
+use crate::util::Solution;
+use aoc_common::{astar, Direction, Point2};
+use blizzards::{BlizzardMap, BlizzardMaps};
+use contain::SimpleContainer;
+
+const START: Point2 = Point2::new(1, 0);
+
+pub(crate) struct Parameters {
+    blizzards: Vec<(Point2, Direction)>,
+    walls: HashSet<Point2>,
+    start: Point2,
+    end: Point2,
+}
+
+mod blizzards {
+    use std::collections::{HashMap, HashSet};
+
+    use aoc_common::{Direction, Point2};
+    use contain::{Container, SimpleContainer};
+
+    pub struct BlizzardMap {
+        blizzards: Vec<(Point2, Direction)>,
+        occupied: HashSet<Point2>,
+        height: i64,
+        width: i64,
+    }
+
+    impl BlizzardMap {
+        pub fn new(blizzards: Vec<(Point2, Direction)>, width: i64, height: i64) -> Self {
+            let occupied = blizzards.iter().map(|&(point, _)| point).collect();
+            Self {
+                blizzards,
+                occupied,
+                height,
+                width,
+            }
+        }
+
+        pub fn next(&self) -> Self {
+            let blizzards = self
+                .blizzards
+                .iter()
+                .map(|&(point, direction)| {
+                    // Blizzards that reach a wall reappear on the far side of the valley.
+                    let mut new_point = point + direction;
+                    if new_point.x == 0 {
+                        new_point.x = self.width - 2;
+                    } else if new_point.x == self.width - 1 {
+                        new_point.x = 1;
+                    }
+                    if new_point.y == 0 {
+                        new_point.y = self.height - 2;
+                    } else if new_point.y == self.height - 1 {
+                        new_point.y = 1;
+                    }
+                    (new_point, direction)
+                })
+                .collect();
+            Self::new(blizzards, self.width, self.height)
+        }
+
+        pub fn contains(&self, p: &Point2) -> bool {
+            self.occupied.contains(p)
+        }
+    }
+
+    pub struct BlizzardMaps<'a> {
+        maps: HashMap<usize, &'a BlizzardMap>,
+        container: &'a SimpleContainer<'a, BlizzardMap>,
+    }
+
+    impl<'a> BlizzardMaps<'a> {
+        pub fn new(container: &'a SimpleContainer<'a, BlizzardMap>, initial: BlizzardMap) -> Self {
+            let mut maps = HashMap::new();
+            let initial = container.put(initial);
+            maps.insert(0, initial);
+            Self { maps, container }
+        }
+
+        pub fn get(&mut self, i: usize) -> &'a BlizzardMap {
+            if let Some(&map) = self.maps.get(&i) {
+                map
+            } else {
+                let old_map = self.get(i - 1);
+                let new_map = old_map.next();
+                let new_map = self.container.put(new_map);
+                self.maps.insert(i, new_map);
+                new_map
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct State {
+    position: Point2,
+    minutes_passed: usize,
+    end: Point2,
+}
+
+impl State {
+    #[must_use]
+    fn step(&self, direction: Option<Direction>) -> Self {
+        Self {
+            position: direction.map_or(self.position, |direction| self.position + direction),
+            minutes_passed: self.minutes_passed + 1,
+            end: self.end,
+        }
+    }
+
+    fn distance_to_end(&self) -> usize {
+        self.position.manhattan(self.end) as usize
+    }
+}
+
+fn solve(blizzards: &mut BlizzardMaps, walls: &HashSet<Point2>, initial_state: State) -> State {
+    let max_row = walls.iter().map(|wall| wall.y).max().unwrap();
+    let successors = |state: &State| {
+        let blizzard_map = blizzards.get(state.minutes_passed + 1);
+
+        let new_states = if state.position.y == 0 || state.position.y == max_row {
+            let new_state = if state.position.y == 0 {
+                state.step(Some(Direction::Down))
+            } else {
+                state.step(Some(Direction::Up))
+            };
+            if blizzard_map.contains(&new_state.position) {
+                vec![state.step(None)]
+            } else {
+                vec![new_state]
+            }
+        } else {
+            Direction::ALL
+                .map(Some)
+                .into_iter()
+                .chain([None])
+                .map(|direction| state.step(direction))
+                .filter(|new_state| {
+                    !blizzard_map.contains(&new_state.position)
+                        && !walls.contains(&new_state.position)
+                })
+                .collect()
+        };
+        new_states.into_iter().map(|new_state| (new_state, 1))
+    };
+
+    let path = astar(
+        [initial_state],
+        successors,
+        State::distance_to_end,
+        |state| state.position == state.end,
+    )
+    .unwrap();
+    *path.states.last().unwrap()
+}
+
+fn initial_blizzards(parameters: &Parameters) -> BlizzardMap {
+    let width = parameters.walls.iter().map(|wall| wall.x).max().unwrap() + 1;
+    let height = parameters.walls.iter().map(|wall| wall.y).max().unwrap() + 1;
+    BlizzardMap::new(parameters.blizzards.clone(), width, height)
+}
+
+pub(crate) struct Day24;
+
+impl Solution for Day24 {
+    type Input = Parameters;
+
+    fn parse(input: &str) -> Self::Input {
+        let mut blizzards = Vec::new();
+        let mut walls = HashSet::new();
+        for (y, line) in (0..).zip(input.lines()) {
+            for (x, cell) in (0..).zip(line.chars()) {
+                let point = Point2::new(x, y);
+                match cell {
+                    '.' => {}
+                    '#' => {
+                        walls.insert(point);
+                    }
+                    _ => blizzards.push((point, Direction::try_from(cell).unwrap())),
+                }
+            }
+        }
+        let bottom_right = walls
+            .iter()
+            .copied()
+            .max_by_key(|wall| (wall.y, wall.x))
+            .unwrap();
+        let end = bottom_right + Direction::Left;
+        assert!(!walls.contains(&START));
+        assert!(!walls.contains(&end));
+
+        Parameters {
+            blizzards,
+            walls,
+            start: START,
+            end,
+        }
+    }
+
+    fn part_1(parameters: &Self::Input) -> impl Display {
+        let blizzard_container = SimpleContainer::new();
+        let mut blizzards = BlizzardMaps::new(&blizzard_container, initial_blizzards(parameters));
+        let initial_state = State {
+            position: parameters.start,
+            minutes_passed: 0,
+            end: parameters.end,
+        };
+
+        solve(&mut blizzards, &parameters.walls, initial_state).minutes_passed
+    }
+
+    fn part_2(parameters: &Self::Input) -> impl Display {
+        let blizzard_container = SimpleContainer::new();
+        let mut blizzards = BlizzardMaps::new(&blizzard_container, initial_blizzards(parameters));
+        let initial_state = State {
+            position: parameters.start,
+            minutes_passed: 0,
+            end: parameters.end,
+        };
+
+        let journey_1 = solve(&mut blizzards, &parameters.walls, initial_state);
+        let journey_2 = solve(
+            &mut blizzards,
+            &parameters.walls,
+            State {
+                position: parameters.end,
+                end: parameters.start,
+                minutes_passed: journey_1.minutes_passed,
+            },
+        );
+        let journey_3 = solve(
+            &mut blizzards,
+            &parameters.walls,
+            State {
+                position: parameters.start,
+                end: parameters.end,
+                minutes_passed: journey_2.minutes_passed,
+            },
+        );
+        journey_3.minutes_passed
+    }
+}
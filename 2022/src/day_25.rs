@@ -1,9 +1,6 @@
-use util::PerfTimer;
+use std::fmt::Display;
 
-fn input() -> Vec<String> {
-    let snafu_nums = util::get_day_input(25);
-    snafu_nums.lines().map(String::from).collect()
-}
+use crate::util::Solution;
 
 fn from_snafu(snafu: &str) -> i64 {
     let mut v: i64 = 0;
@@ -40,17 +37,26 @@ fn to_snafu(mut decimal: i64) -> String {
     snafu.chars().rev().collect()
 }
 
-fn main() {
-    let snafu_nums = input();
-    let _timer = PerfTimer::new("Part 1");
-    let sum: i64 = snafu_nums.iter().map(|x| from_snafu(x)).sum();
-    let part_1 = to_snafu(sum);
-    println!("Part 1: {part_1}");
+pub(crate) struct Day25;
+
+impl Solution for Day25 {
+    type Input = Vec<String>;
+
+    const HAS_PART_2: bool = false;
+
+    fn parse(input: &str) -> Self::Input {
+        input.lines().map(String::from).collect()
+    }
+
+    fn part_1(snafu_nums: &Self::Input) -> impl Display {
+        let sum: i64 = snafu_nums.iter().map(|x| from_snafu(x)).sum();
+        to_snafu(sum)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{from_snafu, to_snafu};
+    use super::{from_snafu, to_snafu};
 
     #[test]
     fn test_from_snafu() {
@@ -1,18 +1,7 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, fmt::Display};
 
+use crate::util::Solution;
 use itertools::Itertools;
-use util::PerfTimer;
-
-fn input() -> Vec<(Vec<char>, Vec<char>)> {
-    util::get_day_input(3)
-        .trim()
-        .lines()
-        .map(|line| {
-            let (l, r) = line.split_at(line.len() / 2);
-            (l.chars().collect(), r.chars().collect())
-        })
-        .collect()
-}
 
 fn priority(c: char) -> i64 {
     if c.is_ascii_lowercase() {
@@ -24,10 +13,23 @@ fn priority(c: char) -> i64 {
     }
 }
 
-fn main() {
-    let rucksacks = input();
-    {
-        let _timer = PerfTimer::new("Part 1");
+pub(crate) struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<(Vec<char>, Vec<char>)>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .trim()
+            .lines()
+            .map(|line| {
+                let (l, r) = line.split_at(line.len() / 2);
+                (l.chars().collect(), r.chars().collect())
+            })
+            .collect()
+    }
+
+    fn part_1(rucksacks: &Self::Input) -> impl Display {
         let part_1: i64 = rucksacks
             .iter()
             .map(|(left, right)| {
@@ -39,10 +41,11 @@ fn main() {
                     .sum::<i64>()
             })
             .sum();
-        println!("Part 1: {part_1}");
+
+        part_1
     }
-    {
-        let _timer = PerfTimer::new("Part 2");
+
+    fn part_2(rucksacks: &Self::Input) -> impl Display {
         let part_2: i64 = rucksacks
             .iter()
             .map(|(left, right)| {
@@ -58,6 +61,7 @@ fn main() {
                     .sum::<i64>()
             })
             .sum();
-        println!("Part 2: {part_2}");
+
+        part_2
     }
 }
@@ -0,0 +1,42 @@
+use std::fmt::Display;
+
+use crate::util::Solution;
+
+pub(crate) struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<((usize, usize), (usize, usize))>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .lines()
+            .map(|line| {
+                let (l, r) = line.split_once(',').unwrap();
+                let (l_a, l_b) = l.split_once('-').unwrap();
+                let (r_a, r_b) = r.split_once('-').unwrap();
+                (
+                    (l_a.parse().unwrap(), l_b.parse().unwrap()),
+                    (r_a.parse().unwrap(), r_b.parse().unwrap()),
+                )
+            })
+            .collect()
+    }
+
+    fn part_1(assignments: &Self::Input) -> impl Display {
+        assignments
+            .iter()
+            .copied()
+            .filter(|&((l_a, l_b), (r_a, r_b))| {
+                l_a <= r_a && l_b >= r_b || r_a <= l_a && r_b >= l_b
+            })
+            .count()
+    }
+
+    fn part_2(assignments: &Self::Input) -> impl Display {
+        assignments
+            .iter()
+            .copied()
+            .filter(|&((l_a, l_b), (r_a, r_b))| l_a <= r_b && l_b >= r_a)
+            .count()
+    }
+}
@@ -0,0 +1,93 @@
+use std::fmt::Display;
+
+use crate::util::Solution;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Instruction {
+    num_to_move: usize,
+    from: usize,
+    to: usize,
+}
+
+pub(crate) struct Day5;
+
+impl Solution for Day5 {
+    type Input = (Vec<Vec<char>>, Vec<Instruction>);
+
+    fn parse(input: &str) -> Self::Input {
+        let (raw_stacks, raw_moves) = input.trim_end().split_once("\n\n").unwrap();
+        let num_stacks = raw_stacks
+            .lines()
+            .last()
+            .unwrap()
+            .split_whitespace()
+            .count();
+        let mut stacks: Vec<Vec<char>> = vec![Vec::new(); num_stacks];
+        for line in raw_stacks.lines() {
+            if !line.starts_with(" 1 ") {
+                for (i, c) in line.chars().skip(1).step_by(4).enumerate() {
+                    if c != ' ' {
+                        stacks[i].push(c);
+                    }
+                }
+            }
+        }
+        for stack in &mut stacks {
+            stack.reverse();
+        }
+
+        let moves = raw_moves
+            .lines()
+            .map(|line| {
+                let words: Vec<&str> = line.split_whitespace().collect();
+                Instruction {
+                    num_to_move: words[1].parse().unwrap(),
+                    from: words[3].parse().unwrap(),
+                    to: words[5].parse().unwrap(),
+                }
+            })
+            .collect();
+
+        (stacks, moves)
+    }
+
+    fn part_1((initial_state, instructions): &Self::Input) -> impl Display {
+        let part_1: String = instructions
+            .iter()
+            .copied()
+            .fold(initial_state.clone(), |mut state, instruction| {
+                for _ in 0..instruction.num_to_move {
+                    let temp = state[instruction.from - 1].pop().unwrap();
+                    state[instruction.to - 1].push(temp);
+                }
+                state
+            })
+            .into_iter()
+            .map(|mut stack| stack.pop().unwrap())
+            .collect();
+
+        part_1
+    }
+
+    fn part_2((initial_state, instructions): &Self::Input) -> impl Display {
+        let mut temp: Vec<char> = Vec::new();
+        let part_2: String = instructions
+            .iter()
+            .copied()
+            .fold(initial_state.clone(), |mut state, instruction| {
+                assert!(temp.is_empty());
+                for _ in 0..instruction.num_to_move {
+                    temp.push(state[instruction.from - 1].pop().unwrap());
+                }
+                for _ in 0..instruction.num_to_move {
+                    state[instruction.to - 1].push(temp.pop().unwrap());
+                }
+                state
+            })
+            .into_iter()
+            .map(|mut stack| stack.pop().unwrap())
+            .collect();
+
+        part_2
+    }
+}
@@ -0,0 +1,31 @@
+use std::fmt::Display;
+
+use crate::util::Solution;
+use itertools::Itertools;
+
+fn find_marker_index(signal: &[char], size: usize) -> usize {
+    signal
+        .windows(size)
+        .find_position(|x| x.iter().all_unique())
+        .unwrap()
+        .0
+        + size
+}
+
+pub(crate) struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<char>;
+
+    fn parse(input: &str) -> Self::Input {
+        input.chars().collect()
+    }
+
+    fn part_1(signal: &Self::Input) -> impl Display {
+        find_marker_index(signal, 4)
+    }
+
+    fn part_2(signal: &Self::Input) -> impl Display {
+        find_marker_index(signal, 14)
+    }
+}
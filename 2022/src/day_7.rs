@@ -1,63 +1,19 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt::Display};
 
-use util::{get_day_input, PerfTimer};
+use crate::util::Solution;
 
 #[derive(Debug, PartialEq, Eq)]
-enum Entry {
+pub(crate) enum Entry {
     Dir(String),
     File(String, u64),
 }
 
 #[derive(Debug, PartialEq, Eq)]
-enum Command {
+pub(crate) enum Command {
     Cd(String),
     Ls(Vec<Entry>),
 }
 
-fn input() -> Vec<Command> {
-    let raw = get_day_input(7);
-    let mut commands = Vec::new();
-    let mut current_dir_name = None;
-    let mut current_dir_contents = None;
-    for line in raw.lines() {
-        let line: Vec<&str> = line.split(' ').collect();
-        match line[0] {
-            "$" => {
-                if let Some(dir_name) = current_dir_name.take() {
-                    commands.push(Command::Cd(dir_name));
-                }
-                if let Some(dir_contents) = current_dir_contents.take() {
-                    commands.push(Command::Ls(dir_contents))
-                }
-                match line[1] {
-                    "cd" => current_dir_name = Some(String::from(line[2])),
-                    "ls" => current_dir_contents = Some(Vec::new()),
-                    o => panic!("Unrecognised command {o:?}"),
-                }
-            }
-            "dir" => current_dir_contents
-                .as_mut()
-                .unwrap()
-                .push(Entry::Dir(String::from(line[1]))),
-            o => {
-                let size: u64 = o.parse().unwrap();
-                let name = String::from(line[1]);
-                current_dir_contents
-                    .as_mut()
-                    .unwrap()
-                    .push(Entry::File(name, size));
-            }
-        }
-    }
-    if let Some(dir_name) = current_dir_name.take() {
-        commands.push(Command::Cd(dir_name));
-    }
-    if let Some(dir_contents) = current_dir_contents.take() {
-        commands.push(Command::Ls(dir_contents))
-    }
-    commands
-}
-
 enum FsItem {
     Dir(HashMap<String, FsItem>),
     File(u64),
@@ -158,29 +114,75 @@ fn dir_sizes(directories: &HashMap<String, FsItem>) -> (u64, Vec<u64>) {
     (my_size, sub_sizes)
 }
 
-fn main() {
-    let commands = input();
-    {
-        let _timer = PerfTimer::new("Part 1");
-        let directories = build_directories(&commands);
+pub(crate) struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<Command>;
+
+    fn parse(input: &str) -> Self::Input {
+        let mut commands = Vec::new();
+        let mut current_dir_name = None;
+        let mut current_dir_contents = None;
+        for line in input.lines() {
+            let line: Vec<&str> = line.split(' ').collect();
+            match line[0] {
+                "$" => {
+                    if let Some(dir_name) = current_dir_name.take() {
+                        commands.push(Command::Cd(dir_name));
+                    }
+                    if let Some(dir_contents) = current_dir_contents.take() {
+                        commands.push(Command::Ls(dir_contents))
+                    }
+                    match line[1] {
+                        "cd" => current_dir_name = Some(String::from(line[2])),
+                        "ls" => current_dir_contents = Some(Vec::new()),
+                        o => panic!("Unrecognised command {o:?}"),
+                    }
+                }
+                "dir" => current_dir_contents
+                    .as_mut()
+                    .unwrap()
+                    .push(Entry::Dir(String::from(line[1]))),
+                o => {
+                    let size: u64 = o.parse().unwrap();
+                    let name = String::from(line[1]);
+                    current_dir_contents
+                        .as_mut()
+                        .unwrap()
+                        .push(Entry::File(name, size));
+                }
+            }
+        }
+        if let Some(dir_name) = current_dir_name.take() {
+            commands.push(Command::Cd(dir_name));
+        }
+        if let Some(dir_contents) = current_dir_contents.take() {
+            commands.push(Command::Ls(dir_contents))
+        }
+        commands
+    }
+
+    fn part_1(commands: &Self::Input) -> impl Display {
+        let directories = build_directories(commands);
         let part_1: u64 = dir_sizes(directories.unwrap_dir())
             .1
             .iter()
             .filter(|&&x| x <= 100_000)
             .sum();
-        println!("Part 1: {part_1}");
+
+        part_1
     }
-    {
-        let _timer = PerfTimer::new("Part 2");
-        let directories = build_directories(&commands);
+
+    fn part_2(commands: &Self::Input) -> impl Display {
+        let directories = build_directories(commands);
         let (used_space, mut dir_sizes) = dir_sizes(directories.unwrap_dir());
         let remaining_space = 70_000_000 - used_space;
         let space_to_clear = 30_000_000 - remaining_space;
         dir_sizes.sort();
-        let part_2 = dir_sizes
+
+        dir_sizes
             .into_iter()
             .find(|&x| x >= space_to_clear)
-            .unwrap();
-        println!("Part 2: {part_2}");
+            .unwrap()
     }
 }
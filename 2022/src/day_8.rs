@@ -0,0 +1,52 @@
+use std::fmt::Display;
+
+use aoc_common::{Grid, Pos, ORTHOGONAL};
+
+use crate::util::Solution;
+
+fn is_visible(grid: &Grid<i8>, tree: Pos) -> bool {
+    let height = grid[tree];
+    ORTHOGONAL
+        .into_iter()
+        .any(|step| grid.ray(tree, step).all(|pos| grid[pos] < height))
+}
+
+fn calculate_scenic_score(grid: &Grid<i8>, tree: Pos) -> usize {
+    let height = grid[tree];
+    ORTHOGONAL
+        .into_iter()
+        .map(|step| {
+            let mut seen = 0;
+            for pos in grid.ray(tree, step) {
+                seen += 1;
+                if grid[pos] >= height {
+                    break;
+                }
+            }
+            seen
+        })
+        .product()
+}
+
+pub(crate) struct Day8;
+
+impl Solution for Day8 {
+    type Input = Grid<i8>;
+
+    fn parse(input: &str) -> Self::Input {
+        Grid::parse(input, |c| Some((c as u8 - b'0') as i8)).unwrap()
+    }
+
+    fn part_1(grid: &Self::Input) -> impl Display {
+        grid.positions()
+            .filter(|&tree| is_visible(grid, tree))
+            .count()
+    }
+
+    fn part_2(grid: &Self::Input) -> impl Display {
+        grid.positions()
+            .map(|tree| calculate_scenic_score(grid, tree))
+            .max()
+            .unwrap()
+    }
+}
@@ -0,0 +1,62 @@
+use std::{collections::HashSet, fmt::Display};
+
+use aoc_common::{Direction, Point2};
+
+use crate::util::Solution;
+
+fn calculate_tail_pos(head_pos: Point2, tail_pos: Point2) -> Point2 {
+    if head_pos.chebyshev(tail_pos) > 1 {
+        tail_pos + (head_pos - tail_pos).signum()
+    } else {
+        tail_pos
+    }
+}
+
+fn simulate(instructions: &[(Direction, i64)], knot_count: usize) -> usize {
+    let mut knots = vec![Point2::ORIGIN; knot_count];
+    let mut tail_visited = HashSet::new();
+    tail_visited.insert(knots[knot_count - 1]);
+    for &(direction, distance) in instructions {
+        for _ in 0..distance {
+            knots[0] += direction;
+            let mut head_pos = knots[0];
+            for tail_pos in knots.iter_mut().skip(1) {
+                *tail_pos = calculate_tail_pos(head_pos, *tail_pos);
+                head_pos = *tail_pos;
+            }
+            tail_visited.insert(knots[knot_count - 1]);
+        }
+    }
+    tail_visited.len()
+}
+
+pub(crate) struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<(Direction, i64)>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .trim()
+            .lines()
+            .map(|line| {
+                let (direction, distance) = line.split_once(' ').unwrap();
+                let direction = direction
+                    .parse::<char>()
+                    .ok()
+                    .and_then(|c| Direction::try_from(c).ok())
+                    .unwrap_or_else(|| panic!("Couldn't parse {direction:?} into a direction"));
+                let distance = distance.parse().unwrap();
+                (direction, distance)
+            })
+            .collect()
+    }
+
+    fn part_1(instructions: &Self::Input) -> impl Display {
+        simulate(instructions, 2)
+    }
+
+    fn part_2(instructions: &Self::Input) -> impl Display {
+        simulate(instructions, 10)
+    }
+}
@@ -0,0 +1,31 @@
+use aoc_common::{collect_timings, PerfTimer};
+
+const BLUEPRINTS: &str = "\
+Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
+Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.
+";
+
+#[test]
+fn day_19_reports_its_search_under_each_part() {
+    let day = advent_of_code_2022::YEAR.day(19).unwrap();
+    let input = day.parse(BLUEPRINTS);
+    let (answer, timings) = collect_timings(|| {
+        let _timer = PerfTimer::new("Part 1");
+        day.part_1(&input)
+    });
+
+    assert_eq!(answer, "33");
+    let paths: Vec<_> = timings.iter().map(|t| t.path.join("/")).collect();
+    assert_eq!(
+        paths,
+        [
+            "Part 1",
+            "Part 1/find_max_geodes",
+            "Part 1/find_max_geodes/State::next"
+        ]
+    );
+    // One search per blueprint, whichever threads they ran on.
+    assert_eq!(timings[1].count, 2);
+    assert!(timings[2].count > timings[1].count);
+    assert!(timings[2].total <= timings[1].total);
+}
@@ -1,21 +1,22 @@
-use itertools::Itertools;
-use util::PerfTimer;
-
-extern crate util;
+use std::fmt::Display;
 
-fn input() -> Vec<String> {
-    util::get_day_input(1).lines().map(String::from).collect()
-}
+use crate::util::Solution;
+use itertools::Itertools;
 
 const DIGITS: [&str; 9] = [
     "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
 ];
 
-fn main() {
-    let lines = input();
+pub(crate) struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Self::Input {
+        input.lines().map(String::from).collect()
+    }
 
-    {
-        let _timer = PerfTimer::new("Part 1");
+    fn part_1(lines: &Self::Input) -> impl Display {
         let part_1: u32 = lines
             .iter()
             .map(|s| {
@@ -28,24 +29,22 @@ fn main() {
                     .unwrap()
             })
             .sum();
-        println!("Part 1: {part_1}");
+
+        part_1
     }
 
-    {
-        let _timer = PerfTimer::new("Part 2");
+    fn part_2(lines: &Self::Input) -> impl Display {
         let part_2: u32 = lines
             .iter()
             .map(|s| {
-                let (a_digit1_pos, a_digit1) =
-                    s.chars().find_position(|c| c.is_ascii_digit()).unwrap();
-                let (a_digit2_pos, a_digit2) = s
+                let a_digit1 = s.chars().find_position(|c| c.is_ascii_digit());
+                let a_digit2 = s
                     .chars()
                     .enumerate()
                     .collect_vec()
                     .into_iter()
                     .rev()
-                    .find(|(_, c)| c.is_ascii_digit())
-                    .unwrap();
+                    .find(|(_, c)| c.is_ascii_digit());
 
                 let b_digit1 = DIGITS
                     .iter()
@@ -58,16 +57,17 @@ fn main() {
                     .filter_map(|(&d, i)| Some((s.rfind(d)?, i)))
                     .max_by_key(|&(p, _)| p);
 
-                let digit1 = if b_digit1.is_some_and(|(p, _)| p < a_digit1_pos) {
-                    b_digit1.unwrap().1
-                } else {
-                    a_digit1
-                };
-                let digit2 = if b_digit2.is_some_and(|(p, _)| p > a_digit2_pos) {
-                    b_digit2.unwrap().1
-                } else {
-                    a_digit2
-                };
+                // A line might only have its digits spelled out.
+                let (_, digit1) = a_digit1
+                    .into_iter()
+                    .chain(b_digit1)
+                    .min_by_key(|&(p, _)| p)
+                    .unwrap();
+                let (_, digit2) = a_digit2
+                    .into_iter()
+                    .chain(b_digit2)
+                    .max_by_key(|&(p, _)| p)
+                    .unwrap();
 
                 [digit1, digit2]
                     .iter()
@@ -77,6 +77,6 @@ fn main() {
             })
             .sum();
 
-        println!("Part 2: {part_2}");
+        part_2
     }
 }
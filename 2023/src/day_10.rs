@@ -0,0 +1,227 @@
+use std::{collections::HashMap, fmt::Display};
+
+use aoc_common::{Direction, Grid, Pos};
+
+use crate::util::Solution;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Pipe {
+    north: bool,
+    east: bool,
+    south: bool,
+    west: bool,
+}
+
+impl Pipe {
+    fn has_pipe(&self, direction: Direction) -> bool {
+        match direction {
+            Direction::Up => self.north,
+            Direction::Right => self.east,
+            Direction::Down => self.south,
+            Direction::Left => self.west,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct State {
+    position: Pos,
+    last_move: Option<Direction>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SearchState {
+    Outside,
+    Entering,
+    Inside,
+    Leaving,
+}
+
+fn move_state(map: &Grid<Pipe>, state: State) -> Vec<State> {
+    let mut new_states = Vec::new();
+    for direction in Direction::ALL {
+        if state.last_move.map(Direction::opposite) == Some(direction)
+            || !map[state.position].has_pipe(direction)
+        {
+            continue;
+        }
+        if let Some(new_position) = map.offset(state.position, direction) {
+            if map[new_position].has_pipe(direction.opposite()) {
+                new_states.push(State {
+                    position: new_position,
+                    last_move: Some(direction),
+                })
+            }
+        }
+    }
+    new_states
+}
+
+/// Walks the loop in both directions from the start until the two ends meet, returning the
+/// number of steps taken and the distance to every position visited along the way.
+fn walk_loop(map: &Grid<Pipe>, start: Pos) -> (i32, HashMap<Pos, i32>) {
+    let initial_state = State {
+        position: start,
+        last_move: None,
+    };
+    let mut states = vec![initial_state];
+    let mut been = HashMap::new();
+    been.insert(initial_state.position, 0);
+    for i in 1.. {
+        let mut next_states = Vec::new();
+        for state in states {
+            let new_states = move_state(map, state);
+            for new_state in new_states {
+                if let Some(&moves) = been.get(&new_state.position) {
+                    return (moves, been);
+                }
+                been.insert(new_state.position, i);
+                next_states.push(new_state);
+            }
+        }
+        states = next_states;
+    }
+    unreachable!("the two ends of the loop never met")
+}
+
+pub(crate) struct Day10;
+
+impl Solution for Day10 {
+    type Input = (Grid<Pipe>, Pos);
+
+    fn parse(input: &str) -> Self::Input {
+        let chars: Grid<char> = input.parse().unwrap();
+        let start = chars.position(|&c| c == 'S').unwrap();
+        let map = chars.map(|&c| match c {
+            '|' => Pipe {
+                north: true,
+                east: false,
+                south: true,
+                west: false,
+            },
+            '-' => Pipe {
+                north: false,
+                east: true,
+                south: false,
+                west: true,
+            },
+            'L' => Pipe {
+                north: true,
+                east: true,
+                south: false,
+                west: false,
+            },
+            'J' => Pipe {
+                north: true,
+                east: false,
+                south: false,
+                west: true,
+            },
+            '7' => Pipe {
+                north: false,
+                east: false,
+                south: true,
+                west: true,
+            },
+            'F' => Pipe {
+                north: false,
+                east: true,
+                south: true,
+                west: false,
+            },
+            '.' => Pipe::default(),
+            'S' => Pipe {
+                north: true,
+                east: true,
+                south: true,
+                west: true,
+            },
+            _ => panic!("unexpected char: {}", c),
+        });
+        (map, start)
+    }
+
+    fn part_1(&(ref map, start): &Self::Input) -> impl Display {
+        walk_loop(map, start).0
+    }
+
+    fn part_2(&(ref map, start): &Self::Input) -> impl Display {
+        let (_, been) = walk_loop(map, start);
+        let mut state = SearchState::Outside;
+        let mut count: u64 = 0;
+        let connects = |direction: Direction| {
+            map.offset(start, direction)
+                .is_some_and(|pos| map[pos].has_pipe(direction.opposite()))
+        };
+        let start_pipe = Pipe {
+            north: connects(Direction::Up),
+            east: connects(Direction::Right),
+            south: connects(Direction::Down),
+            west: connects(Direction::Left),
+        };
+        let map = {
+            let mut map = map.clone();
+            map[start] = start_pipe;
+            map
+        };
+        for (row, line) in map.rows().enumerate() {
+            for (col, pipe) in line.iter().enumerate() {
+                if been.contains_key(&(col, row)) {
+                    if pipe.east {
+                        if pipe.west {
+                            assert!(
+                                state == SearchState::Entering || state == SearchState::Leaving
+                            );
+                        } else if state == SearchState::Outside {
+                            if pipe.north {
+                                state = SearchState::Leaving;
+                            } else if pipe.south {
+                                state = SearchState::Entering;
+                            } else {
+                                panic!();
+                            }
+                        } else if state == SearchState::Inside {
+                            if pipe.north {
+                                state = SearchState::Entering;
+                            } else if pipe.south {
+                                state = SearchState::Leaving;
+                            } else {
+                                panic!();
+                            }
+                        } else {
+                            panic!();
+                        }
+                    } else {
+                        state = match state {
+                            SearchState::Outside => SearchState::Inside,
+                            SearchState::Entering => {
+                                if pipe.north {
+                                    SearchState::Inside
+                                } else if pipe.south {
+                                    SearchState::Outside
+                                } else {
+                                    panic!();
+                                }
+                            }
+                            SearchState::Leaving => {
+                                if pipe.south {
+                                    SearchState::Inside
+                                } else {
+                                    SearchState::Outside
+                                }
+                            }
+                            SearchState::Inside => SearchState::Outside,
+                        }
+                    }
+                } else {
+                    assert!(state == SearchState::Inside || state == SearchState::Outside);
+                    if state == SearchState::Inside {
+                        count += 1;
+                    }
+                }
+            }
+            assert_eq!(state, SearchState::Outside)
+        }
+        count
+    }
+}
@@ -0,0 +1,79 @@
+use std::fmt::Display;
+
+use aoc_common::Grid;
+
+use crate::util::{Params, Solution};
+
+const EMPTY_SIZE: usize = 1_000_000;
+
+/// The sum of the distances between every pair of galaxies, with each empty row and column
+/// counting as `empty_size` rows or columns.
+fn sum_distances(map: &Grid<bool>, empty_size: usize) -> usize {
+    let empty_rows: Vec<usize> = (0..map.height())
+        .filter(|&row| map.row(row).iter().all(|x| !*x))
+        .collect();
+
+    let empty_cols: Vec<usize> = (0..map.width())
+        .filter(|&col| map.column(col).all(|x| !*x))
+        .collect();
+
+    let galaxies: Vec<(usize, usize)> = map
+        .iter()
+        .filter(|(_, x)| **x)
+        .map(|(pos, _)| pos)
+        .collect();
+
+    let mut sum = 0;
+    for g1 in &galaxies {
+        for g2 in &galaxies {
+            if g1 == g2 {
+                continue;
+            }
+            let min_col = g1.0.min(g2.0);
+            let min_row = g1.1.min(g2.1);
+            let max_col = g1.0.max(g2.0);
+            let max_row = g1.1.max(g2.1);
+
+            let empty_rows_i = empty_rows
+                .iter()
+                .filter(|&&row| row > min_row && row < max_row)
+                .count();
+
+            let empty_cols_i = empty_cols
+                .iter()
+                .filter(|&&col| col > min_col && col < max_col)
+                .count();
+
+            let total = g1.0.abs_diff(g2.0)
+                + g1.1.abs_diff(g2.1)
+                + (empty_rows_i + empty_cols_i) * (empty_size - 1);
+
+            sum += total;
+        }
+    }
+
+    sum / 2
+}
+
+pub(crate) struct Day11;
+
+impl Solution for Day11 {
+    type Input = (Grid<bool>, usize);
+
+    fn parse(input: &str) -> Self::Input {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Self::Input {
+        let map = Grid::parse(input, |c| Some(c == '#')).unwrap();
+        (map, params.get("empty size", EMPTY_SIZE))
+    }
+
+    fn part_1((map, _): &Self::Input) -> impl Display {
+        sum_distances(map, 2)
+    }
+
+    fn part_2(&(ref map, empty_size): &Self::Input) -> impl Display {
+        sum_distances(map, empty_size)
+    }
+}
@@ -1,35 +1,15 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt::Display};
 
+use crate::util::Solution;
 use itertools::Itertools;
-use util::PerfTimer;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-enum Condition {
+pub(crate) enum Condition {
     Operational,
     Damaged,
     Unknown,
 }
 
-fn input() -> Vec<(Vec<Condition>, Vec<usize>)> {
-    util::get_day_input(12)
-        .lines()
-        .map(|line| {
-            let (row, groups) = line.split_once(' ').unwrap();
-            let row: Vec<Condition> = row
-                .chars()
-                .map(|c| match c {
-                    '.' => Condition::Operational,
-                    '#' => Condition::Damaged,
-                    '?' => Condition::Unknown,
-                    _ => panic!(),
-                })
-                .collect();
-            let groups: Vec<usize> = groups.split(',').map(|s| s.parse()).try_collect().unwrap();
-            (row, groups)
-        })
-        .collect()
-}
-
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 struct State {
     group: usize,
@@ -78,20 +58,44 @@ fn count_permutations(row: &[Condition], groups: &[usize]) -> u64 {
         .sum()
 }
 
-fn main() {
-    let records = input();
-    {
-        let _timer = PerfTimer::new("Part 1");
+pub(crate) struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<(Vec<Condition>, Vec<usize>)>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .lines()
+            .map(|line| {
+                let (row, groups) = line.split_once(' ').unwrap();
+                let row: Vec<Condition> = row
+                    .chars()
+                    .map(|c| match c {
+                        '.' => Condition::Operational,
+                        '#' => Condition::Damaged,
+                        '?' => Condition::Unknown,
+                        _ => panic!(),
+                    })
+                    .collect();
+                let groups: Vec<usize> =
+                    groups.split(',').map(|s| s.parse()).try_collect().unwrap();
+                (row, groups)
+            })
+            .collect()
+    }
+
+    fn part_1(records: &Self::Input) -> impl Display {
         let part_1: u64 = records
             .iter()
             .map(|(row, groups)| count_permutations(row, groups))
             .sum();
-        println!("Part 1: {part_1}");
+
+        part_1
     }
-    {
-        let _timer = PerfTimer::new("Part 2");
+
+    fn part_2(records: &Self::Input) -> impl Display {
         let records = records
-            .into_iter()
+            .iter()
             .map(|(row, groups)| {
                 (
                     row.iter()
@@ -114,6 +118,7 @@ fn main() {
             .iter()
             .map(|(row, groups)| count_permutations(row, groups))
             .sum();
-        println!("Part 2: {part_2}");
+
+        part_2
     }
 }
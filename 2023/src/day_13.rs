@@ -0,0 +1,90 @@
+use std::fmt::Display;
+
+use aoc_common::Grid;
+
+use crate::util::Solution;
+use itertools::Itertools;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Symmetry {
+    AfterRow(usize),
+    AfterCol(usize),
+}
+
+/// The rows of `pattern` that the rows after them mirror, up to the edge of the pattern.
+fn rows_of_symmetry(pattern: &Grid<bool>) -> Vec<usize> {
+    let height = pattern.height();
+    (0..height - 1)
+        .filter(|&i| {
+            let distance = (i + 1).min(height - i - 1);
+            (0..distance).all(|j| pattern.row(i - j) == pattern.row(i + j + 1))
+        })
+        .collect()
+}
+
+fn find_symmetry(pattern: &Grid<bool>) -> Vec<Symmetry> {
+    let rows = rows_of_symmetry(pattern)
+        .into_iter()
+        .map(Symmetry::AfterRow);
+    let cols = rows_of_symmetry(&pattern.transpose())
+        .into_iter()
+        .map(Symmetry::AfterCol);
+    rows.chain(cols).collect()
+}
+
+pub(crate) struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Grid<bool>>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .split("\n\n")
+            .map(|pattern| Grid::parse(pattern, |c| Some(c == '#')).unwrap())
+            .collect()
+    }
+
+    fn part_1(patterns: &Self::Input) -> impl Display {
+        let symmetries = patterns.iter().map(|p| find_symmetry(p)[0]).collect_vec();
+        let part_1: usize = symmetries
+            .iter()
+            .map(|s| match s {
+                Symmetry::AfterRow(i) => 100 * (i + 1),
+                Symmetry::AfterCol(i) => i + 1,
+            })
+            .sum();
+
+        part_1
+    }
+
+    fn part_2(patterns: &Self::Input) -> impl Display {
+        let mut patterns = patterns.clone();
+        let symmetries = patterns
+            .iter_mut()
+            .map(|pattern| {
+                let symmetry1 = find_symmetry(pattern)[0];
+                for pos in pattern.positions() {
+                    pattern[pos] = !pattern[pos];
+
+                    for symmetry in find_symmetry(pattern) {
+                        if symmetry != symmetry1 {
+                            return symmetry;
+                        }
+                    }
+
+                    pattern[pos] = !pattern[pos];
+                }
+                panic!();
+            })
+            .collect_vec();
+        let part_2: usize = symmetries
+            .iter()
+            .map(|s| match s {
+                Symmetry::AfterRow(i) => 100 * (i + 1),
+                Symmetry::AfterCol(i) => i + 1,
+            })
+            .sum();
+
+        part_2
+    }
+}
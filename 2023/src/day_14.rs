@@ -0,0 +1,90 @@
+use std::fmt::Display;
+
+use crate::util::Solution;
+use aoc_common::{simulate, Direction, Grid};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum Rock {
+    None,
+    Cube,
+    Rounded,
+}
+
+impl TryFrom<char> for Rock {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, char> {
+        match c {
+            '.' => Ok(Rock::None),
+            '#' => Ok(Rock::Cube),
+            'O' => Ok(Rock::Rounded),
+            c => Err(c),
+        }
+    }
+}
+
+fn tilt(platform: &mut Grid<Rock>, direction: Direction) {
+    // Rounded rocks nearest the edge they're rolling towards have to move first, to make way
+    // for the ones behind them.
+    let mut positions: Vec<_> = platform.positions().collect();
+    if matches!(direction, Direction::Down | Direction::Right) {
+        positions.reverse();
+    }
+
+    for pos in positions {
+        if platform[pos] != Rock::Rounded {
+            continue;
+        }
+        let end = platform
+            .ray(pos, direction)
+            .take_while(|&next| platform[next] == Rock::None)
+            .last();
+        if let Some(end) = end {
+            platform[pos] = Rock::None;
+            platform[end] = Rock::Rounded;
+        }
+    }
+}
+
+fn north_load(platform: &Grid<Rock>) -> usize {
+    platform
+        .iter()
+        .filter(|&(_, &rock)| rock == Rock::Rounded)
+        .map(|((_, y), _)| platform.height() - y)
+        .sum()
+}
+
+pub(crate) struct Day14;
+
+impl Solution for Day14 {
+    type Input = Grid<Rock>;
+
+    fn parse(input: &str) -> Self::Input {
+        input.parse().unwrap()
+    }
+
+    fn part_1(platform: &Self::Input) -> impl Display {
+        let mut platform = platform.clone();
+        tilt(&mut platform, Direction::Up);
+        north_load(&platform)
+    }
+
+    fn part_2(platform: &Self::Input) -> impl Display {
+        let platform = simulate(
+            platform.clone(),
+            1_000_000_000,
+            |platform| {
+                for direction in [
+                    Direction::Up,
+                    Direction::Left,
+                    Direction::Down,
+                    Direction::Right,
+                ] {
+                    tilt(platform, direction);
+                }
+            },
+            Grid::clone,
+        );
+        north_load(&platform)
+    }
+}
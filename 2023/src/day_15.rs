@@ -1,13 +1,7 @@
-use itertools::Itertools;
-use util::PerfTimer;
+use std::fmt::Display;
 
-fn input() -> Vec<String> {
-    util::get_day_input(15)
-        .trim()
-        .split(',')
-        .map(str::to_string)
-        .collect()
-}
+use crate::util::Solution;
+use itertools::Itertools;
 
 fn to_hash(s: &str) -> usize {
     s.chars()
@@ -27,16 +21,22 @@ enum Instruction<'a> {
     Remove { label: &'a str },
 }
 
-fn main() {
-    let steps = input();
-    assert!(steps.iter().all(|s| s.is_ascii()));
-    {
-        let _timer = PerfTimer::new("Part 1");
-        let part_1 = steps.iter().map(|s| to_hash(s)).sum::<usize>();
-        println!("Part 1: {part_1}");
+pub(crate) struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Self::Input {
+        let steps: Vec<String> = input.trim().split(',').map(str::to_string).collect();
+        assert!(steps.iter().all(|s| s.is_ascii()));
+        steps
+    }
+
+    fn part_1(steps: &Self::Input) -> impl Display {
+        steps.iter().map(|s| to_hash(s)).sum::<usize>()
     }
-    {
-        let _timer = PerfTimer::new("Part 2");
+
+    fn part_2(steps: &Self::Input) -> impl Display {
         let instructions = steps.iter().map(|s| {
             if let Some((label, focal_length)) = s.split_once('=') {
                 Instruction::Add {
@@ -76,7 +76,7 @@ fn main() {
                 }
             }
         }
-        let part_2: u64 = boxes
+        boxes
             .iter()
             .enumerate()
             .map(|(box_index, b)| {
@@ -85,7 +85,6 @@ fn main() {
                     .map(|(l, i)| (box_index as u64 + 1) * i * l.focal_length as u64)
                     .sum::<u64>()
             })
-            .sum();
-        println!("Part 2: {part_2}");
+            .sum::<u64>()
     }
 }
@@ -0,0 +1,129 @@
+use std::{collections::HashSet, fmt::Display};
+
+use aoc_common::{Direction, Grid, Pos};
+
+use crate::util::Solution;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Tile {
+    Empty,          // .
+    ForwardMirror,  // /
+    BackwardMirror, // \
+    H2VSplitter,    // |
+    V2HSplitter,    // -
+}
+
+impl TryFrom<char> for Tile {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, char> {
+        match c {
+            '.' => Ok(Tile::Empty),
+            '/' => Ok(Tile::ForwardMirror),
+            '\\' => Ok(Tile::BackwardMirror),
+            '|' => Ok(Tile::H2VSplitter),
+            '-' => Ok(Tile::V2HSplitter),
+            c => Err(c),
+        }
+    }
+}
+
+impl Tile {
+    fn act(&self, direction: Direction) -> Vec<Direction> {
+        match self {
+            Tile::Empty => vec![direction],
+            Tile::ForwardMirror => vec![match direction {
+                Direction::Up => Direction::Right,
+                Direction::Right => Direction::Up,
+                Direction::Down => Direction::Left,
+                Direction::Left => Direction::Down,
+            }],
+            Tile::BackwardMirror => vec![match direction {
+                Direction::Up => Direction::Left,
+                Direction::Right => Direction::Down,
+                Direction::Down => Direction::Right,
+                Direction::Left => Direction::Up,
+            }],
+            Tile::H2VSplitter => match direction {
+                Direction::Up | Direction::Down => vec![direction],
+                Direction::Left | Direction::Right => vec![Direction::Up, Direction::Down],
+            },
+            Tile::V2HSplitter => match direction {
+                Direction::Up | Direction::Down => vec![Direction::Left, Direction::Right],
+                Direction::Left | Direction::Right => vec![direction],
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct State {
+    pos: Pos,
+    direction: Direction,
+}
+
+fn count_energised(grid: &Grid<Tile>, initial_state: State) -> usize {
+    let mut energised = HashSet::new();
+    energised.insert(initial_state.pos);
+    let mut past_states = HashSet::new();
+    let mut states_to_process = vec![initial_state];
+    while let Some(state) = states_to_process.pop() {
+        if !past_states.insert(state) {
+            continue;
+        }
+        energised.insert(state.pos);
+        let new_directions = grid[state.pos].act(state.direction);
+        let new_states = new_directions.into_iter().filter_map(|direction| {
+            let pos = grid.offset(state.pos, direction)?;
+            Some(State { pos, direction })
+        });
+        states_to_process.extend(new_states);
+    }
+    energised.len()
+}
+
+pub(crate) struct Day16;
+
+impl Solution for Day16 {
+    type Input = Grid<Tile>;
+
+    fn parse(input: &str) -> Self::Input {
+        input.parse().unwrap()
+    }
+
+    fn part_1(grid: &Self::Input) -> impl Display {
+        count_energised(
+            grid,
+            State {
+                pos: (0, 0),
+                direction: Direction::Right,
+            },
+        )
+    }
+
+    fn part_2(grid: &Self::Input) -> impl Display {
+        let (width, height) = (grid.width(), grid.height());
+        let initial_states = (0..height)
+            .map(|row| State {
+                pos: (0, row),
+                direction: Direction::Right,
+            })
+            .chain((0..width).map(|col| State {
+                pos: (col, 0),
+                direction: Direction::Down,
+            }))
+            .chain((0..height).map(|row| State {
+                pos: (width - 1, row),
+                direction: Direction::Left,
+            }))
+            .chain((0..width).map(|col| State {
+                pos: (col, height - 1),
+                direction: Direction::Up,
+            }));
+
+        initial_states
+            .map(|s| count_energised(grid, s))
+            .max()
+            .unwrap()
+    }
+}
@@ -0,0 +1,59 @@
+use std::fmt::Display;
+
+use aoc_common::{astar, Direction, Grid, Pos};
+
+use crate::util::Solution;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct State {
+    pos: Pos,
+    direction: Direction,
+}
+
+fn mv(map: &Grid<u32>, min_moves: usize, max_moves: usize, state: State) -> Vec<(State, u32)> {
+    let mut next_states = Vec::new();
+    for direction in [state.direction.turn_left(), state.direction.turn_right()] {
+        let mut cost = 0;
+        for (len, pos) in (1..).zip(map.ray(state.pos, direction).take(max_moves)) {
+            cost += map[pos];
+            if len >= min_moves {
+                next_states.push((State { pos, direction }, cost));
+            }
+        }
+    }
+    next_states
+}
+
+fn min_heat_loss(map: &Grid<u32>, min_moves: usize, max_moves: usize) -> u32 {
+    let (width, height) = (map.width(), map.height());
+    let initial_states = [Direction::Right, Direction::Down].map(|direction| State {
+        pos: (0, 0),
+        direction,
+    });
+    astar(
+        initial_states,
+        |&state| mv(map, min_moves, max_moves, state),
+        |state| ((width - state.pos.0 - 1) + (height - state.pos.1 - 1)) as u32,
+        |state| state.pos == (width - 1, height - 1),
+    )
+    .unwrap()
+    .cost
+}
+
+pub(crate) struct Day17;
+
+impl Solution for Day17 {
+    type Input = Grid<u32>;
+
+    fn parse(input: &str) -> Self::Input {
+        Grid::parse(input, |c| c.to_digit(10)).unwrap()
+    }
+
+    fn part_1(map: &Self::Input) -> impl Display {
+        min_heat_loss(map, 1, 3)
+    }
+
+    fn part_2(map: &Self::Input) -> impl Display {
+        min_heat_loss(map, 4, 10)
+    }
+}
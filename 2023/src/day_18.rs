@@ -0,0 +1,106 @@
+use std::fmt::Display;
+
+use crate::util::Solution;
+use aoc_common::{Direction, Point2};
+use itertools::Itertools;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct InstructionLine {
+    direction: Direction,
+    distance: u64,
+    colour: u32,
+}
+
+fn find_vertices(instructions: &[Instruction]) -> Vec<Point2> {
+    let mut vertexes = Vec::with_capacity(instructions.len());
+    let mut position = Point2::ORIGIN;
+    vertexes.push(position);
+    for instruction in instructions {
+        position += instruction.direction.step() * instruction.distance as i64;
+        vertexes.push(position);
+    }
+    vertexes
+}
+
+fn find_area(vertexes: &[Point2]) -> u64 {
+    let base = vertexes
+        .iter()
+        .copied()
+        .tuple_windows()
+        .map(|(v1, v2)| v1.x * v2.y - v1.y * v2.x)
+        .sum::<i64>()
+        .unsigned_abs()
+        / 2;
+
+    let edge_len = vertexes
+        .iter()
+        .copied()
+        .tuple_windows()
+        .map(|(v1, v2)| v1.manhattan(v2))
+        .sum::<u64>()
+        + 1;
+
+    base + edge_len / 2 + 1
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Instruction {
+    direction: Direction,
+    distance: u64,
+}
+
+pub(crate) struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<InstructionLine>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .lines()
+            .map(|line| {
+                let (direction, distance, colour) = line.split(' ').collect_tuple().unwrap();
+                let direction =
+                    Direction::try_from(direction.chars().exactly_one().unwrap()).unwrap();
+                let distance = distance.parse().unwrap();
+                let colour = u32::from_str_radix(colour.trim_matches(['(', ')', '#']), 16).unwrap();
+                InstructionLine {
+                    direction,
+                    distance,
+                    colour,
+                }
+            })
+            .collect()
+    }
+
+    fn part_1(instructions: &Self::Input) -> impl Display {
+        let instructions = instructions
+            .iter()
+            .map(|instruction| Instruction {
+                direction: instruction.direction,
+                distance: instruction.distance,
+            })
+            .collect_vec();
+        let vertexes = find_vertices(&instructions);
+
+        find_area(&vertexes)
+    }
+
+    fn part_2(instructions: &Self::Input) -> impl Display {
+        let instructions = instructions
+            .iter()
+            .map(|instruction| Instruction {
+                distance: (instruction.colour / 16) as u64,
+                direction: match instruction.colour % 16 {
+                    0 => Direction::Right,
+                    1 => Direction::Down,
+                    2 => Direction::Left,
+                    3 => Direction::Up,
+                    _ => panic!(),
+                },
+            })
+            .collect_vec();
+        let vertexes = find_vertices(&instructions);
+
+        find_area(&vertexes)
+    }
+}
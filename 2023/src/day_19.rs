@@ -1,10 +1,10 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt::Display};
 
+use crate::util::Solution;
 use itertools::Itertools;
-use util::PerfTimer;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-struct Part {
+pub(crate) struct Part {
     x: i64,
     m: i64,
     a: i64,
@@ -26,7 +26,7 @@ enum Operator {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
-struct Workflow {
+pub(crate) struct Workflow {
     rules: Vec<Rule>,
     otherwise: Destination,
 }
@@ -74,75 +74,6 @@ enum Destination {
 
 impl Rule {}
 
-fn input() -> (HashMap<String, Workflow>, Vec<Part>) {
-    let raw = util::get_day_input(19);
-    let mut lines = raw.lines();
-    let workflows: HashMap<String, Workflow> = (&mut lines)
-        .take_while(|s| !s.is_empty())
-        .map(|line| {
-            let (name, rest) = line.split_once('{').unwrap();
-            let rules = rest.strip_suffix('}').unwrap().split(',').collect_vec();
-            let otherwise = match *rules.last().unwrap() {
-                "A" => Destination::Accept,
-                "R" => Destination::Reject,
-                s => Destination::Workflow(s.to_string()),
-            };
-            let rules = rules[0..rules.len() - 1]
-                .iter()
-                .map(|s| {
-                    let var = match &s[0..1] {
-                        "x" => Var::X,
-                        "m" => Var::M,
-                        "a" => Var::A,
-                        "s" => Var::S,
-                        _ => panic!(),
-                    };
-                    let operator = match &s[1..2] {
-                        "<" => Operator::LessThan,
-                        ">" => Operator::GreaterThan,
-                        _ => panic!(),
-                    };
-                    let (value, destination) = s[2..].split_once(':').unwrap();
-                    let destination = match destination {
-                        "A" => Destination::Accept,
-                        "R" => Destination::Reject,
-                        s => Destination::Workflow(s.to_string()),
-                    };
-                    let value: i64 = value.parse().unwrap();
-                    Rule {
-                        var,
-                        operator,
-                        value,
-                        destination,
-                    }
-                })
-                .collect();
-            (name.to_string(), Workflow { rules, otherwise })
-        })
-        .collect();
-
-    let parts = lines
-        .map(|line| {
-            let (x, m, a, s) = line
-                .trim_matches(&['{', '}'])
-                .split(',')
-                .collect_tuple()
-                .unwrap();
-            assert!(x.starts_with("x="));
-            assert!(m.starts_with("m="));
-            assert!(a.starts_with("a="));
-            assert!(s.starts_with("s="));
-            let x = x[2..].parse().unwrap();
-            let m = m[2..].parse().unwrap();
-            let a = a[2..].parse().unwrap();
-            let s = s[2..].parse().unwrap();
-            Part { x, m, a, s }
-        })
-        .collect();
-
-    (workflows, parts)
-}
-
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 struct Range {
     min: i64,
@@ -292,21 +223,92 @@ fn pipeline_splits(
     accepted
 }
 
-fn main() {
-    let (workflows, parts) = input();
-    {
-        let _timer = PerfTimer::new("Part 1");
+pub(crate) struct Day19;
+
+impl Solution for Day19 {
+    type Input = (HashMap<String, Workflow>, Vec<Part>);
+
+    fn parse(input: &str) -> Self::Input {
+        let mut lines = input.lines();
+        let workflows: HashMap<String, Workflow> = (&mut lines)
+            .take_while(|s| !s.is_empty())
+            .map(|line| {
+                let (name, rest) = line.split_once('{').unwrap();
+                let rules = rest.strip_suffix('}').unwrap().split(',').collect_vec();
+                let otherwise = match *rules.last().unwrap() {
+                    "A" => Destination::Accept,
+                    "R" => Destination::Reject,
+                    s => Destination::Workflow(s.to_string()),
+                };
+                let rules = rules[0..rules.len() - 1]
+                    .iter()
+                    .map(|s| {
+                        let var = match &s[0..1] {
+                            "x" => Var::X,
+                            "m" => Var::M,
+                            "a" => Var::A,
+                            "s" => Var::S,
+                            _ => panic!(),
+                        };
+                        let operator = match &s[1..2] {
+                            "<" => Operator::LessThan,
+                            ">" => Operator::GreaterThan,
+                            _ => panic!(),
+                        };
+                        let (value, destination) = s[2..].split_once(':').unwrap();
+                        let destination = match destination {
+                            "A" => Destination::Accept,
+                            "R" => Destination::Reject,
+                            s => Destination::Workflow(s.to_string()),
+                        };
+                        let value: i64 = value.parse().unwrap();
+                        Rule {
+                            var,
+                            operator,
+                            value,
+                            destination,
+                        }
+                    })
+                    .collect();
+                (name.to_string(), Workflow { rules, otherwise })
+            })
+            .collect();
+
+        let parts = lines
+            .map(|line| {
+                let (x, m, a, s) = line
+                    .trim_matches(['{', '}'])
+                    .split(',')
+                    .collect_tuple()
+                    .unwrap();
+                assert!(x.starts_with("x="));
+                assert!(m.starts_with("m="));
+                assert!(a.starts_with("a="));
+                assert!(s.starts_with("s="));
+                let x = x[2..].parse().unwrap();
+                let m = m[2..].parse().unwrap();
+                let a = a[2..].parse().unwrap();
+                let s = s[2..].parse().unwrap();
+                Part { x, m, a, s }
+            })
+            .collect();
+
+        (workflows, parts)
+    }
+
+    fn part_1((workflows, parts): &Self::Input) -> impl Display {
         let part_1: i64 = parts
             .iter()
-            .filter(|&&part| pipeline(&workflows, part))
+            .filter(|&&part| pipeline(workflows, part))
             .map(|&part| part.x + part.m + part.a + part.s)
             .sum();
-        println!("Part 1: {part_1}");
+
+        part_1
     }
-    {
-        let _timer = PerfTimer::new("Part 2");
+
+    fn part_2((workflows, _): &Self::Input) -> impl Display {
         let accepted_part_ranges = pipeline_splits(
-            &workflows,
+            workflows,
             &[PartRange {
                 x: Range { min: 1, max: 4000 },
                 m: Range { min: 1, max: 4000 },
@@ -323,6 +325,7 @@ fn main() {
                     * (part_range.s.max - part_range.s.min + 1)
             })
             .sum();
-        println!("Part 2: {part_2}");
+
+        part_2
     }
 }
@@ -0,0 +1,89 @@
+use std::fmt::Display;
+
+use crate::util::Solution;
+
+#[derive(Default)]
+struct Counts {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+pub(crate) struct Game {
+    id: u32,
+    handfuls: Vec<Counts>,
+}
+
+pub(crate) struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .lines()
+            .map(|line| {
+                assert!(line.starts_with("Game "));
+                let id = line[5..]
+                    .chars()
+                    .take_while(char::is_ascii_digit)
+                    .collect::<String>()
+                    .parse::<u32>()
+                    .unwrap();
+                let rest = line.split_once(": ").unwrap().1;
+                let handfuls = rest
+                    .split("; ")
+                    .map(|s| {
+                        let mut counts = Counts::default();
+                        for part in s.split(", ") {
+                            let (count, colour) = part.split_once(' ').unwrap();
+                            let count = count.parse::<u32>().unwrap();
+                            match colour {
+                                "red" => counts.red += count,
+                                "green" => counts.green += count,
+                                "blue" => counts.blue += count,
+                                _ => panic!(),
+                            };
+                        }
+                        counts
+                    })
+                    .collect();
+                Game { id, handfuls }
+            })
+            .collect()
+    }
+
+    fn part_1(games: &Self::Input) -> impl Display {
+        let part_1: u32 = games
+            .iter()
+            .filter(|game| {
+                game.handfuls
+                    .iter()
+                    .all(|counts| counts.red <= 12 && counts.green <= 13 && counts.blue <= 14)
+            })
+            .map(|game| game.id)
+            .sum();
+
+        part_1
+    }
+
+    fn part_2(games: &Self::Input) -> impl Display {
+        let part_2: u64 = games
+            .iter()
+            .map(|game| {
+                let max = game
+                    .handfuls
+                    .iter()
+                    .fold(Counts::default(), |mut max, counts| {
+                        max.red = max.red.max(counts.red);
+                        max.green = max.green.max(counts.green);
+                        max.blue = max.blue.max(counts.blue);
+                        max
+                    });
+                u64::from(max.red * max.green * max.blue)
+            })
+            .sum();
+
+        part_2
+    }
+}
@@ -1,7 +1,11 @@
-use std::collections::{HashMap, VecDeque};
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Display,
+};
 
+use crate::util::Solution;
+use aoc_common::lcm;
 use itertools::Itertools;
-use util::PerfTimer;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum ModuleType {
@@ -11,40 +15,12 @@ enum ModuleType {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
-struct ModuleInfo {
+pub(crate) struct ModuleInfo {
     name: String,
     typ: ModuleType,
     outputs: Vec<String>,
 }
 
-fn input() -> Vec<ModuleInfo> {
-    util::get_day_input(20)
-        .lines()
-        .map(|line| {
-            let (name_and_type, rest) = line.split_once(" -> ").unwrap();
-            if name_and_type == "broadcaster" {
-                ModuleInfo {
-                    name: String::from("broadcaster"),
-                    typ: ModuleType::Broadcaster,
-                    outputs: rest.split(", ").map(String::from).collect(),
-                }
-            } else {
-                let name = &name_and_type[1..];
-                let typ = match name_and_type.chars().next().unwrap() {
-                    '%' => ModuleType::FlipFlop,
-                    '&' => ModuleType::Conjunction,
-                    _ => panic!(),
-                };
-                ModuleInfo {
-                    name: String::from(name),
-                    typ,
-                    outputs: rest.split(", ").map(String::from).collect(),
-                }
-            }
-        })
-        .collect()
-}
-
 #[derive(Debug)]
 enum ModuleState<'a> {
     Broadcaster,
@@ -92,7 +68,7 @@ impl<'a> Module<'a> {
     }
 }
 
-fn build_modules(module_info: &[ModuleInfo]) -> HashMap<&str, Module> {
+fn build_modules(module_info: &[ModuleInfo]) -> HashMap<&str, Module<'_>> {
     let mut module_inputs: HashMap<&str, Vec<&str>> = HashMap::new();
     for m in module_info {
         for output in &m.outputs {
@@ -200,29 +176,46 @@ fn count_presses_to_high(module_info: &[ModuleInfo], search_for: &str) -> u64 {
     unreachable!();
 }
 
-fn hcf(a: u64, b: u64) -> u64 {
-    if b == 0 {
-        a
-    } else {
-        hcf(b, a % b)
+pub(crate) struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<ModuleInfo>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .lines()
+            .map(|line| {
+                let (name_and_type, rest) = line.split_once(" -> ").unwrap();
+                if name_and_type == "broadcaster" {
+                    ModuleInfo {
+                        name: String::from("broadcaster"),
+                        typ: ModuleType::Broadcaster,
+                        outputs: rest.split(", ").map(String::from).collect(),
+                    }
+                } else {
+                    let name = &name_and_type[1..];
+                    let typ = match name_and_type.chars().next().unwrap() {
+                        '%' => ModuleType::FlipFlop,
+                        '&' => ModuleType::Conjunction,
+                        _ => panic!(),
+                    };
+                    ModuleInfo {
+                        name: String::from(name),
+                        typ,
+                        outputs: rest.split(", ").map(String::from).collect(),
+                    }
+                }
+            })
+            .collect()
     }
-}
 
-fn lcm(a: u64, b: u64) -> u64 {
-    a * b / hcf(a, b)
-}
+    fn part_1(module_info: &Self::Input) -> impl Display {
+        let (high_pulse_count, low_pulse_count) = count_pulses(module_info);
 
-fn main() {
-    let module_info = input();
-    {
-        let _timer = PerfTimer::new("Part 1");
-        let (high_pulse_count, low_pulse_count) = count_pulses(&module_info);
-        let part_1 = high_pulse_count * low_pulse_count;
-        dbg!(low_pulse_count, high_pulse_count);
-        println!("Part 1: {}", part_1);
+        high_pulse_count * low_pulse_count
     }
-    {
-        let _timer = PerfTimer::new("Part 2");
+
+    fn part_2(module_info: &Self::Input) -> impl Display {
         let must_go_low = module_info
             .iter()
             .filter(|m| m.outputs.iter().any(|o| o == "rx"))
@@ -236,9 +229,9 @@ fn main() {
             .collect_vec();
         let goes_high = must_go_high
             .iter()
-            .map(|s| count_presses_to_high(&module_info, s))
+            .map(|s| count_presses_to_high(module_info, s))
             .collect_vec();
-        let part_2 = goes_high.into_iter().reduce(lcm).unwrap();
-        println!("Part 2: {}", part_2);
+
+        goes_high.into_iter().reduce(lcm).unwrap()
     }
 }
@@ -0,0 +1,90 @@
+use std::{collections::HashSet, fmt::Display};
+
+use aoc_common::{Grid, Pos};
+
+use crate::util::{Params, Solution};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Tile {
+    Plot,
+    Rock,
+}
+
+fn search_steps(map: &Grid<Tile>, start: Pos, target_steps: u64) -> Vec<Pos> {
+    let mut evens = HashSet::new();
+    let mut odds = HashSet::new();
+    let mut states = HashSet::from_iter(std::iter::once(start));
+    for step in 0..target_steps {
+        let mut new_states = HashSet::new();
+        let processed = if step % 2 == 0 { &mut evens } else { &mut odds };
+        for pos in states {
+            if !processed.insert(pos) {
+                continue;
+            }
+            for new_pos in map.neighbours4(pos) {
+                if map[new_pos] == Tile::Plot && !processed.contains(&new_pos) {
+                    new_states.insert(new_pos);
+                }
+            }
+        }
+        states = new_states;
+    }
+    let mut processed = if target_steps.is_multiple_of(2) {
+        evens
+    } else {
+        odds
+    };
+    for s in states {
+        processed.insert(s);
+    }
+    processed.into_iter().collect()
+}
+
+pub(crate) struct Day21;
+
+impl Solution for Day21 {
+    type Input = (Grid<Tile>, Pos, u64);
+
+    fn parse(input: &str) -> Self::Input {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Self::Input {
+        let chars: Grid<char> = input.parse().unwrap();
+        let start = chars.position(|&c| c == 'S').unwrap();
+        let map = chars.map(|&c| match c {
+            '.' | 'S' => Tile::Plot,
+            '#' => Tile::Rock,
+            _ => panic!(),
+        });
+        (map, start, params.get("steps", 64))
+    }
+
+    fn part_1(&(ref map, start, steps): &Self::Input) -> impl Display {
+        search_steps(map, start, steps).len()
+    }
+
+    fn part_2(&(ref map, start, _): &Self::Input) -> impl Display {
+        // ((26501365 + 0.5) / 131) * 2 = 404601 (diamonds wide/tall)
+        // Total odd/even exterior diamonds = sum(1 ..= (404601 - 1) / 2) * 2
+        // Total odd/even interior diamonds (minus odd off-balance extras) = (404601 - 1)^2 / 4
+        // Total odd extra off-balance interior diamonds = 404601
+        let total_plots =
+            (search_steps(map, start, 150).len() + search_steps(map, start, 151).len()) as u64;
+        let diamond_interior_odd = search_steps(map, start, 65).len() as u64;
+        let diamond_interior_even = search_steps(map, start, 64).len() as u64;
+        let diamond_interior_total = diamond_interior_odd + diamond_interior_even;
+        let diamond_exterior_total = total_plots - diamond_interior_total;
+        let odd_or_even_exterior_diamond_count = {
+            let s = (404601 - 1) / 2;
+            let sum_s = (s * (s + 1)) / 2;
+            sum_s * 2
+        };
+        let odd_or_even_interior_diamonds = (404601 - 1) * (404601 - 1) / 4;
+        let odd_extra_interior_diamonds = 404601;
+
+        odd_or_even_exterior_diamond_count * diamond_exterior_total
+            + odd_or_even_interior_diamonds * diamond_interior_total
+            + odd_extra_interior_diamonds * diamond_interior_odd
+    }
+}
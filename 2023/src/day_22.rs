@@ -1,28 +1,14 @@
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
 
+use crate::util::Solution;
 use itertools::Itertools;
-use util::PerfTimer;
 
 type Point = (i64, i64, i64);
 type Brick = (Point, Point);
 
-fn input() -> Vec<Brick> {
-    util::get_day_input(22)
-        .lines()
-        .map(|line| {
-            line.split('~')
-                .map(|p| {
-                    p.split(',')
-                        .map(|s| s.parse::<i64>().unwrap())
-                        .collect_tuple()
-                        .unwrap()
-                })
-                .collect_tuple()
-                .unwrap()
-        })
-        .collect()
-}
-
 fn settle(bricks: &[Brick]) -> Vec<Brick> {
     let mut settled_cubes: HashSet<Point> = HashSet::new();
     let mut settled_bricks: Vec<(Point, Point)> = Vec::new();
@@ -80,11 +66,30 @@ fn build_resting_map(bricks: &[Brick]) -> HashMap<Brick, Vec<Brick>> {
     resting_map
 }
 
-fn main() {
-    let bricks = input();
-    {
-        let _timer = PerfTimer::new("Part 1");
-        let settled_bricks = settle(&bricks);
+pub(crate) struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<Brick>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .lines()
+            .map(|line| {
+                line.split('~')
+                    .map(|p| {
+                        p.split(',')
+                            .map(|s| s.parse::<i64>().unwrap())
+                            .collect_tuple()
+                            .unwrap()
+                    })
+                    .collect_tuple()
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    fn part_1(bricks: &Self::Input) -> impl Display {
+        let settled_bricks = settle(bricks);
         let resting_map = build_resting_map(&settled_bricks);
         let cannot_disintegrate = resting_map
             .values()
@@ -93,13 +98,11 @@ fn main() {
             .unique()
             .count();
 
-        let part_1 = bricks.len() - cannot_disintegrate;
-
-        println!("Part 1: {}", part_1);
+        bricks.len() - cannot_disintegrate
     }
-    {
-        let _timer = PerfTimer::new("Part 2");
-        let settled_bricks = settle(&bricks);
+
+    fn part_2(bricks: &Self::Input) -> impl Display {
+        let settled_bricks = settle(bricks);
         let resting_map = build_resting_map(&settled_bricks);
 
         let part_2: u64 = settled_bricks
@@ -121,6 +124,6 @@ fn main() {
             })
             .sum();
 
-        println!("Part 2: {part_2}");
+        part_2
     }
 }
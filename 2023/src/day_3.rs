@@ -0,0 +1,98 @@
+use std::{collections::HashMap, fmt::Display};
+
+use aoc_common::{Grid, Pos};
+
+use crate::util::Solution;
+
+fn is_symbol(c: char) -> bool {
+    c != '.' && !c.is_ascii_digit()
+}
+
+fn symbol_is_adjacent(schematic: &Grid<char>, pos: Pos) -> bool {
+    schematic
+        .neighbours8(pos)
+        .any(|neighbour| is_symbol(schematic[neighbour]))
+}
+
+fn find_adjacent_star(schematic: &Grid<char>, pos: Pos) -> Option<Pos> {
+    schematic
+        .neighbours8(pos)
+        .find(|&neighbour| schematic[neighbour] == '*')
+}
+
+pub(crate) struct Day3;
+
+impl Solution for Day3 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Self::Input {
+        input.parse().unwrap()
+    }
+
+    fn part_1(schematic: &Self::Input) -> impl Display {
+        let mut sum = 0;
+        let mut num = 0;
+        let mut adjacent = false;
+
+        for (row, line) in schematic.rows().enumerate() {
+            for (col, c) in line.iter().enumerate() {
+                if let Some(digit) = c.to_digit(10) {
+                    num = num * 10 + digit;
+                    if symbol_is_adjacent(schematic, (col, row)) {
+                        adjacent = true;
+                    }
+                } else {
+                    if adjacent {
+                        sum += num;
+                    }
+                    num = 0;
+                    adjacent = false;
+                }
+            }
+            if adjacent {
+                sum += num;
+            }
+            num = 0;
+            adjacent = false;
+        }
+        sum
+    }
+
+    fn part_2(schematic: &Self::Input) -> impl Display {
+        let mut sum = 0;
+        let mut num = 0;
+        let mut adjacents: HashMap<Pos, u32> = HashMap::new();
+        let mut adjacent: Option<Pos> = None;
+
+        for (row, line) in schematic.rows().enumerate() {
+            for (col, c) in line.iter().enumerate() {
+                if let Some(digit) = c.to_digit(10) {
+                    num = num * 10 + digit;
+                    if let Some(pos) = find_adjacent_star(schematic, (col, row)) {
+                        adjacent = Some(pos);
+                    }
+                } else {
+                    if let Some(pos) = adjacent {
+                        if let Some(other_num) = adjacents.remove(&pos) {
+                            sum += num * other_num;
+                        } else {
+                            adjacents.insert(pos, num);
+                        }
+                    }
+                    num = 0;
+                    adjacent = None;
+                }
+            }
+            if let Some(pos) = adjacent {
+                if let Some(other_num) = adjacents.remove(&pos) {
+                    sum += num * other_num;
+                } else {
+                    adjacents.insert(pos, num);
+                }
+            }
+            num = 0;
+            adjacent = None;
+        }
+        sum
+    }
+}
@@ -0,0 +1,82 @@
+use std::fmt::Display;
+
+use crate::util::Solution;
+
+pub(crate) struct Card {
+    id: usize,
+    winning_numbers: Vec<u32>,
+    numbers: Vec<u32>,
+}
+
+pub(crate) struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Card>;
+
+    fn parse(input: &str) -> Self::Input {
+        let cards = input
+            .lines()
+            .map(|line| {
+                let (id, numbers) = line.split_once(':').unwrap();
+                let id: usize = id[5..].trim().parse().unwrap();
+                let (winning_numbers, numbers) = numbers.split_once(" | ").unwrap();
+                let winning_numbers = winning_numbers
+                    .split_whitespace()
+                    .map(str::parse)
+                    .collect::<Result<Vec<u32>, _>>()
+                    .unwrap();
+                let numbers = numbers
+                    .split_whitespace()
+                    .map(str::parse)
+                    .collect::<Result<Vec<u32>, _>>()
+                    .unwrap();
+
+                Card {
+                    id,
+                    winning_numbers,
+                    numbers,
+                }
+            })
+            .collect();
+        cards
+    }
+
+    fn part_1(cards: &Self::Input) -> impl Display {
+        let part_1: u32 = cards
+            .iter()
+            .map(|card| {
+                let mut score = 0;
+                for number in &card.numbers {
+                    if card.winning_numbers.contains(number) {
+                        if score == 0 {
+                            score = 1;
+                        } else {
+                            score *= 2;
+                        }
+                    }
+                }
+                score
+            })
+            .sum();
+
+        part_1
+    }
+
+    fn part_2(cards: &Self::Input) -> impl Display {
+        let mut card_counts = vec![1; cards.len()];
+        for card in cards {
+            let matches = card
+                .numbers
+                .iter()
+                .filter(|number| card.winning_numbers.contains(number))
+                .count();
+            let card_count = card_counts[card.id - 1];
+            for count in card_counts[card.id..card.id + matches].iter_mut() {
+                *count += card_count;
+            }
+        }
+        let part_2: u32 = card_counts.iter().sum();
+
+        part_2
+    }
+}
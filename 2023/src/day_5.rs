@@ -1,7 +1,9 @@
-use util::PerfTimer;
+use std::fmt::Display;
+
+use crate::util::Solution;
 
 #[allow(dead_code)]
-struct Map {
+pub(crate) struct Map {
     source_name: String,
     dest_name: String,
     ranges: Vec<MapRange>,
@@ -51,58 +53,59 @@ struct MapRange {
     length: u64,
 }
 
-fn input() -> (Vec<u64>, Vec<Map>) {
-    let raw = util::get_day_input(5);
-    let mut lines = raw.lines();
-    let seed_line = lines.next().unwrap();
-    let seeds = seed_line
-        .strip_prefix("seeds: ")
-        .unwrap()
-        .split_whitespace()
-        .map(str::parse)
-        .collect::<Result<Vec<u64>, _>>()
-        .unwrap();
-    assert!(lines.next().unwrap().is_empty());
-    let mut maps = Vec::new();
-    while let Some(title_line) = lines.next() {
-        let split: Vec<&str> = title_line
-            .strip_suffix(" map:")
+pub(crate) struct Day5;
+
+impl Solution for Day5 {
+    type Input = (Vec<u64>, Vec<Map>);
+
+    fn parse(input: &str) -> Self::Input {
+        let mut lines = input.lines();
+        let seed_line = lines.next().unwrap();
+        let seeds = seed_line
+            .strip_prefix("seeds: ")
             .unwrap()
-            .split('-')
-            .collect();
-        assert_eq!(split.len(), 3);
-        assert_eq!(split[1], "to");
-        let source_name = String::from(split[0]);
-        let dest_name = String::from(split[2]);
-        let mut ranges = Vec::new();
-        for range_line in &mut lines {
-            if range_line.is_empty() {
-                break;
-            }
-            let split: Vec<&str> = range_line.split_whitespace().collect();
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<Vec<u64>, _>>()
+            .unwrap();
+        assert!(lines.next().unwrap().is_empty());
+        let mut maps = Vec::new();
+        while let Some(title_line) = lines.next() {
+            let split: Vec<&str> = title_line
+                .strip_suffix(" map:")
+                .unwrap()
+                .split('-')
+                .collect();
             assert_eq!(split.len(), 3);
-            let dest_start = split[0].parse().unwrap();
-            let source_start = split[1].parse().unwrap();
-            let length = split[2].parse().unwrap();
-            ranges.push(MapRange {
-                source_start,
-                dest_start,
-                length,
+            assert_eq!(split[1], "to");
+            let source_name = String::from(split[0]);
+            let dest_name = String::from(split[2]);
+            let mut ranges = Vec::new();
+            for range_line in &mut lines {
+                if range_line.is_empty() {
+                    break;
+                }
+                let split: Vec<&str> = range_line.split_whitespace().collect();
+                assert_eq!(split.len(), 3);
+                let dest_start = split[0].parse().unwrap();
+                let source_start = split[1].parse().unwrap();
+                let length = split[2].parse().unwrap();
+                ranges.push(MapRange {
+                    source_start,
+                    dest_start,
+                    length,
+                })
+            }
+            maps.push(Map {
+                source_name,
+                dest_name,
+                ranges,
             })
         }
-        maps.push(Map {
-            source_name,
-            dest_name,
-            ranges,
-        })
+        (seeds, maps)
     }
-    (seeds, maps)
-}
 
-fn main() {
-    let (seeds, maps) = input();
-    {
-        let _timer = PerfTimer::new("Part 1");
+    fn part_1((seeds, maps): &Self::Input) -> impl Display {
         let locations = maps.iter().fold(seeds.clone(), |mut source_nums, map| {
             for source_num in &mut source_nums {
                 *source_num = map.map(*source_num);
@@ -110,10 +113,11 @@ fn main() {
             source_nums
         });
         let part_1 = *locations.iter().min().unwrap();
-        println!("Part 1: {part_1}");
+
+        part_1
     }
-    {
-        let _timer = PerfTimer::new("Part 2");
+
+    fn part_2((seeds, maps): &Self::Input) -> impl Display {
         let seed_ranges: Vec<(u64, u64)> = seeds
             .chunks_exact(2)
             .map(|chunks| (chunks[0], chunks[1]))
@@ -125,11 +129,11 @@ fn main() {
                 .flat_map(|(start, length)| map.map_range(start, length))
                 .collect()
         });
-        let part_2 = location_ranges
+
+        location_ranges
             .into_iter()
             .map(|(start, _)| start)
             .min()
-            .unwrap();
-        println!("Part 2: {part_2}");
+            .unwrap()
     }
 }
@@ -1,11 +1,10 @@
-#![feature(float_next_up_down)]
+use std::fmt::Display;
 
+use crate::util::Solution;
 use itertools::Itertools;
-use util::PerfTimer;
 
-fn input_part_1() -> Vec<(u64, u64)> {
-    let raw = util::get_day_input(6);
-    let lines = raw.lines().collect_vec();
+fn input_part_1(input: &str) -> Vec<(u64, u64)> {
+    let lines = input.lines().collect_vec();
     assert_eq!(lines.len(), 2);
     let times: Vec<u64> = lines[0]
         .strip_prefix("Time:")
@@ -25,9 +24,8 @@ fn input_part_1() -> Vec<(u64, u64)> {
     times.into_iter().zip(distances).collect()
 }
 
-fn input_part_2() -> (u64, u64) {
-    let raw = util::get_day_input(6);
-    let lines = raw.lines().collect_vec();
+fn input_part_2(input: &str) -> (u64, u64) {
+    let lines = input.lines().collect_vec();
     assert_eq!(lines.len(), 2);
     let time = lines[0]
         .strip_prefix("Time:")
@@ -49,27 +47,29 @@ fn simulate(time: u64, hold_button: u64) -> u64 {
     time * hold_button
 }
 
-fn main() {
-    {
-        let _timer = PerfTimer::new("Part 1");
-        let races = input_part_1();
-        let part_1 = races
-            .into_iter()
-            .map(|(time, record)| {
+pub(crate) struct Day6;
+
+impl Solution for Day6 {
+    type Input = (Vec<(u64, u64)>, (u64, u64));
+
+    fn parse(input: &str) -> Self::Input {
+        (input_part_1(input), input_part_2(input))
+    }
+
+    fn part_1((races, _): &Self::Input) -> impl Display {
+        races
+            .iter()
+            .map(|&(time, record)| {
                 (0..=time)
                     .map(|x| simulate(time, x))
                     .filter(|&d| d > record)
                     .count()
             })
             .reduce(std::ops::Mul::mul)
-            .unwrap();
-        println!("Part 1: {part_1}");
+            .unwrap()
     }
 
-    {
-        let _timer = PerfTimer::new("Part 2");
-        let (time, record) = input_part_2();
-
+    fn part_2(&(_, (time, record)): &Self::Input) -> impl Display {
         // r = (t-h)h = -h^2 + th => -h^2 + th - r = 0
         // => h = (-t ± sqrt(t^2 - 4r)) / -2
         let t = time as f64;
@@ -78,7 +78,6 @@ fn main() {
         let s2 = (-t - (t * t - 4. * r).sqrt()) / -2.;
         let s_min = s1.min(s2).next_up().ceil() as u64;
         let s_max = s1.max(s2).next_down().floor() as u64;
-        let part_2 = s_max - s_min + 1;
-        println!("Part 2: {part_2}");
+        s_max - s_min + 1
     }
 }
@@ -1,8 +1,10 @@
+use std::fmt::Display;
+
+use crate::util::Solution;
 use itertools::Itertools;
-use util::PerfTimer;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-enum Card {
+pub(crate) enum Card {
     Wild,
     N2,
     N3,
@@ -30,36 +32,6 @@ enum HandClass {
     FiveOfAKind,
 }
 
-fn input() -> Vec<(Vec<Card>, u64)> {
-    util::get_day_input(7)
-        .lines()
-        .map(|line| {
-            let (hand, bid) = line.split_once(' ').unwrap();
-            let hand: Vec<Card> = hand
-                .chars()
-                .map(|c| match c {
-                    '2' => Card::N2,
-                    '3' => Card::N3,
-                    '4' => Card::N4,
-                    '5' => Card::N5,
-                    '6' => Card::N6,
-                    '7' => Card::N7,
-                    '8' => Card::N8,
-                    '9' => Card::N9,
-                    'T' => Card::N10,
-                    'J' => Card::Jack,
-                    'Q' => Card::Queen,
-                    'K' => Card::King,
-                    'A' => Card::Ace,
-                    _ => panic!(),
-                })
-                .collect();
-            let bid: u64 = bid.parse().unwrap();
-            (hand, bid)
-        })
-        .collect()
-}
-
 fn classify_hand(hand: &[Card]) -> HandClass {
     assert_eq!(hand.len(), 5);
 
@@ -98,11 +70,43 @@ fn classify_hand(hand: &[Card]) -> HandClass {
     }
 }
 
-fn main() {
-    let games = input();
-    {
-        let _timer = PerfTimer::new("Part 1");
-        let part_1 = games
+pub(crate) struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<(Vec<Card>, u64)>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .lines()
+            .map(|line| {
+                let (hand, bid) = line.split_once(' ').unwrap();
+                let hand: Vec<Card> = hand
+                    .chars()
+                    .map(|c| match c {
+                        '2' => Card::N2,
+                        '3' => Card::N3,
+                        '4' => Card::N4,
+                        '5' => Card::N5,
+                        '6' => Card::N6,
+                        '7' => Card::N7,
+                        '8' => Card::N8,
+                        '9' => Card::N9,
+                        'T' => Card::N10,
+                        'J' => Card::Jack,
+                        'Q' => Card::Queen,
+                        'K' => Card::King,
+                        'A' => Card::Ace,
+                        _ => panic!(),
+                    })
+                    .collect();
+                let bid: u64 = bid.parse().unwrap();
+                (hand, bid)
+            })
+            .collect()
+    }
+
+    fn part_1(games: &Self::Input) -> impl Display {
+        games
             .iter()
             .map(|(hand, bid)| {
                 let class = classify_hand(hand);
@@ -112,15 +116,14 @@ fn main() {
             .sorted()
             .zip(1..)
             .map(|((_, bid), i)| bid * i)
-            .sum::<u64>();
-
-        println!("Part 1: {part_1}");
+            .sum::<u64>()
     }
-    {
-        let _timer = PerfTimer::new("Part 2");
-        let part_2 = games
-            .into_iter()
-            .map(|(mut hand, bid)| {
+
+    fn part_2(games: &Self::Input) -> impl Display {
+        games
+            .iter()
+            .map(|(hand, bid)| {
+                let mut hand = hand.clone();
                 for card in &mut hand {
                     if *card == Card::Jack {
                         *card = Card::Wild;
@@ -133,8 +136,6 @@ fn main() {
             .sorted()
             .zip(1..)
             .map(|((_, bid), i)| bid * i)
-            .sum::<u64>();
-
-        println!("Part 2: {part_2}");
+            .sum::<u64>()
     }
 }
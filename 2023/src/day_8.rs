@@ -1,59 +1,52 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt::Display};
 
+use aoc_common::lcm;
 use itertools::Itertools;
 use regex::Regex;
 
-enum Instruction {
+use crate::util::Solution;
+
+pub(crate) enum Instruction {
     Left,
     Right,
 }
 
-fn input() -> (Vec<Instruction>, HashMap<String, (String, String)>) {
-    let raw = util::get_day_input(8);
-    let mut lines = raw.lines();
-    let instructions = lines
-        .next()
-        .unwrap()
-        .chars()
-        .map(|c| match c {
-            'L' => Instruction::Left,
-            'R' => Instruction::Right,
-            _ => panic!(),
-        })
-        .collect();
-    assert_eq!(lines.next().unwrap(), "");
-    let node_regex =
-        Regex::new(r"(?P<name>[[:alnum:]]+) = \((?P<left>[[:alnum:]]+), (?P<right>[[:alnum:]]+)\)")
-            .unwrap();
-    let nodes = lines
-        .map(|line| {
-            let captures = node_regex.captures(line).unwrap();
-            let name = String::from(&captures["name"]);
-            let left = String::from(&captures["left"]);
-            let right = String::from(&captures["right"]);
-            (name, (left, right))
-        })
-        .collect();
+pub(crate) struct Day8;
 
-    (instructions, nodes)
-}
+impl Solution for Day8 {
+    type Input = (Vec<Instruction>, HashMap<String, (String, String)>);
 
-fn hcf(a: u64, b: u64) -> u64 {
-    if b == 0 {
-        a
-    } else {
-        hcf(b, a % b)
-    }
-}
+    fn parse(input: &str) -> Self::Input {
+        let mut lines = input.lines();
+        let instructions = lines
+            .next()
+            .unwrap()
+            .chars()
+            .map(|c| match c {
+                'L' => Instruction::Left,
+                'R' => Instruction::Right,
+                _ => panic!(),
+            })
+            .collect();
+        assert_eq!(lines.next().unwrap(), "");
+        let node_regex = Regex::new(
+            r"(?P<name>[[:alnum:]]+) = \((?P<left>[[:alnum:]]+), (?P<right>[[:alnum:]]+)\)",
+        )
+        .unwrap();
+        let nodes = lines
+            .map(|line| {
+                let captures = node_regex.captures(line).unwrap();
+                let name = String::from(&captures["name"]);
+                let left = String::from(&captures["left"]);
+                let right = String::from(&captures["right"]);
+                (name, (left, right))
+            })
+            .collect();
 
-fn lcm(a: u64, b: u64) -> u64 {
-    a * b / hcf(a, b)
-}
+        (instructions, nodes)
+    }
 
-fn main() {
-    let (instructions, nodes) = input();
-    {
-        let _timer = util::PerfTimer::new("Part 1");
+    fn part_1((instructions, nodes): &Self::Input) -> impl Display {
         let mut current = "AAA";
         let mut steps: u64 = 0;
         for instruction in instructions.iter().cycle() {
@@ -67,11 +60,11 @@ fn main() {
             };
             steps += 1;
         }
-        println!("Part 1: {steps}");
+
+        steps
     }
 
-    {
-        let _timer = util::PerfTimer::new("Part 2");
+    fn part_2((instructions, nodes): &Self::Input) -> impl Display {
         let mut current = nodes.keys().filter(|n| n.ends_with('A')).collect_vec();
         let mut steps: Vec<Option<u64>> = vec![None; current.len()];
         let mut target_nodes: Vec<Option<&str>> = vec![None; current.len()];
@@ -110,7 +103,7 @@ fn main() {
             }
         }
         let completions = steps.into_iter().collect::<Option<Vec<_>>>().unwrap();
-        let part_2 = completions.into_iter().reduce(lcm).unwrap();
-        println!("Part 2: {part_2}");
+
+        completions.into_iter().reduce(lcm).unwrap()
     }
 }
@@ -0,0 +1,51 @@
+use std::fmt::Display;
+
+use itertools::Itertools;
+
+use crate::util::Solution;
+
+/// Extrapolates the sequence one step in each direction, returning the new first and last values.
+fn extrapolate(data: &[i64]) -> (i64, i64) {
+    let mut order_diffs = Vec::new();
+    loop {
+        let current_diff = order_diffs.last().map_or(data, Vec::as_slice);
+        let new_diff: Vec<i64> = current_diff
+            .iter()
+            .tuple_windows()
+            .map(|(a, b)| b - a)
+            .collect();
+        if new_diff.iter().all(|i| *i == 0) {
+            break;
+        }
+        order_diffs.push(new_diff);
+    }
+
+    let diff_end: i64 = order_diffs.iter().rev().map(|d| d.last().unwrap()).sum();
+    let new_last = *data.last().unwrap() + diff_end;
+
+    let diff_start: i64 = order_diffs.iter().rev().fold(0, |s, d| d[0] - s);
+    let new_first = data[0] - diff_start;
+    (new_first, new_last)
+}
+
+pub(crate) struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .lines()
+            .map(|line| line.split_whitespace().map(str::parse).try_collect())
+            .try_collect()
+            .unwrap()
+    }
+
+    fn part_1(report: &Self::Input) -> impl Display {
+        report.iter().map(|data| extrapolate(data).1).sum::<i64>()
+    }
+
+    fn part_2(report: &Self::Input) -> impl Display {
+        report.iter().map(|data| extrapolate(data).0).sum::<i64>()
+    }
+}
@@ -1,40 +1,36 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt::Display};
 
-use util::PerfTimer;
+use crate::util::Solution;
 
-extern crate util;
+pub(crate) struct Day1;
 
-fn input() -> Vec<(u64, u64)> {
-    let input = util::get_day_input(1);
-    input
-        .lines()
-        .map(|line| {
-            let (l, r) = line.split_once("   ").unwrap();
-            (l.parse().unwrap(), r.parse().unwrap())
-        })
-        .collect()
-}
+impl Solution for Day1 {
+    type Input = Vec<(u64, u64)>;
 
-fn main() {
-    let input = input();
+    fn parse(input: &str) -> Self::Input {
+        input
+            .lines()
+            .map(|line| {
+                let (l, r) = line.split_once("   ").unwrap();
+                (l.parse().unwrap(), r.parse().unwrap())
+            })
+            .collect()
+    }
 
-    {
-        let _timer = PerfTimer::new("Part 1");
+    fn part_1(input: &Self::Input) -> impl Display {
         let mut list_a: Vec<u64> = input.iter().map(|(a, _)| *a).collect();
         let mut list_b: Vec<u64> = input.iter().map(|(_, b)| *b).collect();
         list_a.sort_unstable();
         list_b.sort_unstable();
 
-        let part_1: u64 = list_a
+        list_a
             .iter()
             .zip(list_b.iter())
             .map(|(a, b)| a.abs_diff(*b))
-            .sum();
-        println!("Part 1: {part_1}");
+            .sum::<u64>()
     }
 
-    {
-        let _timer = PerfTimer::new("Part 2");
+    fn part_2(input: &Self::Input) -> impl Display {
         let list_a: Vec<u64> = input.iter().map(|(a, _)| *a).collect();
         let list_b: Vec<u64> = input.iter().map(|(_, b)| *b).collect();
 
@@ -43,10 +39,9 @@ fn main() {
             *b_counts.entry(x).or_insert(0) += 1;
         }
 
-        let part_2: u64 = list_a
+        list_a
             .iter()
             .map(|&a| a * b_counts.get(&a).unwrap_or(&0))
-            .sum();
-        println!("Part 2: {part_2}");
+            .sum::<u64>()
     }
 }
@@ -0,0 +1,61 @@
+use std::{collections::HashSet, fmt::Display};
+
+use aoc_common::{Grid, Pos};
+
+use crate::util::Solution;
+
+/// The positions one step higher than `pos`.
+fn uphill(map: &Grid<u32>, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+    map.neighbours4(pos)
+        .filter(move |&next| map[next] == map[pos] + 1)
+}
+
+fn count_reachable_peaks(map: &Grid<u32>, trailhead: Pos) -> usize {
+    fn inner(map: &Grid<u32>, pos: Pos, visited: &mut HashSet<Pos>) -> usize {
+        if !visited.insert(pos) {
+            return 0;
+        }
+        if map[pos] == 9 {
+            return 1;
+        }
+        uphill(map, pos).map(|next| inner(map, next, visited)).sum()
+    }
+
+    let mut visited = HashSet::new();
+    inner(map, trailhead, &mut visited)
+}
+
+fn count_trails(map: &Grid<u32>, pos: Pos) -> usize {
+    if map[pos] == 9 {
+        return 1;
+    }
+    uphill(map, pos).map(|next| count_trails(map, next)).sum()
+}
+
+fn trailheads(map: &Grid<u32>) -> impl Iterator<Item = Pos> + '_ {
+    map.iter()
+        .filter(|&(_, &height)| height == 0)
+        .map(|(pos, _)| pos)
+}
+
+pub(crate) struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<u32>;
+
+    fn parse(input: &str) -> Self::Input {
+        Grid::parse(input, |c| c.to_digit(10)).unwrap()
+    }
+
+    fn part_1(map: &Self::Input) -> impl Display {
+        trailheads(map)
+            .map(|trailhead| count_reachable_peaks(map, trailhead))
+            .sum::<usize>()
+    }
+
+    fn part_2(map: &Self::Input) -> impl Display {
+        trailheads(map)
+            .map(|trailhead| count_trails(map, trailhead))
+            .sum::<usize>()
+    }
+}
@@ -1,14 +1,8 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt::Display};
 
 use itertools::Itertools;
 
-fn input() -> Vec<u64> {
-    util::get_day_input(11)
-        .trim()
-        .split(' ')
-        .map(|n| n.parse().unwrap())
-        .collect()
-}
+use crate::util::Solution;
 
 fn count_digits(x: u64) -> u32 {
     let mut count: u32 = 1;
@@ -20,7 +14,7 @@ fn count_digits(x: u64) -> u32 {
 
 fn split_stone(stone: u64) -> Option<(u64, u64)> {
     let digit_count = count_digits(stone);
-    if digit_count % 2 == 0 {
+    if digit_count.is_multiple_of(2) {
         let left = stone / 10u64.pow(digit_count / 2);
         let right = stone % 10u64.pow(digit_count / 2);
         Some((left, right))
@@ -82,26 +76,31 @@ fn count_stones_after_blinks_dedup(stones: &[u64], blinks: usize) -> usize {
     stones.values().sum()
 }
 
-fn main() {
-    let initial_stones = input();
-    {
-        let _timer = util::PerfTimer::new("Part 1");
+pub(crate) struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<u64>;
 
+    fn parse(input: &str) -> Self::Input {
+        input
+            .trim()
+            .split(' ')
+            .map(|n| n.parse().unwrap())
+            .collect()
+    }
+
+    fn part_1(initial_stones: &Self::Input) -> impl Display {
         // // Initial part 1 implementation
         // let part_1: usize = initial_stones
         //     .iter()
         //     .map(|&s| count_stones_after_blinks(s, 25))
         //     .sum();
-        // println!("Part 1: {part_1}");
+        // answers.part_1(part_1);
 
-        let part_1 = count_stones_after_blinks_dedup(&initial_stones, 25);
-        println!("Part 1: {part_1}");
+        count_stones_after_blinks_dedup(initial_stones, 25)
     }
 
-    {
-        let _timer = util::PerfTimer::new("Part 2");
-
-        let part_2 = count_stones_after_blinks_dedup(&initial_stones, 75);
-        println!("Part 2: {part_2}");
+    fn part_2(initial_stones: &Self::Input) -> impl Display {
+        count_stones_after_blinks_dedup(initial_stones, 75)
     }
 }
@@ -0,0 +1,147 @@
+use std::{collections::HashMap, fmt::Display};
+
+use aoc_common::{Direction, Grid, Pos};
+
+use crate::util::Solution;
+
+/// Whether the plot next to `pos` in `direction` is in the map and has the same plant.
+fn same_plant(map: &Grid<char>, pos: Pos, direction: Direction) -> bool {
+    map.offset(pos, direction)
+        .is_some_and(|neighbour| map[neighbour] == map[pos])
+}
+
+fn build_regions_and_plots(map: &Grid<char>) -> (HashMap<Pos, Vec<Pos>>, HashMap<Pos, Pos>) {
+    let mut regions: HashMap<Pos, Vec<Pos>> = HashMap::new();
+    let mut plots_to_region: HashMap<Pos, Pos> = HashMap::new();
+
+    for pos @ (x, y) in map.positions() {
+        if same_plant(map, pos, Direction::Up) {
+            let region = plots_to_region[&(x, y - 1)];
+            plots_to_region.insert(pos, region);
+            regions.get_mut(&region).unwrap().push(pos);
+
+            if same_plant(map, pos, Direction::Left) {
+                let left_region = plots_to_region[&(x - 1, y)];
+                if left_region != region {
+                    let left_region = regions.remove(&left_region).unwrap();
+                    for &pos in &left_region {
+                        plots_to_region.insert(pos, region);
+                    }
+                    regions.get_mut(&region).unwrap().extend(left_region);
+                }
+            }
+        } else if same_plant(map, pos, Direction::Left) {
+            let region = plots_to_region[&(x - 1, y)];
+            plots_to_region.insert(pos, region);
+            regions.get_mut(&region).unwrap().push(pos);
+        } else {
+            let region = pos;
+            plots_to_region.insert(pos, region);
+            regions.insert(region, vec![pos]);
+        }
+    }
+
+    (regions, plots_to_region)
+}
+
+/// Counts the sides of each region along `lines` of the map, where `first` and `second` are
+/// the directions across each line. Each run of plots along a line with a fence on the same
+/// side is one side.
+fn count_sides(
+    map: &Grid<char>,
+    plots_to_region: &HashMap<Pos, Pos>,
+    lines: impl Iterator<Item = Vec<Pos>>,
+    [first, second]: [Direction; 2],
+    regions_to_sides: &mut HashMap<Pos, usize>,
+) {
+    for line in lines {
+        let mut was_in_run_first = false;
+        let mut was_in_run_second = false;
+        let mut prev: Option<char> = None;
+        for pos in line {
+            let region = plots_to_region[&pos];
+            let plot = map[pos];
+            if prev != Some(plot) {
+                was_in_run_first = false;
+                was_in_run_second = false;
+            }
+
+            if same_plant(map, pos, first) {
+                was_in_run_first = false;
+            } else {
+                if !was_in_run_first {
+                    *regions_to_sides.entry(region).or_default() += 1;
+                }
+                was_in_run_first = true;
+            }
+
+            if same_plant(map, pos, second) {
+                was_in_run_second = false;
+            } else {
+                if !was_in_run_second {
+                    *regions_to_sides.entry(region).or_default() += 1;
+                }
+                was_in_run_second = true;
+            }
+
+            prev = Some(plot);
+        }
+    }
+}
+
+pub(crate) struct Day12;
+
+impl Solution for Day12 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Self::Input {
+        input.parse().unwrap()
+    }
+
+    fn part_1(map: &Self::Input) -> impl Display {
+        let (regions, _plots_to_region) = build_regions_and_plots(map);
+
+        let part_1: usize = regions
+            .values()
+            .map(|plots| {
+                let area = plots.len();
+                let perimeter = plots
+                    .iter()
+                    .flat_map(|&pos| Direction::ALL.map(|direction| (pos, direction)))
+                    .filter(|&(pos, direction)| !same_plant(map, pos, direction))
+                    .count();
+                area * perimeter
+            })
+            .sum();
+
+        part_1
+    }
+
+    fn part_2(map: &Self::Input) -> impl Display {
+        let (regions, plots_to_region) = build_regions_and_plots(map);
+        let mut regions_to_sides: HashMap<Pos, usize> = HashMap::new();
+
+        let (width, height) = (map.width(), map.height());
+        count_sides(
+            map,
+            &plots_to_region,
+            (0..height).map(|y| (0..width).map(|x| (x, y)).collect()),
+            [Direction::Up, Direction::Down],
+            &mut regions_to_sides,
+        );
+        count_sides(
+            map,
+            &plots_to_region,
+            (0..width).map(|x| (0..height).map(|y| (x, y)).collect()),
+            [Direction::Left, Direction::Right],
+            &mut regions_to_sides,
+        );
+
+        let part_2: usize = regions_to_sides
+            .iter()
+            .map(|(region, sides)| regions[region].len() * sides)
+            .sum();
+
+        part_2
+    }
+}
@@ -0,0 +1,136 @@
+use std::fmt::Display;
+
+use crate::util::Solution;
+use aoc_common::solve_linear_integer;
+use itertools::Itertools;
+use regex::Regex;
+
+type Point = (i128, i128);
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Machine {
+    a_action: Point,
+    b_action: Point,
+    prize_location: Point,
+}
+
+impl Machine {
+    /// The fewest tokens that win the prize, if it can be won. When the buttons move the claw in
+    /// independent directions, there's only one way to win.
+    fn min_tokens(&self) -> Option<i128> {
+        let Machine {
+            a_action: (a_x, a_y),
+            b_action: (b_x, b_y),
+            prize_location: (prize_x, prize_y),
+        } = *self;
+        if a_x * b_y == a_y * b_x {
+            return self.min_tokens_along_line();
+        }
+        let [a_presses, b_presses] =
+            solve_linear_integer([[a_x, b_x], [a_y, b_y]], [prize_x, prize_y])?;
+        (a_presses >= 0 && b_presses >= 0).then_some(a_presses * 3 + b_presses)
+    }
+
+    /// [`Machine::min_tokens`] for buttons that move the claw along the same line, which can win
+    /// in many ways if the prize is on it. The cost of the ways changes steadily with how many
+    /// times one button is pressed instead of the other, so the cheapest is the one that presses
+    /// the cheaper button per unit of distance the most times.
+    fn min_tokens_along_line(&self) -> Option<i128> {
+        let Machine {
+            a_action: (a_x, a_y),
+            b_action: (b_x, b_y),
+            prize_location: (prize_x, prize_y),
+        } = *self;
+        if a_x * prize_y != a_y * prize_x {
+            return None;
+        }
+        // Along the line, the distances along whichever axis both buttons move in are enough.
+        let (a_step, b_step, distance) = if a_x > 0 && b_x > 0 {
+            (a_x, b_x, prize_x)
+        } else {
+            (a_y, b_y, prize_y)
+        };
+        if a_step <= 0 || b_step <= 0 {
+            return None;
+        }
+        let a_is_cheaper = 3 * b_step < a_step;
+        let (cheap_step, dear_step) = if a_is_cheaper {
+            (a_step, b_step)
+        } else {
+            (b_step, a_step)
+        };
+        // Whether the rest of the distance divides by the dearer button's step repeats every
+        // `dear_step` fewer presses of the cheaper one, so there's no point trying more.
+        let most = distance / cheap_step;
+        let (cheap_presses, dear_presses) = (most.saturating_sub(dear_step).max(0)..=most)
+            .rev()
+            .map(|cheap_presses| (cheap_presses, distance - cheap_presses * cheap_step))
+            .find(|(_, rest)| rest % dear_step == 0)
+            .map(|(cheap_presses, rest)| (cheap_presses, rest / dear_step))?;
+        Some(if a_is_cheaper {
+            cheap_presses * 3 + dear_presses
+        } else {
+            dear_presses * 3 + cheap_presses
+        })
+    }
+}
+
+pub(crate) struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Machine>;
+
+    fn parse(input: &str) -> Self::Input {
+        let button_regex = Regex::new(r"^Button (A|B): X\+(\d+), Y\+(\d+)$").unwrap();
+        let prize_regex = Regex::new(r"^Prize: X=(\d+), Y=(\d+)$").unwrap();
+        input
+            .trim()
+            .lines()
+            .chunks(4)
+            .into_iter()
+            .map(|mut chunks| {
+                let (_, [_, a_x, a_y]) = button_regex
+                    .captures(chunks.next().unwrap())
+                    .unwrap()
+                    .extract();
+
+                let (_, [_, b_x, b_y]) = button_regex
+                    .captures(chunks.next().unwrap())
+                    .unwrap()
+                    .extract();
+
+                let (_, [prize_x, prize_y]) = prize_regex
+                    .captures(chunks.next().unwrap())
+                    .unwrap()
+                    .extract();
+
+                Machine {
+                    a_action: (a_x.parse().unwrap(), a_y.parse().unwrap()),
+                    b_action: (b_x.parse().unwrap(), b_y.parse().unwrap()),
+                    prize_location: (prize_x.parse().unwrap(), prize_y.parse().unwrap()),
+                }
+            })
+            .collect()
+    }
+
+    fn part_1(machines: &Self::Input) -> impl Display {
+        machines
+            .iter()
+            .filter_map(Machine::min_tokens)
+            .sum::<i128>()
+    }
+
+    fn part_2(machines: &Self::Input) -> impl Display {
+        machines
+            .iter()
+            .filter_map(|machine| {
+                let (prize_x, prize_y) = machine.prize_location;
+                Machine {
+                    prize_location: (prize_x + 10_000_000_000_000, prize_y + 10_000_000_000_000),
+                    ..*machine
+                }
+                .min_tokens()
+            })
+            .sum::<i128>()
+    }
+}
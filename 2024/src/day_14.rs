@@ -0,0 +1,155 @@
+#![allow(clippy::cast_sign_loss)]
+
+use crate::util::{Params, Solution};
+use regex::Regex;
+use std::fmt::Display;
+
+const AREA_WIDTH: i32 = 101;
+const AREA_HEIGHT: i32 = 103;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Robot {
+    position: (i32, i32),
+    velocity: (i32, i32),
+}
+
+impl Robot {
+    fn moved_many(&self, n: i32, width: i32, height: i32) -> Self {
+        let mut new = *self;
+
+        new.position.0 = (self.position.0 + self.velocity.0 * n).rem_euclid(width);
+        new.position.1 = (self.position.1 + self.velocity.1 * n).rem_euclid(height);
+
+        new
+    }
+}
+
+pub(crate) struct Area {
+    robots: Vec<Robot>,
+    width: i32,
+    height: i32,
+}
+
+fn create_grid_after_seconds(area: &Area, seconds: i32) -> Vec<Vec<bool>> {
+    let mut grid = vec![vec![false; area.width as usize]; area.height as usize];
+
+    for &robot in &area.robots {
+        let robot = robot.moved_many(seconds, area.width, area.height);
+        grid[robot.position.1 as usize][robot.position.0 as usize] = true;
+    }
+
+    grid
+}
+
+#[allow(clippy::needless_range_loop)]
+fn find_col_sequence_larger_than(grid: &[Vec<bool>], n: usize) -> bool {
+    for x in 0..grid[0].len() {
+        let mut count = 0;
+        for y in 0..grid.len() {
+            if grid[y][x] {
+                count += 1;
+            } else {
+                count = 0;
+            }
+
+            if count >= n {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+// Used to visualise the grid for investigating the easer egg
+#[allow(dead_code)]
+fn print_robots_after(area: &Area, seconds: i32, only_interesting: bool) {
+    let grid = create_grid_after_seconds(area, seconds);
+
+    if only_interesting && !find_col_sequence_larger_than(&grid, 20) {
+        return;
+    }
+
+    println!("After {seconds} seconds:");
+
+    for row in &grid {
+        for &robot in row {
+            print!("{}", if robot { '#' } else { '.' });
+        }
+        println!();
+    }
+
+    println!();
+    println!();
+}
+
+pub(crate) struct Day14;
+
+impl Solution for Day14 {
+    type Input = Area;
+
+    fn parse(input: &str) -> Self::Input {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Self::Input {
+        let robot_regex = Regex::new(r"^p=(-?\d+),(-?\d+) v=(-?\d+),(-?\d+)$").unwrap();
+        let robots = input
+            .trim()
+            .lines()
+            .map(|line| {
+                let (_, [p_x, p_y, v_x, v_y]) = robot_regex.captures(line).unwrap().extract();
+
+                Robot {
+                    position: (p_x.parse().unwrap(), p_y.parse().unwrap()),
+                    velocity: (v_x.parse().unwrap(), v_y.parse().unwrap()),
+                }
+            })
+            .collect();
+
+        Area {
+            robots,
+            width: params.get("width", AREA_WIDTH),
+            height: params.get("height", AREA_HEIGHT),
+        }
+    }
+
+    fn part_1(area: &Self::Input) -> impl Display {
+        let (width, height) = (area.width, area.height);
+        let robots = area
+            .robots
+            .iter()
+            .map(|&robot| robot.moved_many(100, width, height));
+        let mut top_left_count = 0;
+        let mut top_right_count = 0;
+        let mut bottom_left_count = 0;
+        let mut bottom_right_count = 0;
+
+        for robot in robots {
+            if robot.position.0 < width / 2 && robot.position.1 < height / 2 {
+                top_left_count += 1;
+            } else if robot.position.0 > width / 2 && robot.position.1 < height / 2 {
+                top_right_count += 1;
+            } else if robot.position.0 < width / 2 && robot.position.1 > height / 2 {
+                bottom_left_count += 1;
+            } else if robot.position.0 > width / 2 && robot.position.1 > height / 2 {
+                bottom_right_count += 1;
+            }
+        }
+
+        top_left_count * top_right_count * bottom_left_count * bottom_right_count
+    }
+
+    fn part_2(area: &Self::Input) -> impl Display {
+        for i in 0.. {
+            // print_robots_after(area, i, true);
+
+            let grid = create_grid_after_seconds(area, i);
+            if find_col_sequence_larger_than(&grid, 20) {
+                return i;
+            }
+        }
+
+        unreachable!("ran out of seconds to check")
+    }
+}
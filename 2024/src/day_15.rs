@@ -0,0 +1,264 @@
+#![allow(clippy::similar_names)]
+
+use crate::util::Solution;
+use aoc_common::{Direction, Grid, Pos};
+use itertools::Itertools;
+use std::fmt::Display;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Cell {
+    Empty,
+    Wall,
+    Box,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Cell2 {
+    Empty,
+    Wall,
+    BoxLeft,
+    BoxRight,
+}
+
+/// The position one step from `pos` in `direction`. Every map is walled in, so this never
+/// leaves it.
+fn step<T>(map: &Grid<T>, pos: Pos, direction: Direction) -> Pos {
+    map.offset(pos, direction).unwrap()
+}
+
+fn shift_cell(map: &mut Grid<Cell>, pos: Pos, m: Direction) -> bool {
+    match map[pos] {
+        Cell::Empty => {
+            // Cell is empty, so no need to clear it
+            true
+        }
+        Cell::Wall => {
+            // Cell is a wall, so we can't move it
+            false
+        }
+        Cell::Box => {
+            // Cell is a box, so we can try to move it
+            let new_pos = step(map, pos, m);
+            if shift_cell(map, new_pos, m) {
+                map[new_pos] = Cell::Box;
+                map[pos] = Cell::Empty;
+                true
+            } else {
+                false
+            }
+        }
+    }
+}
+
+fn shift_cell_2(map: &mut Grid<Cell2>, pos: Pos, m: Direction, dry_run: bool) -> bool {
+    match map[pos] {
+        Cell2::Empty => {
+            // Cell is empty, so no need to clear it
+            true
+        }
+        Cell2::Wall => {
+            // Cell is a wall, so we can't move it
+            false
+        }
+        Cell2::BoxLeft => {
+            // Cell is left side of a box, so we can try to move it
+            match m {
+                Direction::Up | Direction::Down => {
+                    let new_pos = step(map, pos, m);
+                    let right = step(map, pos, Direction::Right);
+                    let new_right = step(map, right, m);
+                    if shift_cell_2(map, new_pos, m, true) && shift_cell_2(map, new_right, m, true)
+                    {
+                        if !dry_run {
+                            assert!(shift_cell_2(map, new_pos, m, false));
+                            assert!(shift_cell_2(map, new_right, m, false));
+                            map[new_pos] = Cell2::BoxLeft;
+                            map[pos] = Cell2::Empty;
+                            map[new_right] = Cell2::BoxRight;
+                            map[right] = Cell2::Empty;
+                        }
+                        true
+                    } else {
+                        false
+                    }
+                }
+                Direction::Left => {
+                    let new_pos = step(map, pos, m);
+                    let right = step(map, pos, Direction::Right);
+                    if shift_cell_2(map, new_pos, m, dry_run) {
+                        if !dry_run {
+                            map[new_pos] = Cell2::BoxLeft;
+                            map[pos] = Cell2::BoxRight;
+                            map[right] = Cell2::Empty;
+                        }
+                        true
+                    } else {
+                        false
+                    }
+                }
+                Direction::Right => {
+                    let right = step(map, pos, Direction::Right);
+                    let new_right = step(map, right, m);
+                    if shift_cell_2(map, new_right, m, dry_run) {
+                        if !dry_run {
+                            map[new_right] = Cell2::BoxRight;
+                            map[right] = Cell2::BoxLeft;
+                            map[pos] = Cell2::Empty;
+                        }
+                        true
+                    } else {
+                        false
+                    }
+                }
+            }
+        }
+        Cell2::BoxRight => {
+            // Just redirect to a left box move to simplify the logic
+            shift_cell_2(map, step(map, pos, Direction::Left), m, dry_run)
+        }
+    }
+}
+
+fn move_robot(map: &mut Grid<Cell>, robot_pos: &mut Pos, m: Direction) -> bool {
+    let new_pos = step(map, *robot_pos, m);
+    if shift_cell(map, new_pos, m) {
+        *robot_pos = new_pos;
+        true
+    } else {
+        false
+    }
+}
+
+fn move_robot_2(map: &mut Grid<Cell2>, robot_pos: &mut Pos, m: Direction) -> bool {
+    let new_pos = step(map, *robot_pos, m);
+    if shift_cell_2(map, new_pos, m, false) {
+        *robot_pos = new_pos;
+        true
+    } else {
+        false
+    }
+}
+
+#[allow(dead_code)]
+fn print_map(map: &Grid<Cell>, robot: &Pos) {
+    for (y, row) in map.rows().enumerate() {
+        for (x, cell) in row.iter().enumerate() {
+            print!(
+                "{}",
+                if *robot == (x, y) {
+                    '@'
+                } else {
+                    match cell {
+                        Cell::Empty => '.',
+                        Cell::Wall => '#',
+                        Cell::Box => 'O',
+                    }
+                }
+            );
+        }
+        println!();
+    }
+    println!();
+    println!();
+}
+
+#[allow(dead_code)]
+fn print_map_2(map: &Grid<Cell2>, robot: &Pos) {
+    for (y, row) in map.rows().enumerate() {
+        for (x, cell) in row.iter().enumerate() {
+            print!(
+                "{}",
+                if *robot == (x, y) {
+                    '@'
+                } else {
+                    match cell {
+                        Cell2::Empty => '.',
+                        Cell2::Wall => '#',
+                        Cell2::BoxLeft => '[',
+                        Cell2::BoxRight => ']',
+                    }
+                }
+            );
+        }
+        println!();
+    }
+    println!();
+    println!();
+}
+
+fn part_2_map_from_map(map: &Grid<Cell>, robot_pos: &Pos) -> (Grid<Cell2>, Pos) {
+    let new_map = Grid::from_fn(map.width() * 2, map.height(), |(x, y)| {
+        match (map[(x / 2, y)], x % 2) {
+            (Cell::Empty, _) => Cell2::Empty,
+            (Cell::Wall, _) => Cell2::Wall,
+            (Cell::Box, 0) => Cell2::BoxLeft,
+            (Cell::Box, _) => Cell2::BoxRight,
+        }
+    });
+
+    let new_robot_pos = (robot_pos.0 * 2, robot_pos.1);
+
+    (new_map, new_robot_pos)
+}
+
+pub(crate) struct Day15;
+
+impl Solution for Day15 {
+    type Input = (Grid<Cell>, Pos, Vec<Direction>);
+
+    fn parse(input: &str) -> Self::Input {
+        let (map, moves) = input.split_once("\n\n").unwrap();
+        let chars: Grid<char> = map.parse().unwrap();
+        let robot_pos = chars
+            .position(|&c| c == '@')
+            .expect("no robot found on input map");
+        let map = chars.map(|&c| match c {
+            '.' | '@' => Cell::Empty,
+            '#' => Cell::Wall,
+            'O' => Cell::Box,
+            _ => panic!("unexpected character in input map: {c:?}"),
+        });
+
+        let moves = moves
+            .lines()
+            .join("")
+            .chars()
+            .map(|c| {
+                Direction::try_from(c)
+                    .unwrap_or_else(|c| panic!("unexpected character in move list: {c:?}"))
+            })
+            .collect();
+
+        (map, robot_pos, moves)
+    }
+
+    fn part_1((map, robot_pos, moves): &Self::Input) -> impl Display {
+        let mut map = map.clone();
+        let mut robot_pos = *robot_pos;
+        for &m in moves {
+            // println!("Move: {m:?}");
+            move_robot(&mut map, &mut robot_pos, m);
+            // print_map(&map, &robot_pos);
+        }
+
+        map.iter()
+            .filter(|&(_, cell)| *cell == Cell::Box)
+            .map(|((x, y), _)| y * 100 + x)
+            .sum::<usize>()
+    }
+
+    fn part_2((map, robot_pos, moves): &Self::Input) -> impl Display {
+        let (mut map, mut robot_pos) = part_2_map_from_map(map, robot_pos);
+        // print_map_2(&map, &robot_pos);
+        for &m in moves {
+            // println!("Move: {m:?}");
+            move_robot_2(&mut map, &mut robot_pos, m);
+            // print_map_2(&map, &robot_pos);
+        }
+
+        map.iter()
+            .filter(|&(_, cell)| *cell == Cell2::BoxLeft)
+            .map(|((x, y), _)| y * 100 + x)
+            .sum::<usize>()
+    }
+}
@@ -0,0 +1,104 @@
+use std::{collections::HashSet, fmt::Display};
+
+use aoc_common::{all_shortest_paths, dijkstra, Direction, Grid, Pos};
+
+use crate::util::Solution;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Tile {
+    Empty,
+    Wall,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct State {
+    position: Pos,
+    direction: Direction,
+}
+
+fn next_states(map: &Grid<Tile>, state: State) -> Vec<(State, u64)> {
+    let mut new_states = Vec::new();
+
+    // The maze is walled in, so a step never leaves it.
+    let forward = map.offset(state.position, state.direction).unwrap();
+
+    if map[forward] == Tile::Empty {
+        new_states.push((
+            State {
+                position: forward,
+                direction: state.direction,
+            },
+            1,
+        ));
+    }
+
+    for new_direction in [state.direction.turn_left(), state.direction.turn_right()] {
+        let new_position = map.offset(state.position, new_direction).unwrap();
+        if map[new_position] == Tile::Empty {
+            new_states.push((
+                State {
+                    position: new_position,
+                    direction: new_direction,
+                },
+                1001,
+            ));
+        }
+    }
+
+    new_states
+}
+
+pub(crate) struct Day16;
+
+impl Solution for Day16 {
+    type Input = (Grid<Tile>, Pos, Pos);
+
+    fn parse(input: &str) -> Self::Input {
+        let chars: Grid<char> = input.parse().unwrap();
+        let start = chars
+            .position(|&c| c == 'S')
+            .expect("start not specified in input map");
+        let end = chars
+            .position(|&c| c == 'E')
+            .expect("end not specified in input map");
+        let map = chars.map(|&c| match c {
+            '.' | 'S' | 'E' => Tile::Empty,
+            '#' => Tile::Wall,
+            _ => panic!("invalid character in input map"),
+        });
+
+        (map, start, end)
+    }
+
+    fn part_1((map, start, end): &Self::Input) -> impl Display {
+        let start = State {
+            position: *start,
+            direction: Direction::Right,
+        };
+        dijkstra(
+            [start],
+            |&state| next_states(map, state),
+            |state| state.position == *end,
+        )
+        .unwrap()
+        .cost
+    }
+
+    fn part_2((map, start, end): &Self::Input) -> impl Display {
+        let start = State {
+            position: *start,
+            direction: Direction::Right,
+        };
+        all_shortest_paths(
+            [start],
+            |&state| next_states(map, state),
+            |state| state.position == *end,
+        )
+        .unwrap()
+        .states()
+        .into_iter()
+        .map(|state| state.position)
+        .collect::<HashSet<_>>()
+        .len()
+    }
+}
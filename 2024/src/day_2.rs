@@ -0,0 +1,89 @@
+use std::fmt::Display;
+
+use crate::util::Solution;
+use itertools::Itertools;
+
+fn check_condition(
+    report: impl Iterator<Item = u64>,
+    condition: impl Fn(u64, u64) -> bool,
+) -> bool {
+    report.tuple_windows().all(|(a, b)| condition(a, b))
+}
+
+fn check_condition_relaxed(report: &[u64], condition: impl Fn(u64, u64) -> bool) -> bool {
+    struct SkipElemIter<I: Iterator> {
+        iter: I,
+        index: usize,
+        skip_index: usize,
+    }
+    impl<I: Iterator> SkipElemIter<I> {
+        pub fn new(iter: I, skip_index: usize) -> Self {
+            Self {
+                iter,
+                index: 0,
+                skip_index,
+            }
+        }
+    }
+    impl<I: Iterator> Iterator for SkipElemIter<I> {
+        type Item = I::Item;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.index == self.skip_index {
+                self.index += 1;
+                self.iter.next();
+            }
+            self.index += 1;
+            self.iter.next()
+        }
+    }
+
+    if check_condition(report.iter().copied(), &condition) {
+        return true;
+    }
+    for i in 0..report.len() {
+        let report = SkipElemIter::new(report.iter().copied(), i);
+        if check_condition(report, &condition) {
+            return true;
+        }
+    }
+
+    false
+}
+
+pub(crate) struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Vec<u64>>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .lines()
+            .map(|line| {
+                line.split_whitespace()
+                    .map(|x| x.parse().unwrap())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part_1(input: &Self::Input) -> impl Display {
+        input
+            .iter()
+            .filter(|report| {
+                check_condition(report.iter().copied(), |a, b| a < b && b - a <= 3)
+                    || check_condition(report.iter().copied(), |a, b| a > b && a - b <= 3)
+            })
+            .count()
+    }
+
+    fn part_2(input: &Self::Input) -> impl Display {
+        input
+            .iter()
+            .filter(|report| {
+                check_condition_relaxed(report, |a, b| a < b && b - a <= 3)
+                    || check_condition_relaxed(report, |a, b| a > b && a - b <= 3)
+            })
+            .count()
+    }
+}
@@ -1,32 +1,35 @@
+use std::fmt::Display;
+
+use crate::util::Solution;
 use itertools::Itertools;
 use regex::RegexBuilder;
-use util::PerfTimer;
 
-fn main() {
-    let input = util::get_day_input(3);
+pub(crate) struct Day3;
+
+impl Solution for Day3 {
+    type Input = String;
 
-    {
-        let _timer = PerfTimer::new("Part 1");
+    fn parse(input: &str) -> Self::Input {
+        input.to_owned()
+    }
+
+    fn part_1(input: &Self::Input) -> impl Display {
         let re = RegexBuilder::new(r"mul\((\d{1,3}),(\d{1,3})\)")
             .dot_matches_new_line(true)
             .multi_line(false)
             .build()
             .unwrap();
-        let part_1 = re
-            .captures_iter(&input)
+        re.captures_iter(input)
             .map(|caps| {
                 let (_, [a, b]) = caps.extract();
                 let a: u64 = a.parse().unwrap();
                 let b: u64 = b.parse().unwrap();
                 a * b
             })
-            .sum::<u64>();
-
-        println!("Part 1: {part_1}");
+            .sum::<u64>()
     }
 
-    {
-        let _timer = PerfTimer::new("Part 2");
+    fn part_2(input: &Self::Input) -> impl Display {
         let re = RegexBuilder::new(r"mul\((\d{1,3}),(\d{1,3})\)|(do\(\))|(don't\(\))")
             .dot_matches_new_line(true)
             .multi_line(false)
@@ -34,7 +37,7 @@ fn main() {
             .unwrap();
         let mut acc = 0;
         let mut enabled = true;
-        for caps in re.captures_iter(&input) {
+        for caps in re.captures_iter(input) {
             match caps.iter().collect_tuple().unwrap() {
                 (_, Some(a), Some(b), None, None) => {
                     if enabled {
@@ -53,6 +56,6 @@ fn main() {
             }
         }
 
-        println!("Part 2: {acc}");
+        acc
     }
 }
@@ -0,0 +1,47 @@
+use std::fmt::Display;
+
+use aoc_common::{Grid, ALL_DIRECTIONS};
+
+use crate::util::Solution;
+
+pub(crate) struct Day4;
+
+impl Solution for Day4 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Self::Input {
+        Grid::parse(input, Some).unwrap()
+    }
+
+    fn part_1(grid: &Self::Input) -> impl Display {
+        grid.iter()
+            .filter(|&(_, &c)| c == 'X')
+            .map(|(pos, _)| {
+                ALL_DIRECTIONS
+                    .into_iter()
+                    .filter(|&step| {
+                        grid.ray(pos, step)
+                            .map(|pos| grid[pos])
+                            .take(3)
+                            .eq("MAS".chars())
+                    })
+                    .count()
+            })
+            .sum::<usize>()
+    }
+
+    fn part_2(grid: &Self::Input) -> impl Display {
+        let is_mas = |a: Option<&char>, b: Option<&char>| {
+            matches!((a, b), (Some('M'), Some('S')) | (Some('S'), Some('M')))
+        };
+        grid.iter()
+            .filter(|&((x, y), &c)| {
+                c == 'A'
+                    && x > 0
+                    && y > 0
+                    && is_mas(grid.get((x - 1, y - 1)), grid.get((x + 1, y + 1)))
+                    && is_mas(grid.get((x + 1, y - 1)), grid.get((x - 1, y + 1)))
+            })
+            .count()
+    }
+}
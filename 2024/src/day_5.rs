@@ -1,34 +1,34 @@
-use std::{borrow::BorrowMut, collections::HashSet};
+use std::{borrow::BorrowMut, collections::HashSet, fmt::Display};
 
-use util::PerfTimer;
+use crate::util::Solution;
 
-fn input() -> (Vec<(u32, u32)>, Vec<Vec<u32>>) {
-    let raw = util::get_day_input(5);
-    let mut lines = raw.lines();
+pub(crate) struct Day5;
 
-    let rules = lines
-        .borrow_mut()
-        .take_while(|l| !l.is_empty())
-        .map(|line| {
-            let (l, r) = line.split_once('|').unwrap();
-            (l.parse().unwrap(), r.parse().unwrap())
-        })
-        .collect();
+impl Solution for Day5 {
+    type Input = (Vec<(u32, u32)>, Vec<Vec<u32>>);
 
-    let updates = lines
-        .map(|line| line.split(',').map(|n| n.parse().unwrap()).collect())
-        .collect();
+    fn parse(input: &str) -> Self::Input {
+        let mut lines = input.lines();
 
-    (rules, updates)
-}
+        let rules = lines
+            .borrow_mut()
+            .take_while(|l| !l.is_empty())
+            .map(|line| {
+                let (l, r) = line.split_once('|').unwrap();
+                (l.parse().unwrap(), r.parse().unwrap())
+            })
+            .collect();
+
+        let updates = lines
+            .map(|line| line.split(',').map(|n| n.parse().unwrap()).collect())
+            .collect();
 
-fn main() {
-    let (rules, updates) = input();
+        (rules, updates)
+    }
 
-    {
-        let _timer = PerfTimer::new("Part 1");
+    fn part_1((rules, updates): &Self::Input) -> impl Display {
         let rules: HashSet<(u32, u32)> = rules.iter().copied().collect();
-        let part_1: u32 = updates
+        updates
             .iter()
             .filter(|update| {
                 for i in 0..update.len() - 1 {
@@ -47,15 +47,12 @@ fn main() {
                 );
                 update[update.len() / 2]
             })
-            .sum();
-
-        println!("Part 1: {part_1}");
+            .sum::<u32>()
     }
 
-    {
-        let _timer = PerfTimer::new("Part 2");
+    fn part_2((rules, updates): &Self::Input) -> impl Display {
         let rules: HashSet<(u32, u32)> = rules.iter().copied().collect();
-        let part_2: u32 = updates
+        updates
             .iter()
             .cloned()
             .filter_map(|mut update| {
@@ -80,8 +77,6 @@ fn main() {
                     None
                 }
             })
-            .sum();
-
-        println!("Part 2: {part_2}");
+            .sum::<u32>()
     }
 }
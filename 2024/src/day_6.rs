@@ -0,0 +1,83 @@
+use std::{collections::HashSet, fmt::Display};
+
+use aoc_common::{Direction, Grid, Pos};
+
+use crate::util::Solution;
+
+fn patrol(grid: &Grid<bool>, guard_position: Pos, guard_direction: Direction) -> HashSet<Pos> {
+    let mut guard_locations = HashSet::new();
+    let mut guard_position = guard_position;
+    let mut guard_direction = guard_direction;
+
+    loop {
+        guard_locations.insert(guard_position);
+
+        let Some(new_position) = grid.offset(guard_position, guard_direction) else {
+            break;
+        };
+
+        if grid[new_position] {
+            guard_direction = guard_direction.turn_right();
+        } else {
+            guard_position = new_position;
+        }
+    }
+
+    guard_locations
+}
+
+pub(crate) struct Day6;
+
+impl Solution for Day6 {
+    type Input = (Grid<bool>, Pos, Direction);
+
+    fn parse(input: &str) -> Self::Input {
+        let map = Grid::parse(input, Some).unwrap();
+        let guard_position = map
+            .position(|c| ['^', 'v', '<', '>'].contains(c))
+            .expect("guard position not found");
+        let guard_direction = Direction::try_from(map[guard_position]).unwrap();
+        assert!(
+            map.iter()
+                .filter(|(_, c)| ['^', 'v', '<', '>'].contains(c))
+                .count()
+                == 1,
+            "multiple guards found"
+        );
+
+        (map.map(|&c| c == '#'), guard_position, guard_direction)
+    }
+
+    fn part_1((grid, guard_position, guard_direction): &Self::Input) -> impl Display {
+        patrol(grid, *guard_position, *guard_direction).len()
+    }
+
+    fn part_2((grid, guard_position, guard_direction): &Self::Input) -> impl Display {
+        let mut loop_count: usize = 0;
+
+        for obstruction_location in patrol(grid, *guard_position, *guard_direction) {
+            let mut guard_locations = HashSet::new();
+            let mut guard_position = *guard_position;
+            let mut guard_direction = *guard_direction;
+
+            loop {
+                if !guard_locations.insert((guard_position, guard_direction)) {
+                    loop_count += 1;
+                    break;
+                }
+
+                let Some(new_position) = grid.offset(guard_position, guard_direction) else {
+                    break;
+                };
+
+                if grid[new_position] || new_position == obstruction_location {
+                    guard_direction = guard_direction.turn_right();
+                } else {
+                    guard_position = new_position;
+                }
+            }
+        }
+
+        loop_count
+    }
+}
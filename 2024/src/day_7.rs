@@ -1,21 +1,25 @@
-use util::PerfTimer;
+use std::fmt::Display;
 
-fn input() -> Vec<(u64, Vec<u64>)> {
-    let raw = util::get_day_input(7);
-    raw.lines()
-        .map(|line| {
-            let (test_value, rem) = line.split_once(": ").unwrap();
-            let test_value = test_value.parse().unwrap();
-            let args = rem.split(' ').map(|x| x.parse().unwrap()).collect();
-            (test_value, args)
-        })
-        .collect()
-}
+use crate::util::Solution;
+
+pub(crate) struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<(u64, Vec<u64>)>;
 
-fn main() {
-    let equations = input();
+    fn parse(input: &str) -> Self::Input {
+        input
+            .lines()
+            .map(|line| {
+                let (test_value, rem) = line.split_once(": ").unwrap();
+                let test_value = test_value.parse().unwrap();
+                let args = rem.split(' ').map(|x| x.parse().unwrap()).collect();
+                (test_value, args)
+            })
+            .collect()
+    }
 
-    {
+    fn part_1(equations: &Self::Input) -> impl Display {
         fn try_eval(test_value: u64, acc: u64, args: &[u64]) -> bool {
             if args.is_empty() {
                 acc == test_value
@@ -27,9 +31,7 @@ fn main() {
             }
         }
 
-        let _timer = PerfTimer::new("Part 1");
-
-        let part_1 = equations
+        equations
             .iter()
             .filter_map(|(test_value, args)| {
                 if try_eval(*test_value, args[0], &args[1..]) {
@@ -38,12 +40,10 @@ fn main() {
                     None
                 }
             })
-            .sum::<u64>();
-
-        println!("Part 1: {part_1}");
+            .sum::<u64>()
     }
 
-    {
+    fn part_2(equations: &Self::Input) -> impl Display {
         fn concat(a: u64, b: u64) -> u64 {
             let mut mul = 1;
             while mul <= b {
@@ -64,9 +64,7 @@ fn main() {
             }
         }
 
-        let _timer = PerfTimer::new("Part 2");
-
-        let part_2 = equations
+        equations
             .iter()
             .filter_map(|(test_value, args)| {
                 if try_eval(*test_value, args[0], &args[1..]) {
@@ -75,8 +73,6 @@ fn main() {
                     None
                 }
             })
-            .sum::<u64>();
-
-        println!("Part 2: {part_2}");
+            .sum::<u64>()
     }
 }
@@ -0,0 +1,64 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use crate::util::Solution;
+use aoc_common::{Grid, Pos};
+use itertools::Itertools;
+
+fn antennas(grid: &Grid<char>) -> HashMap<char, Vec<Pos>> {
+    let mut antennas = HashMap::new();
+    for (pos, &c) in grid.iter() {
+        if c != '.' {
+            assert!(c.is_ascii_alphanumeric());
+            antennas.entry(c).or_insert(Vec::new()).push(pos);
+        }
+    }
+    antennas
+}
+
+/// The step from `a` to `b`.
+fn difference(a: Pos, b: Pos) -> (isize, isize) {
+    (
+        b.0.cast_signed() - a.0.cast_signed(),
+        b.1.cast_signed() - a.1.cast_signed(),
+    )
+}
+
+pub(crate) struct Day8;
+
+impl Solution for Day8 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Self::Input {
+        input.parse().unwrap()
+    }
+
+    fn part_1(grid: &Self::Input) -> impl Display {
+        let mut antinodes = HashSet::new();
+        for antennas in antennas(grid).values() {
+            for (&a, &b) in antennas.iter().tuple_combinations() {
+                let (d_x, d_y) = difference(a, b);
+                antinodes.extend(grid.offset(a, (-d_x, -d_y)));
+                antinodes.extend(grid.offset(b, (d_x, d_y)));
+            }
+        }
+
+        antinodes.len()
+    }
+
+    fn part_2(grid: &Self::Input) -> impl Display {
+        let mut antinodes = HashSet::new();
+        for antennas in antennas(grid).values() {
+            for (&a, &b) in antennas.iter().tuple_combinations() {
+                let (d_x, d_y) = difference(a, b);
+                antinodes.extend([a, b]);
+                antinodes.extend(grid.ray(a, (-d_x, -d_y)));
+                antinodes.extend(grid.ray(b, (d_x, d_y)));
+            }
+        }
+
+        antinodes.len()
+    }
+}
@@ -1,25 +1,33 @@
-use util::PerfTimer;
+use std::fmt::Display;
 
-fn input() -> Vec<u32> {
-    util::get_day_input(9)
-        .trim()
-        .chars()
-        .map(|c| c.to_digit(10).unwrap())
-        .collect()
+use crate::util::Solution;
+
+fn expand(disk_map: &[u32]) -> Vec<Option<usize>> {
+    let mut disk = Vec::new();
+    for (i, n) in disk_map.iter().copied().enumerate() {
+        let v = if i % 2 == 0 { Some(i / 2) } else { None };
+        for _ in 0..n {
+            disk.push(v);
+        }
+    }
+    disk
 }
 
-fn main() {
-    let input = input();
+pub(crate) struct Day9;
 
-    {
-        let _timer = PerfTimer::new("Part 1");
-        let mut disk = Vec::new();
-        for (i, n) in input.iter().copied().enumerate() {
-            let v = if i % 2 == 0 { Some(i / 2) } else { None };
-            for _ in 0..n {
-                disk.push(v);
-            }
-        }
+impl Solution for Day9 {
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .trim()
+            .chars()
+            .map(|c| c.to_digit(10).unwrap())
+            .collect()
+    }
+
+    fn part_1(input: &Self::Input) -> impl Display {
+        let mut disk = expand(input);
 
         let mut i = 0;
         'outer: loop {
@@ -36,24 +44,14 @@ fn main() {
             disk[i] = last;
         }
 
-        let part_1 = disk
-            .into_iter()
+        disk.into_iter()
             .enumerate()
             .map(|(i, v)| i * v.unwrap())
-            .sum::<usize>();
-
-        println!("Part 1: {part_1}");
+            .sum::<usize>()
     }
 
-    {
-        let _timer = PerfTimer::new("Part 2");
-        let mut disk = Vec::new();
-        for (i, n) in input.iter().copied().enumerate() {
-            let v = if i % 2 == 0 { Some(i / 2) } else { None };
-            for _ in 0..n {
-                disk.push(v);
-            }
-        }
+    fn part_2(input: &Self::Input) -> impl Display {
+        let mut disk = expand(input);
 
         let mut from_cursor = disk.len() - 1;
         'outer: for disk_id in (1..=(disk.len() - 1) / 2).rev() {
@@ -99,12 +97,9 @@ fn main() {
             }
         }
 
-        let part_2 = disk
-            .into_iter()
+        disk.into_iter()
             .enumerate()
             .map(|(i, v)| i * v.unwrap_or(0))
-            .sum::<usize>();
-
-        println!("Part 2: {part_2}");
+            .sum::<usize>()
     }
 }
@@ -0,0 +1,43 @@
+pub mod util;
+
+mod day_1;
+mod day_10;
+mod day_11;
+mod day_12;
+mod day_13;
+mod day_14;
+mod day_15;
+mod day_16;
+mod day_2;
+mod day_3;
+mod day_4;
+mod day_5;
+mod day_6;
+mod day_7;
+mod day_8;
+mod day_9;
+
+use aoc_common::{Day, Year};
+
+pub const YEAR: Year = Year {
+    year: 2024,
+    dir: env!("CARGO_MANIFEST_DIR"),
+    days: &[
+        Day::new::<day_1::Day1>(1),
+        Day::new::<day_2::Day2>(2),
+        Day::new::<day_3::Day3>(3),
+        Day::new::<day_4::Day4>(4),
+        Day::new::<day_5::Day5>(5),
+        Day::new::<day_6::Day6>(6),
+        Day::new::<day_7::Day7>(7),
+        Day::new::<day_8::Day8>(8),
+        Day::new::<day_9::Day9>(9),
+        Day::new::<day_10::Day10>(10),
+        Day::new::<day_11::Day11>(11),
+        Day::new::<day_12::Day12>(12),
+        Day::new::<day_13::Day13>(13),
+        Day::new::<day_14::Day14>(14),
+        Day::new::<day_15::Day15>(15),
+        Day::new::<day_16::Day16>(16),
+    ],
+};
@@ -0,0 +1,4 @@
+#[test]
+fn recorded_answers() {
+    aoc_common::assert_recorded_answers(&advent_of_code_2024::YEAR);
+}
@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_common::assert_examples(&advent_of_code_2024::YEAR);
+}
@@ -0,0 +1,155 @@
+//! Recorded answers for a year's puzzle inputs, kept in `answers.txt` next to its `input/`.
+//!
+//! Each non-empty line that doesn't start with `#` is `<day> <part> <answer>`. Answers that span
+//! several lines (the ones drawn as pictures) have their newlines written as `\n`, and backslashes
+//! as `\\`.
+
+use std::{collections::BTreeMap, fs, io, panic, path::Path};
+
+use crate::{Answers, InputError, Year};
+
+pub(crate) fn unescape(answer: &str) -> Result<String, String> {
+    let mut unescaped = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('\\') => unescaped.push('\\'),
+            other => {
+                return Err(format!(
+                    "Invalid escape sequence \\{}",
+                    other.unwrap_or(' ')
+                ))
+            }
+        }
+    }
+    Ok(unescaped)
+}
+
+/// Parses the contents of an answers file into the recorded answers for each day.
+///
+/// # Errors
+///
+/// Returns a message naming the offending line if one isn't a valid entry, or if an answer is
+/// recorded twice.
+pub fn parse_answers(text: &str) -> Result<BTreeMap<u8, Answers>, String> {
+    let mut answers: BTreeMap<u8, Answers> = BTreeMap::new();
+    for (line_number, line) in (1..).zip(text.lines()) {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let error = |message: &str| format!("Line {line_number}: {message}");
+        let mut fields = line.splitn(3, ' ');
+        let (Some(day), Some(part), Some(answer)) = (fields.next(), fields.next(), fields.next())
+        else {
+            return Err(error("expected `<day> <part> <answer>`"));
+        };
+        let day = day
+            .parse()
+            .map_err(|_| error(&format!("invalid day {day:?}")))?;
+        let answer = unescape(answer).map_err(|e| error(&e))?;
+        let entry = answers.entry(day).or_default();
+        let slot = match part {
+            "1" => &mut entry.part_1,
+            "2" => &mut entry.part_2,
+            _ => return Err(error(&format!("invalid part {part:?}"))),
+        };
+        if slot.replace(answer).is_some() {
+            return Err(error(&format!("day {day} part {part} is recorded twice")));
+        }
+    }
+    Ok(answers)
+}
+
+impl Year {
+    /// Reads this year's `answers.txt`, treating a missing file as having no answers recorded.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message naming the file if it can't be read or parsed.
+    pub fn recorded_answers(&self) -> Result<BTreeMap<u8, Answers>, String> {
+        let path = Path::new(self.dir).join("answers.txt");
+        match fs::read_to_string(&path) {
+            Ok(text) => parse_answers(&text).map_err(|e| format!("{}: {e}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+            Err(e) => Err(format!("{}: {e}", path.display())),
+        }
+    }
+}
+
+/// Adds a line to `report` if `actual` isn't the `expected` answer, when one is recorded.
+pub(crate) fn compare(
+    report: &mut Vec<String>,
+    what: &str,
+    part: u8,
+    expected: Option<&String>,
+    actual: Option<&String>,
+) {
+    let Some(expected) = expected else {
+        return;
+    };
+    match actual {
+        Some(actual) if actual == expected => {}
+        Some(actual) => report.push(format!(
+            "{what} part {part}: expected {expected:?}, got {actual:?}"
+        )),
+        None => report.push(format!(
+            "{what} part {part}: expected {expected:?}, but the solution has no part {part}"
+        )),
+    }
+}
+
+/// Runs every registered day that has both an input file and recorded answers, panicking with a
+/// report of every answer that no longer matches. Days without an input file are skipped, so this
+/// passes on a checkout that doesn't include the personal puzzle inputs.
+pub fn assert_recorded_answers(year: &Year) {
+    let recorded = year.recorded_answers().unwrap();
+    let mut report = Vec::new();
+
+    for day in year.days {
+        let Some(expected) = recorded.get(&day.day) else {
+            continue;
+        };
+        let input = match year.read_input(day.day) {
+            Ok(input) => input,
+            Err(e @ InputError::NotFound { .. }) => {
+                eprintln!("Skipping {} day {}: {e}", year.year, day.day);
+                continue;
+            }
+            Err(e) => panic!("{e}"),
+        };
+
+        let what = format!("day {}", day.day);
+        match panic::catch_unwind(|| day.run(&input)) {
+            Ok(actual) => {
+                compare(
+                    &mut report,
+                    &what,
+                    1,
+                    expected.part_1.as_ref(),
+                    actual.part_1.as_ref(),
+                );
+                compare(
+                    &mut report,
+                    &what,
+                    2,
+                    expected.part_2.as_ref(),
+                    actual.part_2.as_ref(),
+                );
+            }
+            Err(_) => report.push(format!("{what}: panicked")),
+        }
+    }
+
+    assert!(
+        report.is_empty(),
+        "{} answers don't match {}/answers.txt:\n{}",
+        year.year,
+        year.dir,
+        report.join("\n")
+    );
+}
@@ -0,0 +1,177 @@
+//! Benchmarking each stage of a solution by running it many times, and comparing the results
+//! against a saved baseline.
+//!
+//! A baseline file has a line `<year> <day> <median in ns> <stage>` for each stage benchmarked.
+//! Lines starting with `#` are comments.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    fs, hint, io,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use crate::Day;
+
+/// How long to spend benchmarking each stage.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// How long to run a stage before measuring it, so that caches and branch predictors are warm.
+    pub warmup: Duration,
+    /// How long to keep taking samples for, once there are at least `min_samples`.
+    pub target: Duration,
+    pub min_samples: usize,
+    pub max_samples: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: Duration::from_millis(200),
+            target: Duration::from_secs(1),
+            min_samples: 10,
+            max_samples: 10_000,
+        }
+    }
+}
+
+/// Summary statistics of a stage's run times.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Summarises `samples`, which must not be empty.
+    #[must_use]
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "No samples to summarise");
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let n = sorted.len();
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+
+        let seconds: Vec<f64> = sorted.iter().map(Duration::as_secs_f64).collect();
+        #[allow(clippy::cast_precision_loss)]
+        let count = n as f64;
+        let mean = seconds.iter().sum::<f64>() / count;
+        let variance = seconds.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / count;
+        Self {
+            samples: n,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Runs `f` repeatedly, first to warm up and then timing each run, as set by `config`.
+pub fn bench<T>(config: &BenchConfig, mut f: impl FnMut() -> T) -> Stats {
+    let start = Instant::now();
+    while start.elapsed() < config.warmup {
+        hint::black_box(f());
+    }
+
+    let mut samples = Vec::new();
+    let start = Instant::now();
+    while samples.len() < config.max_samples
+        && (samples.len() < config.min_samples || start.elapsed() < config.target)
+    {
+        let run = Instant::now();
+        let result = hint::black_box(f());
+        samples.push(run.elapsed());
+        drop(result);
+    }
+    Stats::from_samples(&samples)
+}
+
+impl Day {
+    /// Benchmarks parsing `input` and then each part, returning each stage's name and stats.
+    #[must_use]
+    pub fn bench(&self, input: &str, config: &BenchConfig) -> Vec<(&'static str, Stats)> {
+        let mut stages = vec![("Parse", bench(config, || self.parse(input)))];
+        let parsed = self.parse(input);
+        stages.push(("Part 1", bench(config, || self.part_1(&parsed))));
+        if self.has_part_2() {
+            stages.push(("Part 2", bench(config, || self.part_2(&parsed))));
+        }
+        stages
+    }
+}
+
+/// The median time of each stage from an earlier benchmark run.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Baseline(BTreeMap<(u16, u8, String), Duration>);
+
+impl Baseline {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads the baseline at `path`, treating a missing file as an empty baseline.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message naming the file and line if it can't be read or parsed.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::new()),
+            Err(e) => return Err(format!("{}: {e}", path.display())),
+        };
+
+        let mut baseline = Self::new();
+        for (line_number, line) in (1..).zip(text.lines()) {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = || format!("{}: Line {line_number}: invalid entry", path.display());
+            let mut fields = line.splitn(4, ' ');
+            let (Some(year), Some(day), Some(median), Some(stage)) =
+                (fields.next(), fields.next(), fields.next(), fields.next())
+            else {
+                return Err(error());
+            };
+            baseline.insert(
+                year.parse().map_err(|_| error())?,
+                day.parse().map_err(|_| error())?,
+                stage,
+                Duration::from_nanos(median.parse().map_err(|_| error())?),
+            );
+        }
+        Ok(baseline)
+    }
+
+    /// Writes the baseline to `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be written.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut text = String::from("# <year> <day> <median in ns> <stage>, from `aoc bench`\n");
+        for ((year, day, stage), median) in &self.0 {
+            writeln!(text, "{year} {day} {} {stage}", median.as_nanos()).unwrap();
+        }
+        fs::write(path, text)
+    }
+
+    pub fn insert(&mut self, year: u16, day: u8, stage: &str, median: Duration) {
+        self.0.insert((year, day, stage.to_owned()), median);
+    }
+
+    #[must_use]
+    pub fn get(&self, year: u16, day: u8, stage: &str) -> Option<Duration> {
+        self.0.get(&(year, day, stage.to_owned())).copied()
+    }
+}
@@ -0,0 +1,161 @@
+//! Talks to the Advent of Code site on behalf of a logged-in account, identified by its session
+//! cookie.
+
+use std::{
+    env,
+    error::Error,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::Year;
+
+/// The value of the `session` cookie from a logged-in browser.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Overrides the site to talk to, e.g. to point at a local stub server.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = concat!("aoc-runner/", env!("CARGO_PKG_VERSION"));
+
+/// A request to the site that failed.
+#[derive(Debug)]
+pub enum ClientError {
+    /// [`SESSION_VAR`] isn't set.
+    NoSession,
+    /// The request to `url` failed, or the server refused it.
+    Request { url: String, message: String },
+    /// A downloaded input, or the submission history, couldn't be saved to `path`.
+    Save { path: PathBuf, source: io::Error },
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoSession => write!(
+                f,
+                "Set {SESSION_VAR} to the session cookie of a logged-in Advent of Code account"
+            ),
+            Self::Request { url, message } => write!(f, "Request to {url} failed: {message}"),
+            Self::Save { path, source } => {
+                write!(f, "Failed to save {}: {source}", path.display())
+            }
+        }
+    }
+}
+
+impl Error for ClientError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::NoSession | Self::Request { .. } => None,
+            Self::Save { source, .. } => Some(source),
+        }
+    }
+}
+
+/// Where a fetched input ended up.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was downloaded and saved to this path.
+    Downloaded(PathBuf),
+    /// The input was already at this path, so it wasn't downloaded again.
+    Cached(PathBuf),
+}
+
+/// Downloads inputs from, and submits answers to, the site at `base_url`.
+#[derive(Clone, Debug)]
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+}
+
+impl Client {
+    #[must_use]
+    pub fn new(base_url: impl Into<String>, session: Option<String>) -> Self {
+        let base_url = base_url.into().trim_end_matches('/').to_owned();
+        Self { base_url, session }
+    }
+
+    /// Uses [`BASE_URL_VAR`] and [`SESSION_VAR`], falling back to [`DEFAULT_BASE_URL`].
+    #[must_use]
+    pub fn from_env() -> Self {
+        let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_owned());
+        Self::new(base_url, env::var(SESSION_VAR).ok())
+    }
+
+    /// Saves the input for `day` to [`Year::input_path`], unless it's already there. Inputs never
+    /// change, so a saved one is never downloaded again.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no session cookie is set, the download fails or the input can't be
+    /// saved.
+    pub fn fetch(&self, year: &Year, day: u8) -> Result<Fetched, ClientError> {
+        let path = year.input_path(day);
+        if path.exists() {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let input = self.send("GET", &format!("{}/day/{day}/input", year.year), &[])?;
+
+        save(&path, &input).map_err(|source| ClientError::Save {
+            path: path.clone(),
+            source,
+        })?;
+        Ok(Fetched::Downloaded(path))
+    }
+
+    pub(crate) fn url(&self, path: &str) -> String {
+        format!("{}/{path}", self.base_url)
+    }
+
+    /// Sends a request to `path` on the site, with `form` as the body if it isn't empty, and
+    /// returns the response body.
+    pub(crate) fn send(
+        &self,
+        method: &str,
+        path: &str,
+        form: &[(&str, &str)],
+    ) -> Result<String, ClientError> {
+        let session = self.session.as_ref().ok_or(ClientError::NoSession)?;
+        let url = self.url(path);
+        let request_error = |message: String| ClientError::Request {
+            url: url.clone(),
+            message,
+        };
+
+        let request = ureq::request(method, &url)
+            .set("Cookie", &format!("session={session}"))
+            .set("User-Agent", USER_AGENT);
+        let response = if form.is_empty() {
+            request.call()
+        } else {
+            request.send_form(form)
+        };
+        response
+            .map_err(|e| match e {
+                ureq::Error::Status(status, response) => {
+                    request_error(format!("{status} {}", response.status_text()))
+                }
+                ureq::Error::Transport(transport) => request_error(match transport.message() {
+                    Some(message) => format!("{}: {message}", transport.kind()),
+                    None => transport.kind().to_string(),
+                }),
+            })?
+            .into_string()
+            .map_err(|e| request_error(e.to_string()))
+    }
+}
+
+/// Writes to a temporary file first, so that an interrupted download doesn't leave a truncated
+/// input behind to be mistaken for a cached one.
+pub(crate) fn save(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let partial = path.with_extension("txt.partial");
+    fs::write(&partial, contents)?;
+    fs::rename(&partial, path)
+}
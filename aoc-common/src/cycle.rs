@@ -0,0 +1,148 @@
+//! Finding where a simulation starts repeating itself, so that it can be skipped ahead to a step
+//! far beyond what could be simulated one step at a time.
+//!
+//! A simulation is a state and a step function. States are compared by a fingerprint, which can
+//! be the whole state or just the part of it that decides what happens next, as long as states
+//! with the same fingerprint go on to have the same fingerprints as each other.
+
+use std::{collections::HashMap, hash::Hash};
+
+/// Where a sequence of states repeats: the state after `start + length` steps has the same
+/// fingerprint as the state after `start` steps, and so on forever after.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    /// How many steps come before the first pass through the cycle.
+    pub start: usize,
+    /// How many steps it takes to go round the cycle once.
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The first step with the same state as step `n`, which is before `start + length`.
+    #[must_use]
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+
+    /// How much a quantity derived from the state changes each time round the cycle, given its
+    /// `value` after each step up to `start + length`.
+    pub fn delta(&self, mut value: impl FnMut(usize) -> i64) -> i64 {
+        value(self.start + self.length) - value(self.start)
+    }
+
+    /// The value after step `n` of a quantity that changes by the same amount each time round
+    /// the cycle, such as the height of a tower that grows as the simulation runs. `value` gives
+    /// it after each step up to `start + length`.
+    pub fn extrapolate(&self, n: usize, mut value: impl FnMut(usize) -> i64) -> i64 {
+        if n < self.start {
+            return value(n);
+        }
+        let laps = i64::try_from((n - self.start) / self.length).unwrap();
+        value(self.equivalent_step(n)) + laps * self.delta(value)
+    }
+}
+
+/// Runs `step` on `state` until it reaches a fingerprint it has had before, and returns the
+/// cycle along with the state, which is left `start + length` steps in.
+///
+/// This remembers every fingerprint, so it finds the cycle as soon as it's been round once. `step`
+/// runs exactly once per step, so it can record whatever it needs to along the way.
+pub fn find_cycle<S, K>(
+    mut state: S,
+    mut step: impl FnMut(&mut S),
+    mut fingerprint: impl FnMut(&S) -> K,
+) -> (Cycle, S)
+where
+    K: Eq + Hash,
+{
+    let mut seen = HashMap::new();
+    for steps in 0.. {
+        if let Some(start) = seen.insert(fingerprint(&state), steps) {
+            let cycle = Cycle {
+                start,
+                length: steps - start,
+            };
+            return (cycle, state);
+        }
+        step(&mut state);
+    }
+    unreachable!()
+}
+
+/// Finds the cycle with Brent's algorithm, which only ever keeps two states and compares their
+/// fingerprints, at the cost of running up to about three times as many steps as [`find_cycle`].
+/// Because it runs steps more than once, `step` shouldn't have side effects.
+pub fn brent<S, K>(
+    initial: S,
+    mut step: impl FnMut(&mut S),
+    mut fingerprint: impl FnMut(&S) -> K,
+) -> Cycle
+where
+    S: Clone,
+    K: Eq,
+{
+    // Find the length by moving the tortoise up to the hare at each power of two, until the
+    // hare catches it up within the next power of two steps.
+    let mut tortoise = fingerprint(&initial);
+    let mut hare = initial.clone();
+    step(&mut hare);
+    let (mut power, mut length) = (1, 1);
+    while tortoise != fingerprint(&hare) {
+        if power == length {
+            tortoise = fingerprint(&hare);
+            power *= 2;
+            length = 0;
+        }
+        step(&mut hare);
+        length += 1;
+    }
+
+    // Then find the start by walking two states a length apart until they meet.
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        step(&mut hare);
+    }
+    let mut start = 0;
+    while fingerprint(&tortoise) != fingerprint(&hare) {
+        step(&mut tortoise);
+        step(&mut hare);
+        start += 1;
+    }
+    Cycle { start, length }
+}
+
+/// Runs `step` on `state` `n` times, or as many fewer as skipping whole cycles allows. The state
+/// returned has the same fingerprint as it would have after `n` steps.
+pub fn simulate<S, K>(
+    state: S,
+    n: usize,
+    mut step: impl FnMut(&mut S),
+    fingerprint: impl FnMut(&S) -> K,
+) -> S
+where
+    K: Eq + Hash,
+{
+    // Once `n` steps have run, stepping stops, so the next fingerprint repeats straight away.
+    let mut steps = 0;
+    let (cycle, mut state) = find_cycle(
+        state,
+        |state| {
+            if steps < n {
+                step(state);
+                steps += 1;
+            }
+        },
+        fingerprint,
+    );
+    if steps < n {
+        for _ in 0..(n - steps) % cycle.length {
+            step(&mut state);
+        }
+    }
+    state
+}
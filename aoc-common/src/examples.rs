@@ -0,0 +1,169 @@
+//! Worked examples from the puzzle statements, kept in a year's `examples/` so that every
+//! solution can be checked without the personal puzzle inputs.
+//!
+//! Each example is a file named `dayN.txt`, or `dayN-M.txt` when a day has several. It starts with
+//! a header of `name: value` lines, ended by a line holding just `---`, and the rest of the file is
+//! the example input exactly as given. `part 1` and `part 2` give the expected answers, escaped the
+//! same way as in `answers.txt`; a part without one isn't run. Any other name sets a parameter
+//! passed to [`Solution::parse_with`](crate::Solution::parse_with). Header lines starting with `#`
+//! are comments.
+
+use std::{fs, io, panic, path::Path};
+
+use crate::{
+    answers::{compare, unescape},
+    Answers, Params, Year,
+};
+
+/// A worked example along with its expected answers.
+#[derive(Clone, Debug)]
+pub struct Example {
+    /// The file name the example was read from, without its extension.
+    pub name: String,
+    pub day: u8,
+    pub params: Params,
+    pub answers: Answers,
+    pub input: String,
+}
+
+/// Parses the contents of an example file named `name`.
+///
+/// # Errors
+///
+/// Returns a message naming the offending line if the header isn't valid.
+pub fn parse_example(name: &str, text: &str) -> Result<Example, String> {
+    let day = name
+        .strip_prefix("day")
+        .and_then(|rest| rest.split('-').next())
+        .and_then(|day| day.parse().ok())
+        .ok_or_else(|| format!("Expected a name like day1 or day1-2, not {name:?}"))?;
+    let mut params = Params::new();
+    let mut answers = Answers::default();
+
+    let mut rest = text;
+    for line_number in 1.. {
+        let Some((line, remaining)) = rest.split_once('\n') else {
+            return Err("Expected a `---` line ending the header".to_string());
+        };
+        rest = remaining;
+        let line = line.trim_end_matches('\r');
+        if line == "---" {
+            break;
+        }
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let error = |message: &str| format!("Line {line_number}: {message}");
+        let Some((key, value)) = line.split_once(": ") else {
+            return Err(error("expected `<name>: <value>`"));
+        };
+        let slot = match key {
+            "part 1" => &mut answers.part_1,
+            "part 2" => &mut answers.part_2,
+            _ => {
+                params.insert(key, value);
+                continue;
+            }
+        };
+        let answer = unescape(value).map_err(|e| error(&e))?;
+        if slot.replace(answer).is_some() {
+            return Err(error(&format!("{key} is given twice")));
+        }
+    }
+
+    Ok(Example {
+        name: name.to_string(),
+        day,
+        params,
+        answers,
+        input: rest.to_string(),
+    })
+}
+
+impl Year {
+    /// Reads every example in this year's `examples/`, ordered by day. A missing directory means
+    /// there are no examples.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message naming the file if one can't be read or parsed.
+    pub fn examples(&self) -> Result<Vec<Example>, String> {
+        let dir = Path::new(self.dir).join("examples");
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(format!("{}: {e}", dir.display())),
+        };
+
+        let mut examples = Vec::new();
+        for entry in entries {
+            let path = entry.map_err(|e| format!("{}: {e}", dir.display()))?.path();
+            if path.extension().is_none_or(|extension| extension != "txt") {
+                continue;
+            }
+            let error = |e: &dyn ToString| format!("{}: {}", path.display(), e.to_string());
+            let text = fs::read_to_string(&path).map_err(|e| error(&e))?;
+            let name = path.file_stem().unwrap_or_default().to_string_lossy();
+            examples.push(parse_example(&name, &text).map_err(|e| error(&e))?);
+        }
+        examples.sort_by(|a, b| (a.day, &a.name).cmp(&(b.day, &b.name)));
+        Ok(examples)
+    }
+}
+
+/// Runs every example in the year's `examples/` against its day's solution, panicking with a
+/// report of every answer that doesn't match. Only the parts an example gives an answer for are
+/// run, since some examples only make sense for one part.
+pub fn assert_examples(year: &Year) {
+    let examples = year.examples().unwrap();
+    let mut report = Vec::new();
+
+    for example in &examples {
+        let Some(day) = year.day(example.day) else {
+            report.push(format!(
+                "{}: day {} isn't registered",
+                example.name, example.day
+            ));
+            continue;
+        };
+
+        let run = || {
+            let input = day.parse_with(&example.input, &example.params);
+            Answers {
+                part_1: example.answers.part_1.as_ref().map(|_| day.part_1(&input)),
+                part_2: example
+                    .answers
+                    .part_2
+                    .as_ref()
+                    .and_then(|_| day.part_2(&input)),
+            }
+        };
+        match panic::catch_unwind(run) {
+            Ok(actual) => {
+                compare(
+                    &mut report,
+                    &example.name,
+                    1,
+                    example.answers.part_1.as_ref(),
+                    actual.part_1.as_ref(),
+                );
+                compare(
+                    &mut report,
+                    &example.name,
+                    2,
+                    example.answers.part_2.as_ref(),
+                    actual.part_2.as_ref(),
+                );
+            }
+            Err(_) => report.push(format!("{}: panicked", example.name)),
+        }
+    }
+
+    assert!(
+        report.is_empty(),
+        "{} examples don't match their answers in {}/examples:\n{}",
+        year.year,
+        year.dir,
+        report.join("\n")
+    );
+}
@@ -0,0 +1,366 @@
+//! Points, vectors and compass directions on integer grids.
+//!
+//! `y` grows downwards, as it does down the lines of a puzzle's input, so [`Direction::Up`] is
+//! `(0, -1)` and turning right takes `Up` to `Right`. Days whose puzzle has `y` growing upwards
+//! can use [`Direction::Down`] for up.
+
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// A point or vector in two dimensions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// A point or vector in three dimensions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point2 {
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    #[must_use]
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// The distance to `other` moving only along the axes.
+    #[must_use]
+    pub fn manhattan(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The distance to `other` moving along the axes or diagonally, like a king in chess.
+    #[must_use]
+    pub fn chebyshev(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// The vector with each component replaced by its sign, which is the step a king would take
+    /// towards it.
+    #[must_use]
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    /// The vector turned a quarter turn clockwise, as seen with `y` growing downwards.
+    #[must_use]
+    pub fn rotate_right(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// The vector turned a quarter turn anticlockwise, as seen with `y` growing downwards.
+    #[must_use]
+    pub fn rotate_left(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    /// The four points next to this one along the axes.
+    #[must_use]
+    pub fn neighbours4(self) -> [Self; 4] {
+        Direction::ALL.map(|direction| self + direction)
+    }
+
+    /// The eight points around this one, including diagonally.
+    #[must_use]
+    pub fn neighbours8(self) -> [Self; 8] {
+        Direction8::ALL.map(|direction| self + direction.step())
+    }
+}
+
+impl Point3 {
+    pub const ORIGIN: Self = Self::new(0, 0, 0);
+
+    #[must_use]
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    #[must_use]
+    pub fn manhattan(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    #[must_use]
+    pub fn chebyshev(self, other: Self) -> u64 {
+        self.x
+            .abs_diff(other.x)
+            .max(self.y.abs_diff(other.y))
+            .max(self.z.abs_diff(other.z))
+    }
+
+    #[must_use]
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    /// The six points sharing a face with this one.
+    #[must_use]
+    pub fn neighbours6(self) -> [Self; 6] {
+        [
+            Self::new(1, 0, 0),
+            Self::new(-1, 0, 0),
+            Self::new(0, 1, 0),
+            Self::new(0, -1, 0),
+            Self::new(0, 0, 1),
+            Self::new(0, 0, -1),
+        ]
+        .map(|step| self + step)
+    }
+}
+
+macro_rules! impl_ops {
+    ($point:ident { $($axis:ident),+ }) => {
+        impl Add for $point {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($axis: self.$axis + other.$axis),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($axis: self.$axis - other.$axis),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($axis: -self.$axis),+ }
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = Self;
+
+            fn mul(self, scale: i64) -> Self {
+                Self { $($axis: self.$axis * scale),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+impl_ops!(Point2 { x, y });
+impl_ops!(Point3 { x, y, z });
+
+impl From<(i64, i64)> for Point2 {
+    fn from((x, y): (i64, i64)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl From<(i64, i64, i64)> for Point3 {
+    fn from((x, y, z): (i64, i64, i64)) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+impl fmt::Display for Point2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl fmt::Display for Point3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+/// One of the four directions along the axes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise from up.
+    pub const ALL: [Self; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        self.opposite().turn_right()
+    }
+
+    #[must_use]
+    pub fn opposite(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    #[must_use]
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+
+    /// The vector one step in this direction.
+    #[must_use]
+    pub fn step(self) -> Point2 {
+        match self {
+            Direction::Up => Point2::new(0, -1),
+            Direction::Right => Point2::new(1, 0),
+            Direction::Down => Point2::new(0, 1),
+            Direction::Left => Point2::new(-1, 0),
+        }
+    }
+}
+
+/// Parses the arrows `^>v<` and the letters `URDL`, as puzzles write directions.
+impl TryFrom<char> for Direction {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, char> {
+        match c {
+            '^' | 'U' => Ok(Direction::Up),
+            '>' | 'R' => Ok(Direction::Right),
+            'v' | 'D' => Ok(Direction::Down),
+            '<' | 'L' => Ok(Direction::Left),
+            c => Err(c),
+        }
+    }
+}
+
+impl Add<Direction> for Point2 {
+    type Output = Point2;
+
+    fn add(self, direction: Direction) -> Point2 {
+        self + direction.step()
+    }
+}
+
+impl AddAssign<Direction> for Point2 {
+    fn add_assign(&mut self, direction: Direction) {
+        *self = *self + direction;
+    }
+}
+
+/// The step in `direction`, as [`Grid`](crate::Grid) offsets positions by.
+impl From<Direction> for (isize, isize) {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => (0, -1),
+            Direction::Right => (1, 0),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+        }
+    }
+}
+
+/// One of the eight compass directions, including the diagonals.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction8 {
+    /// Clockwise from north.
+    pub const ALL: [Self; 8] = [
+        Direction8::N,
+        Direction8::NE,
+        Direction8::E,
+        Direction8::SE,
+        Direction8::S,
+        Direction8::SW,
+        Direction8::W,
+        Direction8::NW,
+    ];
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|&d| d == self).unwrap()
+    }
+
+    /// The direction an eighth of a turn clockwise.
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self.index() + 1) % 8]
+    }
+
+    /// The direction an eighth of a turn anticlockwise.
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self.index() + 7) % 8]
+    }
+
+    #[must_use]
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self.index() + 4) % 8]
+    }
+
+    #[must_use]
+    pub fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+
+    /// The vector one step in this direction.
+    #[must_use]
+    pub fn step(self) -> Point2 {
+        let (x, y) = self.into();
+        Point2::new(x as i64, y as i64)
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Direction8::N,
+            Direction::Right => Direction8::E,
+            Direction::Down => Direction8::S,
+            Direction::Left => Direction8::W,
+        }
+    }
+}
+
+impl From<Direction8> for (isize, isize) {
+    fn from(direction: Direction8) -> Self {
+        crate::grid::ALL_DIRECTIONS[direction.index()]
+    }
+}
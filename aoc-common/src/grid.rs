@@ -0,0 +1,311 @@
+//! A rectangular grid of cells, as most puzzles with a map in their input need.
+//!
+//! Positions are `(x, y)` pairs, with `x` counting columns from the left and `y` counting rows
+//! from the top, so that a grid displays the same way round as the text it was parsed from.
+
+use std::{
+    error::Error,
+    fmt,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+pub type Pos = (usize, usize);
+
+/// The steps to the four orthogonal neighbours of a cell: up, right, down and left.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The steps to all eight neighbours of a cell, clockwise from up.
+pub const ALL_DIRECTIONS: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    /// The cells, row by row.
+    cells: Vec<T>,
+}
+
+/// Why text couldn't be parsed as a grid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GridError {
+    /// A line is a different length to the first one.
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// A character doesn't map to a cell.
+    InvalidChar { line: usize, column: usize, c: char },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::Ragged {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Line {line}: expected {expected} characters, found {found}"
+            ),
+            GridError::InvalidChar { line, column, c } => {
+                write!(f, "Line {line}: invalid character {c:?} in column {column}")
+            }
+        }
+    }
+}
+
+impl Error for GridError {}
+
+impl<T> Grid<T> {
+    #[must_use]
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// A grid whose cells are `f` of their positions.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a grid with a line of text per row, mapping each character to a cell with `f`.
+    /// Empty lines at the end are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the lines aren't all the same length, or if `f` returns `None` for
+    /// a character.
+    pub fn parse(text: &str, mut f: impl FnMut(char) -> Option<T>) -> Result<Self, GridError> {
+        let lines: Vec<&str> = text.trim_end_matches('\n').lines().collect();
+        let width = lines.first().map_or(0, |line| line.chars().count());
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (line_number, line) in (1..).zip(&lines) {
+            let start = cells.len();
+            for (column, c) in (1..).zip(line.chars()) {
+                cells.push(f(c).ok_or(GridError::InvalidChar {
+                    line: line_number,
+                    column,
+                    c,
+                })?);
+            }
+            if cells.len() - start != width {
+                return Err(GridError::Ragged {
+                    line: line_number,
+                    expected: width,
+                    found: cells.len() - start,
+                });
+            }
+        }
+        Ok(Self {
+            width,
+            height: lines.len(),
+            cells,
+        })
+    }
+
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    #[must_use]
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    #[must_use]
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.1 * self.width + pos.0])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.1 * self.width + pos.0])
+        } else {
+            None
+        }
+    }
+
+    /// The position `step` away from `pos`, if it's in the grid. `step` can be a
+    /// [`Direction`](crate::Direction) or [`Direction8`](crate::Direction8) as well as an offset.
+    #[must_use]
+    pub fn offset(&self, (x, y): Pos, step: impl Into<(isize, isize)>) -> Option<Pos> {
+        let (dx, dy) = step.into();
+        let pos = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every position with its cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The position of the first cell, row by row, that matches `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find_map(|(pos, cell)| predicate(cell).then_some(pos))
+    }
+
+    /// The positions of the up to four cells above, below and beside `pos`.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |step| self.offset(pos, step))
+    }
+
+    /// The positions of the up to eight cells around `pos`, including diagonally.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ALL_DIRECTIONS
+            .into_iter()
+            .filter_map(move |step| self.offset(pos, step))
+    }
+
+    /// The positions reached by repeatedly taking `step` from `pos`, not including `pos`, until
+    /// the edge of the grid. This walks along a row, column or diagonal.
+    pub fn ray(&self, pos: Pos, step: impl Into<(isize, isize)>) -> impl Iterator<Item = Pos> + '_ {
+        let step = step.into();
+        std::iter::successors(self.offset(pos, step), move |&pos| self.offset(pos, step))
+    }
+
+    #[must_use]
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a chunk size of zero, which a grid with no columns would give.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Panics if `x` is out of bounds, as [`Grid::row`] does for `y`, rather than yielding part
+    /// of a later row.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {x} is out of bounds");
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    /// A grid of `f` of each cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// The grid flipped over its leading diagonal, so that rows become columns.
+    #[must_use]
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    /// The grid turned a quarter turn clockwise.
+    #[must_use]
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.height, self.width, |(x, y)| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    /// The grid turned a quarter turn anticlockwise.
+    #[must_use]
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.height, self.width, |(x, y)| {
+            self[(self.width - 1 - y, x)].clone()
+        })
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "Position {pos:?} is outside a {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("Position {pos:?} is outside a {width}x{height} grid"))
+    }
+}
+
+/// Parses cells with their `TryFrom<char>` implementation.
+impl<T: TryFrom<char>> FromStr for Grid<T> {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, |c| T::try_from(c).ok())
+    }
+}
+
+/// Shows a line of characters per row, as the grid would be parsed from.
+impl<T: Clone + Into<char>> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            let line: String = row.iter().cloned().map(Into::into).collect();
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.rows()).finish()
+    }
+}
@@ -0,0 +1,106 @@
+use std::{
+    env,
+    error::Error,
+    fmt, fs,
+    io::{self, Read},
+    path::{self, Path, PathBuf},
+};
+
+use crate::Year;
+
+/// Overrides where puzzle inputs are read from. Each year's inputs are read from a subdirectory
+/// named after the year, e.g. `$AOC_INPUT_DIR/2022/day1.txt`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// A puzzle input that couldn't be read.
+#[derive(Debug)]
+pub enum InputError {
+    /// There is no input file at `path`.
+    NotFound { path: PathBuf },
+    /// The input file at `path` exists but couldn't be read.
+    Unreadable { path: PathBuf, source: io::Error },
+    /// The input couldn't be read from stdin.
+    Stdin(io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound { path } => write!(f, "Expected an input file at {}", path.display()),
+            Self::Unreadable { path, source } => {
+                write!(f, "Failed to read input file {}: {source}", path.display())
+            }
+            Self::Stdin(source) => write!(f, "Failed to read input from stdin: {source}"),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::NotFound { .. } => None,
+            Self::Unreadable { source, .. } | Self::Stdin(source) => Some(source),
+        }
+    }
+}
+
+/// Somewhere to read a puzzle input from other than the year's input directory.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Reads the whole input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the file's absolute path if it's missing or can't be read.
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            Self::File(path) => read_file(path),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(InputError::Stdin)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    let path = path::absolute(path).unwrap_or_else(|_| path.to_owned());
+    fs::read_to_string(&path).map_err(|source| match source.kind() {
+        io::ErrorKind::NotFound => InputError::NotFound { path },
+        _ => InputError::Unreadable { path, source },
+    })
+}
+
+impl Year {
+    /// The directory this year's `dayN.txt` inputs are read from: a subdirectory of
+    /// [`INPUT_DIR_VAR`] if it's set, or else `input/` in the year's crate.
+    #[must_use]
+    pub fn input_dir(&self) -> PathBuf {
+        match env::var_os(INPUT_DIR_VAR) {
+            Some(dir) => Path::new(&dir).join(self.year.to_string()),
+            None => Path::new(self.dir).join("input"),
+        }
+    }
+
+    #[must_use]
+    pub fn input_path(&self, day: u8) -> PathBuf {
+        self.input_dir().join(format!("day{day}.txt"))
+    }
+
+    /// Reads the input for `day` from [`Year::input_dir`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the file's absolute path if it's missing or can't be read.
+    pub fn read_input(&self, day: u8) -> Result<String, InputError> {
+        read_file(&self.input_path(day))
+    }
+}
@@ -0,0 +1,41 @@
+mod answers;
+mod bench;
+#[cfg(feature = "client")]
+mod client;
+mod cycle;
+mod examples;
+mod geometry;
+mod grid;
+mod input;
+mod math;
+mod params;
+mod registry;
+mod search;
+mod solution;
+#[cfg(feature = "client")]
+mod submit;
+mod timer;
+
+pub use answers::{assert_recorded_answers, parse_answers};
+pub use bench::{bench, Baseline, BenchConfig, Stats};
+#[cfg(feature = "client")]
+pub use client::{Client, ClientError, Fetched, BASE_URL_VAR, DEFAULT_BASE_URL, SESSION_VAR};
+pub use cycle::{brent, find_cycle, simulate, Cycle};
+pub use examples::{assert_examples, parse_example, Example};
+pub use geometry::{Direction, Direction8, Point2, Point3};
+pub use grid::{Grid, GridError, Pos, ALL_DIRECTIONS, ORTHOGONAL};
+pub use input::{InputError, InputSource, INPUT_DIR_VAR};
+pub use math::{
+    crt, extended_gcd, gcd, gcd_all, lcm, lcm_all, mod_inverse, mod_pow, solve_linear,
+    solve_linear_integer, Integer, Ratio,
+};
+pub use params::Params;
+pub use registry::{Answers, Day, Parsed, Year};
+pub use search::{
+    all_shortest_paths, astar, astar_by_key, bfs, dijkstra, dijkstra_by_key, Cost, Path,
+    ShortestPaths,
+};
+pub use solution::Solution;
+#[cfg(feature = "client")]
+pub use submit::{History, Refusal, Response, Verdict};
+pub use timer::{add_timings, collect_timings, PerfTimer, Timing, TimingReport};
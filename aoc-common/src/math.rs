@@ -0,0 +1,280 @@
+//! Integer maths that puzzles keep coming back to: common factors and multiples, modular
+//! arithmetic, and solving small systems of linear equations exactly.
+//!
+//! The modular functions work in `i128`, so that products of two residues can't overflow for any
+//! modulus up to `i64::MAX`.
+
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+/// What [`gcd`] and [`lcm`] work on: any of the primitive integer types.
+pub trait Integer:
+    Copy
+    + Ord
+    + Default
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+}
+
+impl<T> Integer for T where
+    T: Copy
+        + Ord
+        + Default
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Rem<Output = T>
+{
+}
+
+/// The greatest common divisor of two non-negative numbers, which is zero only if both are.
+pub fn gcd<T: Integer>(mut a: T, mut b: T) -> T {
+    while b != T::default() {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple of two non-negative numbers, which is zero if either is.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::default() || b == T::default() {
+        return T::default();
+    }
+    a / gcd(a, b) * b
+}
+
+/// The greatest common divisor of all of `numbers`, or `None` if there aren't any.
+pub fn gcd_all<T: Integer>(numbers: impl IntoIterator<Item = T>) -> Option<T> {
+    numbers.into_iter().reduce(gcd)
+}
+
+/// The least common multiple of all of `numbers`, or `None` if there aren't any. This is how
+/// long it takes several cycles of these lengths that start together to line up again.
+pub fn lcm_all<T: Integer>(numbers: impl IntoIterator<Item = T>) -> Option<T> {
+    numbers.into_iter().reduce(lcm)
+}
+
+/// The greatest common divisor `g` of `a` and `b`, with `x` and `y` such that `a * x + b * y = g`,
+/// as `(g, x, y)`. `g` is never negative.
+#[must_use]
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    // Each pair is the previous and current remainder, or coefficient of `a` or `b`.
+    let mut remainders = (a, b);
+    let mut xs = (1, 0);
+    let mut ys = (0, 1);
+    while remainders.1 != 0 {
+        let quotient = remainders.0 / remainders.1;
+        remainders = (remainders.1, remainders.0 - quotient * remainders.1);
+        xs = (xs.1, xs.0 - quotient * xs.1);
+        ys = (ys.1, ys.0 - quotient * ys.1);
+    }
+    let sign = if remainders.0 < 0 { -1 } else { 1 };
+    (sign * remainders.0, sign * xs.0, sign * ys.0)
+}
+
+/// `base` to the power `exponent`, modulo `modulus`, in the range `0..modulus`.
+#[must_use]
+pub fn mod_pow(base: i128, mut exponent: u64, modulus: i128) -> i128 {
+    let mut base = base.rem_euclid(modulus);
+    let mut result = 1 % modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+    result
+}
+
+/// The number in `0..modulus` that `a` multiplies to 1 modulo `modulus`, if there is one, which
+/// there is exactly when `a` and `modulus` have no common factor.
+#[must_use]
+pub fn mod_inverse(a: i128, modulus: i128) -> Option<i128> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// Solves a system of congruences `x = residue (mod modulus)` with the Chinese Remainder Theorem,
+/// returning the smallest non-negative solution and the modulus that all solutions are congruent
+/// under, as `(x, modulus)`. The moduli needn't be coprime, in which case there might not be a
+/// solution.
+pub fn crt(congruences: impl IntoIterator<Item = (i128, i128)>) -> Option<(i128, i128)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(x, modulus), (residue, other)| {
+            let (g, p, _) = extended_gcd(modulus, other);
+            let difference = residue - x;
+            if difference % g != 0 {
+                return None;
+            }
+            let combined = modulus / g * other;
+            // `x + modulus * k` where `modulus * k = difference (mod other)`.
+            let k = (difference / g % (other / g) * p).rem_euclid(other / g);
+            Some(((x + modulus * k).rem_euclid(combined), combined))
+        })
+}
+
+/// A fraction, always kept in lowest terms with a positive denominator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Ratio {
+    numer: i128,
+    denom: i128,
+}
+
+impl Ratio {
+    pub const ZERO: Self = Self::from_integer(0);
+    pub const ONE: Self = Self::from_integer(1);
+
+    #[must_use]
+    pub fn new(numer: i128, denom: i128) -> Self {
+        assert!(denom != 0, "Ratio with a zero denominator");
+        let g = gcd(numer.abs(), denom.abs()) * denom.signum();
+        Self {
+            numer: numer / g,
+            denom: denom / g,
+        }
+    }
+
+    #[must_use]
+    pub const fn from_integer(n: i128) -> Self {
+        Self { numer: n, denom: 1 }
+    }
+
+    #[must_use]
+    pub fn numer(self) -> i128 {
+        self.numer
+    }
+
+    #[must_use]
+    pub fn denom(self) -> i128 {
+        self.denom
+    }
+
+    /// The whole number this is, if it is one.
+    #[must_use]
+    pub fn to_integer(self) -> Option<i128> {
+        (self.denom == 1).then_some(self.numer)
+    }
+}
+
+impl From<i128> for Ratio {
+    fn from(n: i128) -> Self {
+        Self::from_integer(n)
+    }
+}
+
+impl Add for Ratio {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(
+            self.numer * other.denom + other.numer * self.denom,
+            self.denom * other.denom,
+        )
+    }
+}
+
+impl Sub for Ratio {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl Neg for Ratio {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            numer: -self.numer,
+            denom: self.denom,
+        }
+    }
+}
+
+impl Mul for Ratio {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::new(self.numer * other.numer, self.denom * other.denom)
+    }
+}
+
+impl Div for Ratio {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        Self::new(self.numer * other.denom, self.denom * other.numer)
+    }
+}
+
+impl PartialOrd for Ratio {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Ratio {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.numer * other.denom).cmp(&(other.numer * self.denom))
+    }
+}
+
+impl fmt::Display for Ratio {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.denom == 1 {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+/// Solves the system of linear equations `matrix * x = rhs` exactly, by Gaussian elimination.
+/// Returns `None` if the matrix is singular, so that there isn't exactly one solution.
+#[must_use]
+pub fn solve_linear<const N: usize>(matrix: [[i128; N]; N], rhs: [i128; N]) -> Option<[Ratio; N]> {
+    let mut rows: [[Ratio; N]; N] = matrix.map(|row| row.map(Ratio::from));
+    let mut rhs = rhs.map(Ratio::from);
+    for column in 0..N {
+        let pivot = (column..N).find(|&row| rows[row][column] != Ratio::ZERO)?;
+        rows.swap(column, pivot);
+        rhs.swap(column, pivot);
+        for row in 0..N {
+            if row != column && rows[row][column] != Ratio::ZERO {
+                let pivot_row = rows[column];
+                let factor = rows[row][column] / pivot_row[column];
+                for (cell, &pivot_cell) in rows[row].iter_mut().zip(&pivot_row).skip(column) {
+                    *cell = *cell - factor * pivot_cell;
+                }
+                rhs[row] = rhs[row] - factor * rhs[column];
+            }
+        }
+    }
+    Some(std::array::from_fn(|i| rhs[i] / rows[i][i]))
+}
+
+/// Solves `matrix * x = rhs` like [`solve_linear`], but only if the solution is all whole
+/// numbers, as it has to be when counting things like button presses.
+#[must_use]
+pub fn solve_linear_integer<const N: usize>(
+    matrix: [[i128; N]; N],
+    rhs: [i128; N],
+) -> Option<[i128; N]> {
+    let solution = solve_linear(matrix, rhs)?;
+    let mut integers = [0; N];
+    for (integer, ratio) in integers.iter_mut().zip(solution) {
+        *integer = ratio.to_integer()?;
+    }
+    Some(integers)
+}
@@ -0,0 +1,34 @@
+use std::{collections::BTreeMap, fmt::Debug, str::FromStr};
+
+/// Named puzzle parameters that a worked example sets differently from the real puzzle, such as
+/// the size of a grid or a number of steps. Solutions read each one with the real puzzle's value
+/// as the default.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Params(BTreeMap<String, String>);
+
+impl Params {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.0.insert(name.into(), value.into());
+    }
+
+    /// Returns the value of `name`, or `default` if it isn't set. Panics if it is set to
+    /// something that doesn't parse as a `T`.
+    #[must_use]
+    pub fn get<T>(&self, name: &str, default: T) -> T
+    where
+        T: FromStr,
+        T::Err: Debug,
+    {
+        match self.0.get(name) {
+            Some(value) => value
+                .parse()
+                .unwrap_or_else(|e| panic!("Invalid value {value:?} for {name}: {e:?}")),
+            None => default,
+        }
+    }
+}
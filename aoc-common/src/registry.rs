@@ -0,0 +1,112 @@
+use std::any::Any;
+
+use crate::{Params, Solution};
+
+/// The answers produced by running a day's solution.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+/// A parsed puzzle input, as produced by [`Day::parse`].
+pub type Parsed = Box<dyn Any>;
+
+/// A single registered puzzle solution, with its stages type-erased so that every day can be
+/// driven the same way.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    parse: fn(&str, &Params) -> Parsed,
+    part_1: fn(&Parsed) -> String,
+    part_2: Option<fn(&Parsed) -> String>,
+}
+
+fn parse<S: Solution>(input: &str, params: &Params) -> Parsed {
+    Box::new(S::parse_with(input, params))
+}
+
+fn part_1<S: Solution>(input: &Parsed) -> String {
+    let input = input
+        .downcast_ref()
+        .expect("Input was parsed by another day");
+    let answer = S::part_1(input);
+    answer.to_string()
+}
+
+fn part_2<S: Solution>(input: &Parsed) -> String {
+    let input = input
+        .downcast_ref()
+        .expect("Input was parsed by another day");
+    let answer = S::part_2(input);
+    answer.to_string()
+}
+
+impl Day {
+    #[must_use]
+    pub const fn new<S: Solution>(day: u8) -> Self {
+        Self {
+            day,
+            parse: parse::<S>,
+            part_1: part_1::<S>,
+            part_2: if S::HAS_PART_2 {
+                Some(part_2::<S>)
+            } else {
+                None
+            },
+        }
+    }
+
+    #[must_use]
+    pub fn parse(&self, input: &str) -> Parsed {
+        self.parse_with(input, &Params::default())
+    }
+
+    #[must_use]
+    pub fn parse_with(&self, input: &str, params: &Params) -> Parsed {
+        (self.parse)(input, params)
+    }
+
+    /// Panics if `input` was not produced by this day's [`Day::parse`].
+    #[must_use]
+    pub fn part_1(&self, input: &Parsed) -> String {
+        (self.part_1)(input)
+    }
+
+    /// Returns `None` if the puzzle has no second part. Panics if `input` was not produced by
+    /// this day's [`Day::parse`].
+    #[must_use]
+    pub fn part_2(&self, input: &Parsed) -> Option<String> {
+        self.part_2.map(|part_2| part_2(input))
+    }
+
+    #[must_use]
+    pub fn has_part_2(&self) -> bool {
+        self.part_2.is_some()
+    }
+
+    /// Runs every stage in turn.
+    #[must_use]
+    pub fn run(&self, input: &str) -> Answers {
+        let input = self.parse(input);
+        Answers {
+            part_1: Some(self.part_1(&input)),
+            part_2: self.part_2(&input),
+        }
+    }
+}
+
+/// Every registered solution for one year, along with the crate directory its `input/` lives in.
+#[derive(Clone, Copy)]
+pub struct Year {
+    pub year: u16,
+    pub dir: &'static str,
+    pub days: &'static [Day],
+}
+
+impl Year {
+    #[must_use]
+    pub fn day(&self, day: u8) -> Option<&Day> {
+        self.days.iter().find(|d| d.day == day)
+    }
+}
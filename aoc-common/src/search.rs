@@ -0,0 +1,384 @@
+//! Shortest path searches over implicit graphs.
+//!
+//! A graph is given by a function from a state to its successors, so states can be anything from
+//! a grid position to a whole puzzle configuration. Searches start from any number of states and
+//! stop at the first state that satisfies a goal predicate. Each state is expanded at most once,
+//! keyed by the state itself or, with the `_by_key` variants, by a key derived from it. That lets
+//! a state carry data that doesn't affect where it can go next without defeating the dedup.
+//!
+//! The weighted searches need every edge cost to be positive, which is what lets them stop as
+//! soon as they reach a goal.
+
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// What edge costs can be: anything that adds up and compares, with `Default` as zero.
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<T: Copy + Ord + Default + Add<Output = T>> Cost for T {}
+
+/// A path found by a search.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<S, C> {
+    /// The total cost of the edges along the path.
+    pub cost: C,
+    /// The states along the path, from a start to a goal.
+    pub states: Vec<S>,
+}
+
+/// Every shortest path to the goals, as found by [`all_shortest_paths`].
+#[derive(Clone, Debug)]
+pub struct ShortestPaths<S, C> {
+    cost: C,
+    goals: Vec<S>,
+    /// The cheapest cost of each state reached, and the states it can be reached from at that
+    /// cost.
+    nodes: HashMap<S, (C, Vec<S>)>,
+}
+
+/// Finds the path to a goal with the fewest steps, by breadth first search.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut parents: HashMap<S, Option<S>> = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = parents.entry(start.clone()) {
+            entry.insert(None);
+            queue.push_back(start);
+        }
+    }
+    while let Some(state) = queue.pop_front() {
+        if goal(&state) {
+            let mut states = vec![state];
+            while let Some(parent) = &parents[states.last().unwrap()] {
+                states.push(parent.clone());
+            }
+            states.reverse();
+            return Some(Path {
+                cost: states.len() - 1,
+                states,
+            });
+        }
+        for next in successors(&state) {
+            if let Entry::Vacant(entry) = parents.entry(next.clone()) {
+                entry.insert(Some(state.clone()));
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// Finds the cheapest path to a goal. `successors` gives each state reachable in one step from a
+/// state, with the cost of that step.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar_by_key(starts, S::clone, successors, |_| C::default(), goal)
+}
+
+/// Like [`dijkstra`], but only expanding the first state reached with each key.
+pub fn dijkstra_by_key<S, K, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    key: impl FnMut(&S) -> K,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone,
+    K: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar_by_key(starts, key, successors, |_| C::default(), goal)
+}
+
+/// Finds the cheapest path to a goal, expanding first the states that `heuristic` estimates are
+/// closest to one. The heuristic must never overestimate the cost left to a goal, and must not
+/// drop by more than the cost of a step, or the path found might not be the cheapest.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar_by_key(starts, S::clone, successors, heuristic, goal)
+}
+
+/// Like [`astar`], but only expanding the first state reached with each key.
+pub fn astar_by_key<S, K, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    key: impl FnMut(&S) -> K,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone,
+    K: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut search = Search::new(key, heuristic);
+    let found = search.run(starts, successors, goal, false)?;
+    let mut states = Vec::new();
+    let mut next = Some(&found[0]);
+    while let Some(key) = next {
+        let node = &search.nodes[key];
+        states.push(node.state.clone());
+        next = node.parents.first();
+    }
+    states.reverse();
+    Some(Path {
+        cost: search.nodes[&found[0]].cost,
+        states,
+    })
+}
+
+/// Finds every cheapest path to a goal, which might end at several goals that are equally cheap.
+pub fn all_shortest_paths<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<ShortestPaths<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut search = Search::new(S::clone, |_| C::default());
+    let goals = search.run(starts, successors, goal, true)?;
+    let cost = search.nodes[&goals[0]].cost;
+    let nodes = search
+        .nodes
+        .into_iter()
+        .map(|(key, node)| (key, (node.cost, node.parents)))
+        .collect();
+    Some(ShortestPaths { cost, goals, nodes })
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> ShortestPaths<S, C> {
+    /// The cost of each of the paths.
+    #[must_use]
+    pub fn cost(&self) -> C {
+        self.cost
+    }
+
+    /// The goals that the paths end at.
+    #[must_use]
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    /// Every state on at least one of the paths, including the starts and goals.
+    #[must_use]
+    pub fn states(&self) -> HashSet<&S> {
+        let mut on_path: HashSet<&S> = self.goals.iter().collect();
+        let mut to_visit: Vec<&S> = self.goals.iter().collect();
+        while let Some(state) = to_visit.pop() {
+            for parent in &self.nodes[state].1 {
+                if on_path.insert(parent) {
+                    to_visit.push(parent);
+                }
+            }
+        }
+        on_path
+    }
+
+    /// Each of the paths. There can be exponentially many of them, so prefer
+    /// [`states`](Self::states) when that's all that's needed.
+    #[must_use]
+    pub fn paths(&self) -> Vec<Path<S, C>> {
+        let mut paths = Vec::new();
+        let mut partial: Vec<Vec<&S>> = self.goals.iter().map(|goal| vec![goal]).collect();
+        while let Some(path) = partial.pop() {
+            let parents = &self.nodes[*path.last().unwrap()].1;
+            for parent in parents {
+                let mut path = path.clone();
+                path.push(parent);
+                partial.push(path);
+            }
+            if parents.is_empty() {
+                paths.push(Path {
+                    cost: self.cost,
+                    states: path.into_iter().rev().cloned().collect(),
+                });
+            }
+        }
+        paths
+    }
+}
+
+struct Node<S, K, C> {
+    state: S,
+    cost: C,
+    /// The keys of the states this one is reached from at `cost`.
+    parents: Vec<K>,
+    expanded: bool,
+}
+
+/// A state waiting to be expanded, ordered so that the one estimated to be cheapest is the
+/// greatest.
+struct Queued<K, C> {
+    estimate: C,
+    cost: C,
+    key: K,
+}
+
+impl<K, C: Copy + Ord> PartialEq for Queued<K, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<K, C: Copy + Ord> Eq for Queued<K, C> {}
+
+impl<K, C: Copy + Ord> PartialOrd for Queued<K, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K, C: Copy + Ord> Ord for Queued<K, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Among equal estimates, the costlier state is nearer a goal, so try it first.
+        (other.estimate, self.cost).cmp(&(self.estimate, other.cost))
+    }
+}
+
+struct Search<S, K, C, FK, FH> {
+    key: FK,
+    heuristic: FH,
+    nodes: HashMap<K, Node<S, K, C>>,
+    queue: BinaryHeap<Queued<K, C>>,
+}
+
+impl<S, K, C, FK, FH> Search<S, K, C, FK, FH>
+where
+    K: Clone + Eq + Hash,
+    C: Cost,
+    FK: FnMut(&S) -> K,
+    FH: FnMut(&S) -> C,
+{
+    fn new(key: FK, heuristic: FH) -> Self {
+        Self {
+            key,
+            heuristic,
+            nodes: HashMap::new(),
+            queue: BinaryHeap::new(),
+        }
+    }
+
+    /// Records that `state` can be reached at `cost`, from the state with key `parent`.
+    fn reach(&mut self, state: S, cost: C, parent: Option<&K>) {
+        let key = (self.key)(&state);
+        match self.nodes.entry(key.clone()) {
+            Entry::Occupied(mut entry) => {
+                let node = entry.get_mut();
+                match cost.cmp(&node.cost) {
+                    Ordering::Less => {
+                        // A cheaper way to a state that was already expanded means expanding it
+                        // again, so that the states after it get the cheaper cost too.
+                        node.state = state;
+                        node.cost = cost;
+                        node.parents = parent.into_iter().cloned().collect();
+                        node.expanded = false;
+                    }
+                    Ordering::Equal => {
+                        // A state can be reached from the same parent twice, e.g. by two edges
+                        // between them, but that's still only one path.
+                        if let Some(parent) = parent {
+                            if !node.parents.contains(parent) {
+                                node.parents.push(parent.clone());
+                            }
+                        }
+                        return;
+                    }
+                    Ordering::Greater => return,
+                }
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(Node {
+                    state,
+                    cost,
+                    parents: parent.into_iter().cloned().collect(),
+                    expanded: false,
+                });
+            }
+        }
+        let estimate = cost + (self.heuristic)(&self.nodes[&key].state);
+        self.queue.push(Queued {
+            estimate,
+            cost,
+            key,
+        });
+    }
+
+    /// Searches until a goal is expanded, or with `all` until every goal as cheap as the first
+    /// is, returning the keys of the goals.
+    fn run<I>(
+        &mut self,
+        starts: impl IntoIterator<Item = S>,
+        mut successors: impl FnMut(&S) -> I,
+        mut goal: impl FnMut(&S) -> bool,
+        all: bool,
+    ) -> Option<Vec<K>>
+    where
+        I: IntoIterator<Item = (S, C)>,
+    {
+        for start in starts {
+            self.reach(start, C::default(), None);
+        }
+        let mut goals = Vec::new();
+        let mut goal_cost = None;
+        while let Some(Queued { estimate, key, .. }) = self.queue.pop() {
+            if goal_cost.is_some_and(|goal_cost| estimate > goal_cost) {
+                break;
+            }
+            let node = self.nodes.get_mut(&key).unwrap();
+            if node.expanded {
+                continue;
+            }
+            node.expanded = true;
+            if goal(&node.state) {
+                goal_cost = Some(node.cost);
+                if !goals.contains(&key) {
+                    goals.push(key);
+                }
+                if all {
+                    continue;
+                }
+                break;
+            }
+            let cost = node.cost;
+            let nexts: Vec<(S, C)> = successors(&node.state).into_iter().collect();
+            for (next, step) in nexts {
+                self.reach(next, cost + step, Some(&key));
+            }
+        }
+        (!goals.is_empty()).then_some(goals)
+    }
+}
@@ -0,0 +1,29 @@
+use std::fmt::Display;
+
+use crate::Params;
+
+/// A puzzle solution, split into stages so they can be run and timed separately.
+pub trait Solution {
+    /// The puzzle input after parsing, shared by both parts.
+    type Input: 'static;
+
+    /// Day 25 puzzles only have a first part, so their solutions set this to `false` and leave
+    /// out `part_2`.
+    const HAS_PART_2: bool = true;
+
+    fn parse(input: &str) -> Self::Input;
+
+    /// Parses an input for a puzzle whose parameters differ from the real one, as some of the
+    /// worked examples do. Solutions with such parameters override this, and have `parse` call it
+    /// with [`Params::default`].
+    #[must_use]
+    fn parse_with(input: &str, _params: &Params) -> Self::Input {
+        Self::parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> impl Display;
+
+    fn part_2(_input: &Self::Input) -> impl Display {
+        String::new()
+    }
+}
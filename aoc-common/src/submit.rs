@@ -0,0 +1,365 @@
+//! Submitting answers, along with a local history of what was submitted, so that an answer the
+//! site has already judged is never sent again.
+//!
+//! The history is kept in `submissions.txt` in a year's input directory. Each line is
+//! `<day> <part> <verdict> <answer>`, where the verdict is `correct`, `wrong`, `too-high` or
+//! `too-low`, except for `wait` lines, which record the Unix time in seconds before which the site
+//! won't accept another answer for that part.
+
+use std::{
+    fmt::{self, Write},
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{client::save, Client, ClientError, Year};
+
+/// What the site made of a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+impl Verdict {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Correct => "correct",
+            Self::Wrong => "wrong",
+            Self::TooHigh => "too-high",
+            Self::TooLow => "too-low",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        [Self::Correct, Self::Wrong, Self::TooHigh, Self::TooLow]
+            .into_iter()
+            .find(|verdict| verdict.name() == name)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Correct => "correct",
+            Self::Wrong => "wrong",
+            Self::TooHigh => "too high",
+            Self::TooLow => "too low",
+        })
+    }
+}
+
+/// The site's response to a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Response {
+    /// The answer was judged. A wrong answer comes with a wait before the next one is accepted.
+    Judged {
+        verdict: Verdict,
+        wait: Option<Duration>,
+    },
+    /// The last answer was too recent, so this one wasn't judged.
+    TooSoon { wait: Duration },
+    /// The part is already solved, or isn't unlocked yet.
+    WrongLevel,
+}
+
+/// Why an answer wasn't submitted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Refusal {
+    /// The part was already solved, with `answer`.
+    AlreadySolved { answer: String },
+    /// This exact answer was already submitted and judged.
+    AlreadyJudged { verdict: Verdict },
+    /// The answer is no lower than one already judged too high, or no higher than one judged too
+    /// low.
+    OutOfBounds { verdict: Verdict, bound: String },
+    /// The site won't accept another answer for this long.
+    Waiting { wait: Duration },
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AlreadySolved { answer } => write!(f, "this part was already solved by {answer}"),
+            Self::AlreadyJudged { verdict } => write!(f, "it was already judged {verdict}"),
+            Self::OutOfBounds { verdict, bound } => write!(f, "{bound} was already {verdict}"),
+            Self::Waiting { wait } => write!(
+                f,
+                "the site won't accept another answer for {}s",
+                wait.as_secs()
+            ),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Record {
+    Judged(Verdict, String),
+    WaitUntil(u64),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Entry {
+    day: u8,
+    part: u8,
+    record: Record,
+}
+
+/// Every answer submitted for one year, and what the site made of it.
+#[derive(Clone, Debug)]
+pub struct History {
+    path: PathBuf,
+    entries: Vec<Entry>,
+}
+
+fn unix_time(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+impl History {
+    /// Reads the history at `path`, treating a missing file as an empty history.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message naming the file and line if it can't be read or parsed.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(format!("{}: {e}", path.display())),
+        };
+
+        let mut entries = Vec::new();
+        for (line_number, line) in (1..).zip(text.lines()) {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = || format!("{}: Line {line_number}: invalid entry", path.display());
+            let mut fields = line.splitn(4, ' ');
+            let (Some(day), Some(part), Some(kind), Some(value)) =
+                (fields.next(), fields.next(), fields.next(), fields.next())
+            else {
+                return Err(error());
+            };
+            let record = match (kind, Verdict::from_name(kind)) {
+                ("wait", _) => Record::WaitUntil(value.parse().map_err(|_| error())?),
+                (_, Some(verdict)) => Record::Judged(verdict, value.to_owned()),
+                (_, None) => return Err(error()),
+            };
+            entries.push(Entry {
+                day: day.parse().map_err(|_| error())?,
+                part: part.parse().map_err(|_| error())?,
+                record,
+            });
+        }
+
+        Ok(Self {
+            path: path.to_owned(),
+            entries,
+        })
+    }
+
+    /// Checks whether `answer` is worth submitting at `now`, given what's already been judged.
+    ///
+    /// # Errors
+    ///
+    /// Returns why the answer shouldn't be submitted.
+    pub fn check(&self, day: u8, part: u8, answer: &str, now: SystemTime) -> Result<(), Refusal> {
+        let records = self
+            .entries
+            .iter()
+            .filter(|entry| entry.day == day && entry.part == part)
+            .map(|entry| &entry.record);
+        let number = answer.parse::<i128>().ok();
+
+        let mut wait_until = 0;
+        for record in records {
+            let (verdict, judged) = match record {
+                Record::Judged(verdict, judged) => (*verdict, judged),
+                Record::WaitUntil(time) => {
+                    wait_until = wait_until.max(*time);
+                    continue;
+                }
+            };
+            if verdict == Verdict::Correct {
+                return Err(Refusal::AlreadySolved {
+                    answer: judged.clone(),
+                });
+            }
+            if judged == answer {
+                return Err(Refusal::AlreadyJudged { verdict });
+            }
+            let out_of_bounds = match (verdict, number, judged.parse::<i128>()) {
+                (Verdict::TooHigh, Some(number), Ok(bound)) => number >= bound,
+                (Verdict::TooLow, Some(number), Ok(bound)) => number <= bound,
+                _ => false,
+            };
+            if out_of_bounds {
+                return Err(Refusal::OutOfBounds {
+                    verdict,
+                    bound: judged.clone(),
+                });
+            }
+        }
+
+        match wait_until.checked_sub(unix_time(now)) {
+            Some(wait) if wait > 0 => Err(Refusal::Waiting {
+                wait: Duration::from_secs(wait),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Records the site's `response` to `answer`, received at `now`.
+    pub fn record(&mut self, day: u8, part: u8, answer: &str, response: Response, now: SystemTime) {
+        let (verdict, wait) = match response {
+            Response::Judged { verdict, wait } => (Some(verdict), wait),
+            Response::TooSoon { wait } => (None, Some(wait)),
+            Response::WrongLevel => (None, None),
+        };
+        if let Some(verdict) = verdict {
+            self.entries.push(Entry {
+                day,
+                part,
+                record: Record::Judged(verdict, answer.to_owned()),
+            });
+        }
+        if let Some(wait) = wait {
+            self.entries.push(Entry {
+                day,
+                part,
+                record: Record::WaitUntil(unix_time(now + wait)),
+            });
+        }
+    }
+
+    /// Writes the history back to the file it was loaded from.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be written.
+    pub fn save(&self) -> Result<(), ClientError> {
+        let mut text =
+            String::from("# Answers sent by `aoc submit`, and what the site made of them.\n");
+        for Entry { day, part, record } in &self.entries {
+            let (kind, value) = match record {
+                Record::Judged(verdict, answer) => (verdict.name(), answer.clone()),
+                Record::WaitUntil(time) => ("wait", time.to_string()),
+            };
+            writeln!(text, "{day} {part} {kind} {value}").unwrap();
+        }
+        save(&self.path, &text).map_err(|source| ClientError::Save {
+            path: self.path.clone(),
+            source,
+        })
+    }
+}
+
+impl Year {
+    #[must_use]
+    pub fn submission_history_path(&self) -> PathBuf {
+        self.input_dir().join("submissions.txt")
+    }
+}
+
+/// Reads a wait given either as "You have 1m 23s left to wait", or as "wait one minute" or
+/// "wait 5 minutes".
+fn parse_wait(text: &str) -> Option<Duration> {
+    if let Some((before, _)) = text.split_once(" left to wait") {
+        let mut seconds = 0;
+        for token in before.rsplit(' ').take_while(|token| {
+            token
+                .strip_suffix(['h', 'm', 's'])
+                .is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+        }) {
+            let (n, unit) = token.split_at(token.len() - 1);
+            let n: u64 = n.parse().ok()?;
+            seconds += n * match unit {
+                "h" => 3600,
+                "m" => 60,
+                _ => 1,
+            };
+        }
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let after = text.split_once("wait ")?.1;
+    let (count, rest) = after.split_once(' ')?;
+    let count = match count {
+        "one" => 1,
+        count => count.parse().ok()?,
+    };
+    rest.starts_with("minute")
+        .then(|| Duration::from_secs(count * 60))
+}
+
+/// Returns the text of the page's `<article>`, where the site puts its verdict.
+fn article_text(html: &str) -> &str {
+    let start = html.find("<article").unwrap_or(0);
+    let end = html[start..]
+        .find("</article>")
+        .map_or(html.len(), |end| start + end);
+    &html[start..end]
+}
+
+fn parse_response(html: &str) -> Option<Response> {
+    let text = article_text(html);
+    if text.contains("That's the right answer") {
+        Some(Response::Judged {
+            verdict: Verdict::Correct,
+            wait: None,
+        })
+    } else if text.contains("That's not the right answer") {
+        let verdict = if text.contains("too high") {
+            Verdict::TooHigh
+        } else if text.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        };
+        Some(Response::Judged {
+            verdict,
+            wait: parse_wait(text),
+        })
+    } else if text.contains("You gave an answer too recently") {
+        Some(Response::TooSoon {
+            wait: parse_wait(text)?,
+        })
+    } else if text.contains("You don't seem to be solving the right level") {
+        Some(Response::WrongLevel)
+    } else {
+        None
+    }
+}
+
+impl Client {
+    /// Submits `answer` for the given part of a puzzle.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no session cookie is set, the request fails or the response isn't one
+    /// the site is known to give.
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<Response, ClientError> {
+        let path = format!("{year}/day/{day}/answer");
+        let html = self.send(
+            "POST",
+            &path,
+            &[("level", &part.to_string()), ("answer", answer)],
+        )?;
+        parse_response(&html).ok_or_else(|| ClientError::Request {
+            url: self.url(&path),
+            message: format!("Unrecognised response: {}", article_text(&html)),
+        })
+    }
+}
@@ -0,0 +1,323 @@
+//! Timing named stages of a solution with [`PerfTimer`].
+//!
+//! Outside [`collect_timings`], a timer just prints how long it took. Inside it, timers are
+//! aggregated into spans instead: a timer started while another is running is nested under it, and
+//! every timer with the same name under the same parent adds to the same [`Timing`], so a timer in
+//! a function called a million times shows up once, with a count.
+//!
+//! The collector is per thread, so work spread over other threads, e.g. by rayon, collects its own
+//! timings with [`collect_timings`] and hands them back with [`add_timings`].
+
+use std::{
+    cell::RefCell,
+    fmt::Write,
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, Instant},
+};
+
+/// The id of the next [`Collector`], so a timer can tell whether it's finishing in the collector
+/// it was started in.
+static NEXT_COLLECTOR_ID: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    static COLLECTOR: RefCell<Option<Collector>> = const { RefCell::new(None) };
+}
+
+/// Every [`PerfTimer`] with the same path that finished while [`collect_timings`] was running.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Timing {
+    /// The names of the timers this one ran inside, outermost first, followed by its own name.
+    pub path: Vec<String>,
+    pub count: u64,
+    pub total: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Timing {
+    fn new(path: Vec<String>) -> Self {
+        Self {
+            path,
+            count: 0,
+            total: Duration::ZERO,
+            min: Duration::MAX,
+            max: Duration::ZERO,
+        }
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &str {
+        self.path.last().map_or("", String::as_str)
+    }
+
+    /// How many timers this one ran inside.
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        self.path.len() - 1
+    }
+
+    #[must_use]
+    pub fn mean(&self) -> Duration {
+        self.total / u32::try_from(self.count.max(1)).unwrap_or(u32::MAX)
+    }
+
+    fn add(&mut self, duration: Duration) {
+        self.count += 1;
+        self.total += duration;
+        self.min = self.min.min(duration);
+        self.max = self.max.max(duration);
+    }
+
+    fn merge(&mut self, other: &Timing) {
+        self.count += other.count;
+        self.total += other.total;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+}
+
+struct Collector {
+    id: u64,
+    /// In the order each path was first started, so that a parent comes before its children.
+    timings: Vec<Timing>,
+    parents: Vec<Option<usize>>,
+    /// The running timers, innermost last.
+    running: Vec<usize>,
+}
+
+impl Collector {
+    fn new() -> Self {
+        Self {
+            id: NEXT_COLLECTOR_ID.fetch_add(1, Ordering::Relaxed),
+            timings: Vec::new(),
+            parents: Vec::new(),
+            running: Vec::new(),
+        }
+    }
+
+    /// The span called `name` under `parent`, added if it's not been seen before.
+    fn child(&mut self, parent: Option<usize>, name: &str) -> usize {
+        let existing = (0..self.timings.len())
+            .find(|&i| self.parents[i] == parent && self.timings[i].name() == name);
+        existing.unwrap_or_else(|| {
+            let mut path = parent.map_or_else(Vec::new, |parent| self.timings[parent].path.clone());
+            path.push(name.to_owned());
+            self.timings.push(Timing::new(path));
+            self.parents.push(parent);
+            self.timings.len() - 1
+        })
+    }
+
+    fn start(&mut self, name: &str) -> usize {
+        let index = self.child(self.running.last().copied(), name);
+        self.running.push(index);
+        index
+    }
+
+    fn finish(&mut self, index: usize, duration: Duration) {
+        self.timings[index].add(duration);
+        if let Some(position) = self.running.iter().rposition(|&i| i == index) {
+            self.running.truncate(position);
+        }
+    }
+
+    fn merge(&mut self, timing: &Timing) {
+        let root = self.running.last().copied();
+        let index = timing
+            .path
+            .iter()
+            .fold(root, |parent, name| Some(self.child(parent, name)));
+        if let Some(index) = index {
+            self.timings[index].merge(timing);
+        }
+    }
+}
+
+/// Runs `f`, aggregating every `PerfTimer` started and finished on this thread while it runs
+/// instead of printing it. The timings are in the order they were first started.
+pub fn collect_timings<T>(f: impl FnOnce() -> T) -> (T, Vec<Timing>) {
+    let outer = COLLECTOR.with_borrow_mut(|collector| collector.replace(Collector::new()));
+    let result = f();
+    let collector = COLLECTOR.with_borrow_mut(|collector| std::mem::replace(collector, outer));
+    let timings = collector.map_or_else(Vec::new, |collector| collector.timings);
+    (
+        result,
+        timings.into_iter().filter(|t| t.count > 0).collect(),
+    )
+}
+
+/// Adds `timings` from another [`collect_timings`], nested under the timer running on this thread,
+/// e.g. those collected by each task of a parallel iterator. Does nothing outside
+/// [`collect_timings`].
+pub fn add_timings(timings: &[Timing]) {
+    COLLECTOR.with_borrow_mut(|collector| {
+        if let Some(collector) = collector {
+            for timing in timings {
+                collector.merge(timing);
+            }
+        }
+    });
+}
+
+pub struct PerfTimer<'a> {
+    name: &'a str,
+    start: Instant,
+    end: Option<Instant>,
+    /// The id of the collector this timer was started under and its place in it, if any.
+    span: Option<(u64, usize)>,
+}
+
+impl<'a> PerfTimer<'a> {
+    #[must_use]
+    pub fn new(name: &'a str) -> Self {
+        let span = COLLECTOR.with_borrow_mut(|collector| {
+            let collector = collector.as_mut()?;
+            Some((collector.id, collector.start(name)))
+        });
+        let start = Instant::now();
+        Self {
+            name,
+            start,
+            end: None,
+            span,
+        }
+    }
+
+    /// Stops the timer, adding it to its span if it was started under [`collect_timings`]. A timer
+    /// that's stopped outside the collector it was started in, e.g. on another thread, is printed
+    /// instead.
+    pub fn stop(&mut self) {
+        let now = Instant::now();
+        assert!(self.end.is_none(), "Timer was already stopped");
+        self.end = Some(now);
+        let duration = self.duration();
+        if let Some((id, index)) = self.span {
+            let collected = COLLECTOR.with_borrow_mut(|collector| match collector {
+                Some(collector) if collector.id == id => {
+                    collector.finish(index, duration);
+                    true
+                }
+                _ => false,
+            });
+            if !collected {
+                self.span = None;
+            }
+        }
+    }
+
+    /// Prints how long the timer took, unless it was added to a span instead.
+    pub fn print(&self) {
+        assert!(self.end.is_some(), "Timer is still running");
+        if self.span.is_none() {
+            eprintln!("{} took {:?}", self.name, self.duration());
+        }
+    }
+
+    #[must_use]
+    pub fn is_running(&self) -> bool {
+        self.end.is_none()
+    }
+
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        let end = self.end.unwrap_or_else(Instant::now);
+        end - self.start
+    }
+}
+
+impl Drop for PerfTimer<'_> {
+    fn drop(&mut self) {
+        if self.is_running() {
+            self.stop();
+            self.print();
+        }
+    }
+}
+
+/// The timings of every day in a run, for comparing runs, e.g. between commits.
+#[derive(Clone, Debug, Default)]
+pub struct TimingReport {
+    days: Vec<(u16, u8, Vec<Timing>)>,
+}
+
+impl TimingReport {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, year: u16, day: u8, timings: Vec<Timing>) {
+        self.days.push((year, day, timings));
+    }
+
+    fn rows(&self) -> impl Iterator<Item = (u16, u8, &Timing)> {
+        self.days
+            .iter()
+            .flat_map(|(year, day, timings)| timings.iter().map(move |t| (*year, *day, t)))
+    }
+
+    /// One row per span, with its path joined by `/` and durations in nanoseconds.
+    #[must_use]
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("year,day,span,depth,count,total_ns,min_ns,max_ns\n");
+        for (year, day, t) in self.rows() {
+            let span = t.path.join("/");
+            let span = if span.contains([',', '"', '\n']) {
+                format!("\"{}\"", span.replace('"', "\"\""))
+            } else {
+                span
+            };
+            writeln!(
+                csv,
+                "{year},{day},{span},{},{},{},{},{}",
+                t.depth(),
+                t.count,
+                t.total.as_nanos(),
+                t.min.as_nanos(),
+                t.max.as_nanos()
+            )
+            .unwrap();
+        }
+        csv
+    }
+
+    /// An array with an object per span, with durations in nanoseconds.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let rows: Vec<String> = self
+            .rows()
+            .map(|(year, day, t)| {
+                let path: Vec<String> = t.path.iter().map(|name| json_string(name)).collect();
+                format!(
+                    "  {{\"year\": {year}, \"day\": {day}, \"path\": [{}], \"count\": {}, \
+                     \"total_ns\": {}, \"min_ns\": {}, \"max_ns\": {}}}",
+                    path.join(", "),
+                    t.count,
+                    t.total.as_nanos(),
+                    t.min.as_nanos(),
+                    t.max.as_nanos()
+                )
+            })
+            .collect();
+        if rows.is_empty() {
+            "[]\n".to_owned()
+        } else {
+            format!("[\n{}\n]\n", rows.join(",\n"))
+        }
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            c if c.is_control() => write!(json, "\\u{:04x}", u32::from(c)).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
@@ -0,0 +1,34 @@
+use std::{fs, time::Duration};
+
+use aoc_common::{Baseline, Stats};
+
+#[test]
+fn summarises_samples() {
+    let samples = [4, 1, 3, 2].map(Duration::from_millis);
+    let stats = Stats::from_samples(&samples);
+    assert_eq!(stats.samples, 4);
+    assert_eq!(stats.min, Duration::from_millis(1));
+    assert_eq!(stats.median, Duration::from_micros(2500));
+    assert_eq!(stats.mean, Duration::from_micros(2500));
+    assert_eq!(stats.stddev.as_micros(), 1118);
+}
+
+#[test]
+fn saves_and_loads_baselines() {
+    let path = std::env::temp_dir().join(format!("aoc-baseline-test-{}.txt", std::process::id()));
+    assert_eq!(Baseline::load(&path).unwrap(), Baseline::new());
+
+    let mut baseline = Baseline::new();
+    baseline.insert(2022, 19, "Part 2", Duration::from_millis(180));
+    baseline.insert(2022, 1, "Parse", Duration::from_micros(60));
+    baseline.save(&path).unwrap();
+    let loaded = Baseline::load(&path).unwrap();
+    assert_eq!(loaded, baseline);
+    assert_eq!(
+        loaded.get(2022, 19, "Part 2"),
+        Some(Duration::from_millis(180))
+    );
+    assert_eq!(loaded.get(2022, 19, "Part 1"), None);
+
+    fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,144 @@
+use std::{
+    fs,
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    path::PathBuf,
+    sync::mpsc,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use aoc_common::{Client, Fetched, History, Refusal, Response, Verdict, Year};
+
+/// Serves each of `bodies` in turn, one per request, sending each request's head and body back
+/// down the channel.
+fn stub_server(bodies: &'static [&'static str]) -> (String, mpsc::Receiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let (requests, received) = mpsc::channel();
+    thread::spawn(move || {
+        for (stream, body) in listener.incoming().zip(bodies) {
+            let mut stream = stream.unwrap();
+            let mut head = String::new();
+            let mut reader = BufReader::new(&stream);
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                head.push_str(&line);
+            }
+            let length = head
+                .lines()
+                .find_map(|line| line.strip_prefix("Content-Length: "))
+                .map_or(0, |length| length.trim().parse().unwrap());
+            let mut request_body = vec![0; length];
+            reader.read_exact(&mut request_body).unwrap();
+            head.push_str(&String::from_utf8(request_body).unwrap());
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            requests.send(head).unwrap();
+        }
+    });
+    (url, received)
+}
+
+/// A year with no days, whose crate directory is a fresh temporary directory.
+fn temp_year(test: &str) -> (PathBuf, Year) {
+    let dir = std::env::temp_dir().join(format!("aoc-{test}-test-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    let year = Year {
+        year: 2022,
+        dir: dir.to_str().unwrap().to_owned().leak(),
+        days: &[],
+    };
+    (dir, year)
+}
+
+#[test]
+fn fetches_once_then_uses_the_cache() {
+    let (dir, year) = temp_year("fetch");
+    let (url, requests) = stub_server(&["1\n2\n3\n"]);
+    let fetcher = Client::new(url, Some("abc123".to_owned()));
+
+    let path = dir.join("input/day5.txt");
+    assert_eq!(
+        fetcher.fetch(&year, 5).unwrap(),
+        Fetched::Downloaded(path.clone())
+    );
+    assert_eq!(fs::read_to_string(&path).unwrap(), "1\n2\n3\n");
+    let head = requests.recv().unwrap();
+    assert!(head.starts_with("GET /2022/day/5/input "), "{head}");
+    assert!(head.contains("session=abc123"), "{head}");
+
+    assert_eq!(fetcher.fetch(&year, 5).unwrap(), Fetched::Cached(path));
+    assert!(requests.try_recv().is_err());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn submits_and_refuses_answers_already_ruled_out() {
+    let (dir, year) = temp_year("submit");
+    let (url, requests) = stub_server(&[
+        "<main><article><p>That's not the right answer; your answer is too high. Please wait one \
+         minute before trying again.</p></article></main>",
+        "<main><article><p>You gave an answer too recently. You have 42s left to wait.</p>\
+         </article></main>",
+    ]);
+    let client = Client::new(url, Some("abc123".to_owned()));
+    let now = SystemTime::now();
+
+    let response = client.submit(2022, 5, 1, "100").unwrap();
+    assert_eq!(
+        response,
+        Response::Judged {
+            verdict: Verdict::TooHigh,
+            wait: Some(Duration::from_mins(1)),
+        }
+    );
+    let request = requests.recv().unwrap();
+    assert!(request.starts_with("POST /2022/day/5/answer "), "{request}");
+    assert!(request.ends_with("level=1&answer=100"), "{request}");
+
+    let path = year.submission_history_path();
+    let mut history = History::load(&path).unwrap();
+    history.record(5, 1, "100", response, now);
+    history.save().unwrap();
+    let history = History::load(&path).unwrap();
+
+    let later = now + Duration::from_secs(61);
+    assert_eq!(
+        history.check(5, 1, "100", later),
+        Err(Refusal::AlreadyJudged {
+            verdict: Verdict::TooHigh
+        })
+    );
+    assert_eq!(
+        history.check(5, 1, "150", later),
+        Err(Refusal::OutOfBounds {
+            verdict: Verdict::TooHigh,
+            bound: "100".to_owned()
+        })
+    );
+    assert!(matches!(
+        history.check(5, 1, "50", now),
+        Err(Refusal::Waiting { .. })
+    ));
+    assert_eq!(history.check(5, 1, "50", later), Ok(()));
+    assert_eq!(history.check(5, 2, "150", now), Ok(()));
+
+    assert_eq!(
+        client.submit(2022, 5, 1, "50").unwrap(),
+        Response::TooSoon {
+            wait: Duration::from_secs(42)
+        }
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,48 @@
+use aoc_common::{brent, find_cycle, simulate, Cycle};
+
+/// A sequence that runs into a cycle after a few steps.
+fn step(x: &mut u64) {
+    *x = (*x * *x + 1) % 103;
+}
+
+#[test]
+fn finds_cycles() {
+    let (cycle, state) = find_cycle(3, step, |&x| x);
+    assert_eq!(brent(3, step, |&x| x), cycle);
+
+    let mut x = 3;
+    for _ in 0..cycle.start {
+        step(&mut x);
+    }
+    let first = x;
+    for _ in 0..cycle.length {
+        step(&mut x);
+    }
+    assert_eq!(x, first);
+    assert_eq!(state, first);
+
+    let mut x = 3;
+    for _ in 0..1000 {
+        step(&mut x);
+    }
+    assert_eq!(simulate(3, 1000, step, |&x| x), x);
+    assert_eq!(simulate(3, 2, step, |&x| x), 101);
+}
+
+#[test]
+fn extrapolates_through_cycles() {
+    let cycle = Cycle {
+        start: 2,
+        length: 3,
+    };
+    assert_eq!(cycle.equivalent_step(1), 1);
+    assert_eq!(cycle.equivalent_step(5), 2);
+    assert_eq!(cycle.equivalent_step(1_000_000), 4);
+
+    // Growing by 1 and 2, then by 3, 4 and 3 round and round.
+    let values = [0, 1, 3, 6, 10, 13];
+    assert_eq!(cycle.delta(|i| values[i]), 10);
+    assert_eq!(cycle.extrapolate(4, |i| values[i]), 10);
+    assert_eq!(cycle.extrapolate(8, |i| values[i]), 23);
+    assert_eq!(cycle.extrapolate(3_000_002, |i| values[i]), 10_000_003);
+}
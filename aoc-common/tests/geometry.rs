@@ -0,0 +1,37 @@
+use aoc_common::{Direction, Direction8, Grid, Point2, Point3};
+
+#[test]
+fn points_do_arithmetic() {
+    let a = Point2::new(3, -4);
+    let b = Point2::from((1, 2));
+    assert_eq!(a + b, Point2::new(4, -2));
+    assert_eq!(a - b, Point2::new(2, -6));
+    assert_eq!(-a * 2, Point2::new(-6, 8));
+    assert_eq!(a.manhattan(Point2::ORIGIN), 7);
+    assert_eq!(a.chebyshev(b), 6);
+    assert_eq!((a - b).signum(), Point2::new(1, -1));
+    assert_eq!(a.rotate_right().rotate_left(), a);
+    assert_eq!(Direction::Up.step().rotate_right(), Direction::Right.step());
+
+    let c = Point3::new(1, 2, 3);
+    assert_eq!(c.manhattan(Point3::ORIGIN), 6);
+    assert!(c.neighbours6().iter().all(|&n| n.manhattan(c) == 1));
+}
+
+#[test]
+fn directions_turn() {
+    for direction in Direction::ALL {
+        assert_eq!(direction.turn_right().turn_left(), direction);
+        assert_eq!(direction.opposite().step(), -direction.step());
+        assert_eq!(Direction8::from(direction).step(), direction.step());
+    }
+    assert_eq!(Direction::try_from('<'), Ok(Direction::Left));
+    assert_eq!(Direction::try_from('x'), Err('x'));
+    assert_eq!(Direction8::NW.turn_right(), Direction8::N);
+    assert!(Direction8::SE.is_diagonal());
+
+    let grid = Grid::new(2, 2, ());
+    assert_eq!(grid.offset((0, 0), Direction::Down), Some((0, 1)));
+    assert_eq!(grid.offset((0, 0), Direction8::NE), None);
+    assert_eq!(Point2::new(5, 5) + Direction::Left, Point2::new(4, 5));
+}
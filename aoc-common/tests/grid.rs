@@ -0,0 +1,93 @@
+use aoc_common::{Grid, GridError, ORTHOGONAL};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Cell {
+    Open,
+    Wall,
+}
+
+impl TryFrom<char> for Cell {
+    type Error = ();
+
+    fn try_from(c: char) -> Result<Self, ()> {
+        match c {
+            '.' => Ok(Cell::Open),
+            '#' => Ok(Cell::Wall),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<Cell> for char {
+    fn from(cell: Cell) -> Self {
+        match cell {
+            Cell::Open => '.',
+            Cell::Wall => '#',
+        }
+    }
+}
+
+const MAP: &str = "\
+#..
+.#.
+";
+
+#[test]
+fn parses_and_displays() {
+    let grid: Grid<Cell> = MAP.parse().unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(1, 1)], Cell::Wall);
+    assert_eq!(grid.get((3, 0)), None);
+    assert_eq!(grid.to_string(), MAP);
+    assert_eq!(grid.position(|&cell| cell == Cell::Wall), Some((0, 0)));
+
+    assert_eq!(
+        "#..\n.#".parse::<Grid<Cell>>(),
+        Err(GridError::Ragged {
+            line: 2,
+            expected: 3,
+            found: 2
+        })
+    );
+    let error = "#..\n.x.".parse::<Grid<Cell>>().unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Line 2: invalid character 'x' in column 2"
+    );
+}
+
+#[test]
+fn finds_neighbours_within_bounds() {
+    let grid = Grid::new(3, 3, 0);
+    assert_eq!(
+        grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+        [(1, 0), (0, 1)]
+    );
+    assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+    assert_eq!(grid.neighbours8((2, 2)).count(), 3);
+    assert_eq!(grid.offset((0, 1), ORTHOGONAL[3]), None);
+    assert_eq!(
+        grid.ray((0, 0), (1, 1)).collect::<Vec<_>>(),
+        [(1, 1), (2, 2)]
+    );
+}
+
+#[test]
+fn views_and_rearranges() {
+    let grid = Grid::from_fn(3, 2, |(x, y)| ["abc", "def"][y].as_bytes()[x] as char);
+    assert_eq!(grid.to_string(), "abc\ndef\n");
+    assert_eq!(grid.row(1), ['d', 'e', 'f']);
+    assert_eq!(grid.column(2).collect::<String>(), "cf");
+    assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+    assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc\n");
+    assert_eq!(grid.rotate_left().to_string(), "cf\nbe\nad\n");
+    assert_eq!(grid.rotate_right().rotate_left(), grid);
+    assert_eq!(grid.map(char::to_ascii_uppercase).to_string(), "ABC\nDEF\n");
+}
+
+#[test]
+#[should_panic(expected = "Column 3 is out of bounds")]
+fn rejects_a_column_past_the_edge() {
+    let grid = Grid::from_fn(3, 2, |(x, y)| x + y);
+    let _ = grid.column(3);
+}
@@ -0,0 +1,59 @@
+use aoc_common::{
+    crt, extended_gcd, gcd, gcd_all, lcm, lcm_all, mod_inverse, mod_pow, solve_linear,
+    solve_linear_integer, Ratio,
+};
+
+#[test]
+fn finds_common_factors_and_multiples() {
+    assert_eq!(gcd(12_u64, 18), 6);
+    assert_eq!(gcd(0_u32, 7), 7);
+    assert_eq!(lcm(4_u128, 6), 12);
+    assert_eq!(lcm(0_i64, 5), 0);
+    assert_eq!(gcd_all([24_u64, 36, 60]), Some(12));
+    assert_eq!(lcm_all([2_u64, 3, 4, 5]), Some(60));
+    assert_eq!(lcm_all(Vec::<u64>::new()), None);
+
+    let (g, x, y) = extended_gcd(240, 46);
+    assert_eq!(g, 2);
+    assert_eq!(240 * x + 46 * y, 2);
+    let (g, x, y) = extended_gcd(-4, 6);
+    assert_eq!(g, 2);
+    assert_eq!(-4 * x + 6 * y, 2);
+}
+
+#[test]
+fn does_modular_arithmetic() {
+    assert_eq!(mod_pow(3, 200, 13), 9);
+    assert_eq!(mod_pow(-2, 3, 5), 2);
+    assert_eq!(mod_inverse(3, 11), Some(4));
+    assert_eq!(mod_inverse(4, 10), None);
+
+    assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+    assert_eq!(crt([(1, 4), (3, 6)]), Some((9, 12)));
+    assert_eq!(crt([(0, 4), (1, 6)]), None);
+    assert_eq!(crt([]), Some((0, 1)));
+}
+
+#[test]
+fn solves_linear_systems_exactly() {
+    let half = Ratio::new(2, -4);
+    assert_eq!((half.numer(), half.denom()), (-1, 2));
+    assert_eq!(half + Ratio::ONE, Ratio::new(1, 2));
+    assert_eq!((half * half).to_string(), "1/4");
+    assert!(half < Ratio::ZERO);
+
+    assert_eq!(
+        solve_linear_integer([[94, 22], [34, 67]], [8400, 5400]),
+        Some([80, 40])
+    );
+    assert_eq!(
+        solve_linear([[2, 0], [0, 3]], [1, 1]),
+        Some([Ratio::new(1, 2), Ratio::new(1, 3)])
+    );
+    assert_eq!(solve_linear_integer([[2, 0], [0, 3]], [1, 1]), None);
+    assert_eq!(solve_linear([[1, 2], [2, 4]], [3, 6]), None);
+    assert_eq!(
+        solve_linear_integer([[0, 1, 1], [1, 0, 1], [1, 1, 0]], [5, 4, 3]),
+        Some([1, 2, 3])
+    );
+}
@@ -0,0 +1,105 @@
+use aoc_common::{all_shortest_paths, astar, bfs, dijkstra, dijkstra_by_key, Grid, Pos};
+
+const MAZE: &str = "\
+.....
+.###.
+.#...
+...#.
+";
+
+fn open_neighbours(grid: &Grid<bool>, pos: Pos) -> Vec<Pos> {
+    grid.neighbours4(pos).filter(|&next| !grid[next]).collect()
+}
+
+#[test]
+fn finds_shortest_paths() {
+    let grid = Grid::parse(MAZE, |c| Some(c == '#')).unwrap();
+    let goal = (4, 3);
+
+    let path = bfs(
+        [(0, 0)],
+        |&pos| open_neighbours(&grid, pos),
+        |&pos| pos == goal,
+    )
+    .unwrap();
+    assert_eq!(path.cost, 7);
+    assert_eq!(path.states.first(), Some(&(0, 0)));
+    assert_eq!(path.states.last(), Some(&goal));
+
+    let weighted = |&pos: &Pos| {
+        open_neighbours(&grid, pos)
+            .into_iter()
+            .map(|next| (next, 1 + u32::try_from(next.1).unwrap()))
+            .collect::<Vec<_>>()
+    };
+    let cheapest = dijkstra([(0, 0)], weighted, |&pos| pos == goal).unwrap();
+    assert_eq!(cheapest.cost, 13);
+    assert_eq!(
+        cheapest.states[..5],
+        [(0, 0), (1, 0), (2, 0), (3, 0), (4, 0)]
+    );
+
+    let guided = astar(
+        [(0, 0)],
+        weighted,
+        |&(x, y)| u32::try_from(goal.0 - x + goal.1 - y).unwrap(),
+        |&pos| pos == goal,
+    )
+    .unwrap();
+    assert_eq!(guided, cheapest);
+
+    assert_eq!(
+        bfs([(0, 0)], |&pos| open_neighbours(&grid, pos), |_| false),
+        None
+    );
+}
+
+#[test]
+fn finds_every_shortest_path() {
+    let grid = Grid::new(3, 3, false);
+    let paths = all_shortest_paths(
+        [(0, 0)],
+        |&pos| {
+            grid.neighbours4(pos)
+                .map(|next| (next, 1))
+                .collect::<Vec<_>>()
+        },
+        |&pos| pos == (2, 2),
+    )
+    .unwrap();
+    assert_eq!(paths.cost(), 4);
+    assert_eq!(paths.goals(), [(2, 2)]);
+    assert_eq!(paths.states().len(), 9);
+    assert_eq!(paths.paths().len(), 6);
+
+    // Two equally cheap edges between the same states are still one path.
+    let paths = all_shortest_paths(
+        [0],
+        |&n: &u32| {
+            if n < 2 {
+                vec![(n + 1, 1), (n + 1, 1)]
+            } else {
+                vec![]
+            }
+        },
+        |&n| n == 2,
+    )
+    .unwrap();
+    assert_eq!(paths.paths().len(), 1);
+    assert_eq!(paths.paths()[0].states, [0, 1, 2]);
+}
+
+#[test]
+fn dedups_by_key() {
+    // Counting up in steps of one or three, where only the number modulo ten matters.
+    let path = dijkstra_by_key(
+        [0_u32],
+        |&n| n % 10,
+        |&n| [(n + 1, 1), (n + 3, 1)],
+        |&n| n == 7,
+    )
+    .unwrap();
+    assert_eq!(path.cost, 3);
+    assert_eq!(path.states.len(), 4);
+    assert_eq!(path.states.last(), Some(&7));
+}
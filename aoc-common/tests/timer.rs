@@ -0,0 +1,81 @@
+use aoc_common::{collect_timings, PerfTimer, TimingReport};
+
+fn step() {
+    let _timer = PerfTimer::new("step");
+}
+
+#[test]
+fn nests_and_aggregates_spans() {
+    let ((), timings) = collect_timings(|| {
+        drop(PerfTimer::new("Parse"));
+        let _part = PerfTimer::new("Part 1");
+        for _ in 0..3 {
+            step();
+        }
+    });
+
+    let paths: Vec<_> = timings.iter().map(|t| t.path.join("/")).collect();
+    assert_eq!(paths, ["Parse", "Part 1", "Part 1/step"]);
+    assert_eq!(
+        timings.iter().map(|t| t.count).collect::<Vec<_>>(),
+        [1, 1, 3]
+    );
+    let steps = &timings[2];
+    assert_eq!(steps.depth(), 1);
+    assert!(steps.min <= steps.max && steps.total <= timings[1].total);
+
+    let mut report = TimingReport::new();
+    report.add(2022, 19, timings);
+    let csv = report.to_csv();
+    assert!(
+        csv.starts_with("year,day,span,depth,count,total_ns,min_ns,max_ns\n2022,19,Parse,0,1,"),
+        "{csv}"
+    );
+    assert!(csv.contains("\n2022,19,Part 1/step,1,3,"), "{csv}");
+    let json = report.to_json();
+    assert!(
+        json.contains(r#"{"year": 2022, "day": 19, "path": ["Part 1", "step"], "count": 3, "#),
+        "{json}"
+    );
+}
+
+#[test]
+fn stopped_timers_are_recorded_once() {
+    let ((), timings) = collect_timings(|| {
+        let mut a = PerfTimer::new("A");
+        a.stop();
+        a.print();
+        a.print();
+        drop(a);
+        drop(PerfTimer::new("B"));
+    });
+
+    let paths: Vec<_> = timings.iter().map(|t| t.path.join("/")).collect();
+    assert_eq!(paths, ["A", "B"]);
+    assert_eq!(timings.iter().map(|t| t.count).collect::<Vec<_>>(), [1, 1]);
+}
+
+#[test]
+fn timers_only_finish_in_their_own_collector() {
+    let ((), timings) = collect_timings(|| {
+        let mut outer = PerfTimer::new("Outer");
+        let ((), inner) = collect_timings(|| outer.stop());
+        assert!(inner.is_empty());
+    });
+    assert!(timings.is_empty());
+}
+
+#[test]
+fn adds_timings_from_other_threads() {
+    let ((), timings) = collect_timings(|| {
+        let _part = PerfTimer::new("Part 1");
+        let worker = std::thread::spawn(|| collect_timings(|| drop(PerfTimer::new("Task"))).1);
+        let worker = worker.join().unwrap();
+        aoc_common::add_timings(&worker);
+        aoc_common::add_timings(&worker);
+    });
+
+    let paths: Vec<_> = timings.iter().map(|t| t.path.join("/")).collect();
+    assert_eq!(paths, ["Part 1", "Part 1/Task"]);
+    assert_eq!(timings[1].count, 2);
+}
@@ -0,0 +1,540 @@
+use std::{
+    fs, panic,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant, SystemTime},
+};
+
+use aoc_common::{
+    collect_timings, Answers, Baseline, BenchConfig, Client, Day, Fetched, History, InputSource,
+    PerfTimer, Response, Timing, TimingReport, Verdict, Year,
+};
+
+const YEARS: &[Year] = &[
+    advent_of_code_2019::YEAR,
+    advent_of_code_2021::YEAR,
+    advent_of_code_2022::YEAR,
+    advent_of_code_2023::YEAR,
+    advent_of_code_2024::YEAR,
+];
+
+const USAGE: &str = "\
+Usage:
+    aoc run <year> [day]    Run one day, or every day of a year
+    aoc run --all [day]     Run every year, or one day of every year
+    aoc bench <year> [day]  Time each stage over many runs, after warming up
+    aoc bench --all [day]
+    aoc fetch <year> <day>  Download a day's input into the year's input directory
+    aoc submit <year> <day> <part>
+                            Submit the answer to one part, unless it's already known to be wrong
+
+Options:
+    --input <path>          Read a single day's input from <path>, or from stdin if it's -
+    --timings <path>        Save every timed span to <path>, as JSON or CSV by its extension
+    --baseline <path>       Compare each benchmarked stage's median against the one in <path>,
+                            failing if any is more than the threshold slower
+    --save-baseline <path>  Save each benchmarked stage's median to <path>, keeping other entries
+    --threshold <percent>   How much slower than the baseline is a regression (default 10)
+
+Inputs are read from each year's input/ directory, or from $AOC_INPUT_DIR/<year> if it's set.
+Fetching and submitting need $AOC_SESSION set to the session cookie of a logged-in Advent of Code
+account, and talk to $AOC_BASE_URL instead of the real site if it's set. Submitted answers and
+their verdicts are kept in submissions.txt in the year's input directory.";
+
+const ANSWER_WIDTH: usize = 20;
+
+/// How much slower than its baseline a stage can get before `aoc bench` reports it, by default.
+const DEFAULT_THRESHOLD: f64 = 0.1;
+
+struct Selection {
+    year: Option<u16>,
+    day: Option<u8>,
+    input: Option<InputSource>,
+    timings: Option<PathBuf>,
+}
+
+/// Removes `option` and the value after it from `args`, returning the value.
+fn take_option(args: &mut Vec<String>, option: &str) -> Result<Option<String>, String> {
+    match args.iter().position(|arg| arg == option) {
+        Some(i) => {
+            // Another option where the value should be means the value was left out.
+            let value = args
+                .get(i + 1)
+                .filter(|value| !value.starts_with("--"))
+                .cloned();
+            let end = if value.is_some() { i + 2 } else { i + 1 };
+            args.drain(i..end);
+            value
+                .map(Some)
+                .ok_or_else(|| format!("Expected a value after {option}"))
+        }
+        None => Ok(None),
+    }
+}
+
+fn parse_selection(args: &[String]) -> Result<Selection, String> {
+    let mut args = args.to_vec();
+    let input = take_option(&mut args, "--input")?.map(|path| match path.as_str() {
+        "-" => InputSource::Stdin,
+        path => InputSource::File(PathBuf::from(path)),
+    });
+    let timings = take_option(&mut args, "--timings")?.map(PathBuf::from);
+
+    let (year, day) = match args.as_slice() {
+        [year] => (year, None),
+        [year, day] => (year, Some(day)),
+        _ => return Err("Expected a year (or --all) and an optional day".to_owned()),
+    };
+    let year = match year.as_str() {
+        "--all" | "all" => None,
+        year => Some(year.parse().map_err(|_| format!("Invalid year {year:?}"))?),
+    };
+    let day = day
+        .map(|day| day.parse().map_err(|_| format!("Invalid day {day:?}")))
+        .transpose()?;
+    if input.is_some() && (year.is_none() || day.is_none()) {
+        return Err("--input needs a single year and day".to_owned());
+    }
+    Ok(Selection {
+        year,
+        day,
+        input,
+        timings,
+    })
+}
+
+fn select(selection: &Selection) -> Result<Vec<(&'static Year, &'static Day)>, String> {
+    let years: Vec<&Year> = match selection.year {
+        Some(year) => vec![YEARS
+            .iter()
+            .find(|y| y.year == year)
+            .ok_or_else(|| format!("No solutions registered for {year}"))?],
+        None => YEARS.iter().collect(),
+    };
+
+    let selected: Vec<_> = years
+        .into_iter()
+        .flat_map(|year| {
+            year.days
+                .iter()
+                .filter(|day| selection.day.is_none_or(|d| d == day.day))
+                .map(move |day| (year, day))
+        })
+        .collect();
+
+    if selected.is_empty() {
+        return Err("No solutions match that selection".to_owned());
+    }
+    Ok(selected)
+}
+
+struct Outcome {
+    answers: Option<Answers>,
+    elapsed: Duration,
+    timings: Vec<Timing>,
+}
+
+fn run_stages(day: &Day, input: &str) -> Answers {
+    let input = {
+        let _timer = PerfTimer::new("Parse");
+        day.parse(input)
+    };
+    let part_1 = {
+        let _timer = PerfTimer::new("Part 1");
+        day.part_1(&input)
+    };
+    let part_2 = day.has_part_2().then(|| {
+        let _timer = PerfTimer::new("Part 2");
+        day.part_2(&input).unwrap()
+    });
+    Answers {
+        part_1: Some(part_1),
+        part_2,
+    }
+}
+
+fn run_day(day: &Day, input: &str) -> Outcome {
+    let start = Instant::now();
+    let (answers, timings) = collect_timings(|| panic::catch_unwind(|| run_stages(day, input)));
+    Outcome {
+        answers: answers.ok(),
+        elapsed: start.elapsed(),
+        timings,
+    }
+}
+
+fn answer_cell(answer: Option<&String>) -> &str {
+    match answer {
+        Some(answer) if answer.contains('\n') => "(see below)",
+        Some(answer) => answer,
+        None => "-",
+    }
+}
+
+fn print_row(year: &Year, day: &Day, outcome: &Outcome) {
+    let (part_1, part_2) = match &outcome.answers {
+        Some(answers) => (
+            answer_cell(answers.part_1.as_ref()),
+            answer_cell(answers.part_2.as_ref()),
+        ),
+        None => ("panicked", "panicked"),
+    };
+    let timings = outcome
+        .timings
+        .iter()
+        .filter(|t| t.depth() == 0)
+        .map(|t| format!("{} {:.1?}", t.name(), t.total))
+        .collect::<Vec<_>>()
+        .join(", ");
+    println!(
+        "{:<4}  {:>3}  {part_1:<ANSWER_WIDTH$}  {part_2:<ANSWER_WIDTH$}  {:>10}  {timings}",
+        year.year,
+        day.day,
+        format!("{:.1?}", outcome.elapsed),
+    );
+
+    let multi_line = outcome.answers.iter().flat_map(|answers| {
+        [("Part 1", &answers.part_1), ("Part 2", &answers.part_2)]
+            .into_iter()
+            .filter_map(|(name, answer)| Some((name, answer.as_ref()?)))
+            .filter(|(_, answer)| answer.contains('\n'))
+    });
+    for (name, answer) in multi_line {
+        println!("{:11}{name}:", "");
+        for line in answer.lines() {
+            println!("{:11}{line}", "");
+        }
+    }
+
+    for timing in outcome.timings.iter().filter(|t| t.depth() > 0) {
+        println!(
+            "{:11}{:indent$}{} {:.1?} ({} × {:.1?}, max {:.1?})",
+            "",
+            "",
+            timing.path.join(" > "),
+            timing.total,
+            timing.count,
+            timing.mean(),
+            timing.max,
+            indent = 2 * (timing.depth() - 1),
+        );
+    }
+}
+
+fn save_timings(path: &Path, report: &TimingReport) -> Result<(), String> {
+    let contents = match path.extension().and_then(|extension| extension.to_str()) {
+        Some("json") => report.to_json(),
+        Some("csv") => report.to_csv(),
+        _ => {
+            return Err(format!(
+                "Expected a .json or .csv path for --timings, not {}",
+                path.display()
+            ))
+        }
+    };
+    fs::write(path, contents).map_err(|e| format!("Failed to save {}: {e}", path.display()))
+}
+
+fn run(args: &[String]) -> Result<bool, String> {
+    let selection = parse_selection(args)?;
+    let selected = select(&selection)?;
+
+    println!(
+        "{:<4}  {:>3}  {:<ANSWER_WIDTH$}  {:<ANSWER_WIDTH$}  {:>10}  Timings",
+        "Year", "Day", "Part 1", "Part 2", "Time"
+    );
+    let mut all_succeeded = true;
+    let mut report = TimingReport::new();
+    for (year, day) in selected {
+        let input = match &selection.input {
+            Some(source) => source.read(),
+            None => year.read_input(day.day),
+        };
+        let input = match input {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("{} day {}: {e}", year.year, day.day);
+                all_succeeded = false;
+                continue;
+            }
+        };
+        let outcome = run_day(day, &input);
+        all_succeeded &= outcome.answers.is_some();
+        print_row(year, day, &outcome);
+        report.add(year.year, day.day, outcome.timings);
+    }
+
+    if let Some(path) = &selection.timings {
+        save_timings(path, &report)?;
+    }
+    Ok(all_succeeded)
+}
+
+/// Formats how much slower `current` is than `baseline`, along with whether that's a regression.
+/// A zero baseline can't be compared against, so it's shown the same as a missing one.
+fn compare_to_baseline(baseline: Duration, current: Duration, threshold: f64) -> (String, bool) {
+    if baseline.is_zero() {
+        return ("-".to_owned(), false);
+    }
+    let change = current.as_secs_f64() / baseline.as_secs_f64() - 1.0;
+    let regressed = change > threshold;
+    let flag = if regressed { "  REGRESSED" } else { "" };
+    (format!("{:+.1}%{flag}", change * 100.0), regressed)
+}
+
+fn bench(args: &[String]) -> Result<bool, String> {
+    let mut args = args.to_vec();
+    let baseline_path = take_option(&mut args, "--baseline")?.map(PathBuf::from);
+    let save_path = take_option(&mut args, "--save-baseline")?.map(PathBuf::from);
+    let threshold = match take_option(&mut args, "--threshold")? {
+        Some(percent) => {
+            percent
+                .parse::<f64>()
+                .map_err(|_| format!("Invalid threshold {percent:?}"))?
+                / 100.0
+        }
+        None => DEFAULT_THRESHOLD,
+    };
+    let selection = parse_selection(&args)?;
+    if selection.timings.is_some() {
+        return Err("--timings only applies to run".to_owned());
+    }
+    let selected = select(&selection)?;
+    let baseline = baseline_path
+        .as_deref()
+        .map(Baseline::load)
+        .transpose()?
+        .unwrap_or_default();
+    let mut results = match &save_path {
+        Some(path) => Baseline::load(path)?,
+        None => Baseline::new(),
+    };
+
+    println!(
+        "{:<4}  {:>3}  {:<6}  {:>10}  {:>10}  {:>10}  {:>7}  Baseline",
+        "Year", "Day", "Stage", "Min", "Median", "Stddev", "Samples"
+    );
+    let config = BenchConfig::default();
+    let mut all_succeeded = true;
+    for (year, day) in selected {
+        let input = match &selection.input {
+            Some(source) => source.read(),
+            None => year.read_input(day.day),
+        };
+        let input = match input {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("{} day {}: {e}", year.year, day.day);
+                all_succeeded = false;
+                continue;
+            }
+        };
+        let Ok(stages) = panic::catch_unwind(|| day.bench(&input, &config)) else {
+            println!("{:<4}  {:>3}  panicked", year.year, day.day);
+            all_succeeded = false;
+            continue;
+        };
+
+        for (stage, stats) in stages {
+            let comparison = match baseline.get(year.year, day.day, stage) {
+                Some(median) => {
+                    let (comparison, regressed) =
+                        compare_to_baseline(median, stats.median, threshold);
+                    all_succeeded &= !regressed;
+                    comparison
+                }
+                None => "-".to_owned(),
+            };
+            println!(
+                "{:<4}  {:>3}  {stage:<6}  {:>10}  {:>10}  {:>10}  {:>7}  {comparison}",
+                year.year,
+                day.day,
+                format!("{:.1?}", stats.min),
+                format!("{:.1?}", stats.median),
+                format!("{:.1?}", stats.stddev),
+                stats.samples,
+            );
+            results.insert(year.year, day.day, stage, stats.median);
+        }
+    }
+
+    if let Some(path) = &save_path {
+        results
+            .save(path)
+            .map_err(|e| format!("Failed to save {}: {e}", path.display()))?;
+    }
+    Ok(all_succeeded)
+}
+
+fn fetch(args: &[String]) -> Result<bool, String> {
+    let [year, day] = args else {
+        return Err("Expected a year and day to fetch".to_owned());
+    };
+    let year: u16 = year.parse().map_err(|_| format!("Invalid year {year:?}"))?;
+    let day: u8 = match day.parse() {
+        Ok(day @ 1..=25) => day,
+        _ => return Err(format!("Invalid day {day:?}")),
+    };
+    // The day doesn't need a solution yet, since fetching its input usually comes first.
+    let year = YEARS
+        .iter()
+        .find(|y| y.year == year)
+        .ok_or_else(|| format!("No crate registered for {year}"))?;
+
+    match Client::from_env().fetch(year, day) {
+        Ok(Fetched::Downloaded(path)) => println!("Saved input to {}", path.display()),
+        Ok(Fetched::Cached(path)) => println!("Already have {}", path.display()),
+        Err(e) => return Err(e.to_string()),
+    }
+    Ok(true)
+}
+
+fn submit(args: &[String]) -> Result<bool, String> {
+    let [year, day, part] = args else {
+        return Err("Expected a year, day and part to submit".to_owned());
+    };
+    let [(year, day)] = select(&parse_selection(&[year.clone(), day.clone()])?)?[..] else {
+        unreachable!("a single year and day select a single solution");
+    };
+    let part: u8 = match part.parse() {
+        Ok(1) => 1,
+        Ok(2) if day.has_part_2() => 2,
+        _ => return Err(format!("Invalid part {part:?}")),
+    };
+
+    let input = year.read_input(day.day).map_err(|e| e.to_string())?;
+    let input = day.parse(&input);
+    let answer = match part {
+        1 => day.part_1(&input),
+        _ => day.part_2(&input).unwrap(),
+    };
+    if answer.contains('\n') {
+        return Err(format!(
+            "Part {part}'s answer has to be read off its output and submitted by hand:\n{answer}"
+        ));
+    }
+
+    let mut history = History::load(&year.submission_history_path())?;
+    if let Err(refusal) = history.check(day.day, part, &answer, SystemTime::now()) {
+        return Err(format!("Not submitting {answer}: {refusal}"));
+    }
+    let response = Client::from_env()
+        .submit(year.year, day.day, part, &answer)
+        .map_err(|e| e.to_string())?;
+    history.record(day.day, part, &answer, response, SystemTime::now());
+    history.save().map_err(|e| e.to_string())?;
+
+    match response {
+        Response::Judged { verdict, wait } => {
+            println!("{answer} is {verdict}");
+            if let Some(wait) = wait {
+                println!("Wait {}s before submitting again", wait.as_secs());
+            }
+            Ok(verdict == Verdict::Correct)
+        }
+        Response::TooSoon { wait } => Err(format!(
+            "Answered too recently; wait {}s before submitting again",
+            wait.as_secs()
+        )),
+        Response::WrongLevel => Err(format!(
+            "Part {part} is already solved, or isn't unlocked yet"
+        )),
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("run") => run(&args[1..]),
+        Some("bench") => bench(&args[1..]),
+        Some("fetch") => fetch(&args[1..]),
+        Some("submit") => submit(&args[1..]),
+        _ => Err(USAGE.to_owned()),
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|&arg| arg.to_owned()).collect()
+    }
+
+    #[test]
+    fn options_before_positionals() {
+        let selection = parse_selection(&args(&["--input", "day1.txt", "2019", "1"])).unwrap();
+        assert_eq!(selection.year, Some(2019));
+        assert_eq!(selection.day, Some(1));
+        assert!(
+            matches!(selection.input, Some(InputSource::File(path)) if path == Path::new("day1.txt"))
+        );
+    }
+
+    #[test]
+    fn consecutive_options() {
+        let selection = parse_selection(&args(&[
+            "2019",
+            "1",
+            "--input",
+            "-",
+            "--timings",
+            "timings.json",
+        ]))
+        .unwrap();
+        assert_eq!(selection.year, Some(2019));
+        assert_eq!(selection.day, Some(1));
+        assert!(matches!(selection.input, Some(InputSource::Stdin)));
+        assert_eq!(selection.timings, Some(PathBuf::from("timings.json")));
+
+        let mut rest = args(&["--baseline", "a.txt", "--threshold", "5", "2019"]);
+        assert_eq!(
+            take_option(&mut rest, "--baseline").unwrap().as_deref(),
+            Some("a.txt")
+        );
+        assert_eq!(
+            take_option(&mut rest, "--threshold").unwrap().as_deref(),
+            Some("5")
+        );
+        assert_eq!(rest, args(&["2019"]));
+    }
+
+    #[test]
+    fn missing_option_value() {
+        let mut rest = args(&["2019", "--timings"]);
+        assert_eq!(
+            take_option(&mut rest, "--timings"),
+            Err("Expected a value after --timings".to_owned())
+        );
+
+        let mut rest = args(&["2019", "--input", "--timings", "timings.json"]);
+        assert!(take_option(&mut rest, "--input").is_err());
+        assert!(parse_selection(&args(&["2019", "1", "--input", "--timings", "t.json"])).is_err());
+    }
+
+    #[test]
+    fn compares_to_a_baseline() {
+        let ms = Duration::from_millis;
+        assert_eq!(
+            compare_to_baseline(ms(100), ms(120), 0.1),
+            ("+20.0%  REGRESSED".to_owned(), true)
+        );
+        assert_eq!(
+            compare_to_baseline(ms(100), ms(90), 0.1),
+            ("-10.0%".to_owned(), false)
+        );
+        assert_eq!(
+            compare_to_baseline(Duration::ZERO, ms(1), 0.1),
+            ("-".to_owned(), false)
+        );
+    }
+}